@@ -0,0 +1,49 @@
+//! timing of the conversions that should not allocate, run with `cargo bench`
+//!
+//! a plain loop over `std::time::Instant`, so it needs no bench harness or extra dependency
+
+use dayjs::{Dayjs, DayjsTimeZone};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ROUNDS: u32 = 200_000;
+
+/// run `f` and print the mean time of a call
+fn bench<T>(name: &str, mut f: impl FnMut() -> T) {
+    for _ in 0..ROUNDS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    let mean = start.elapsed().as_nanos() / u128::from(ROUNDS);
+    println!("{:<32} {:>8} ns/iter", name, mean);
+}
+
+fn main() {
+    let date = dayjs::from_timestamp(1704443415250);
+    let dates: Vec<Dayjs> = (0..64).map(|i| date.add(i, dayjs::Unit::Hour)).collect();
+    bench("min of 64", || {
+        dayjs::min(black_box(&dates).iter().copied())
+    });
+    bench("max of 64", || {
+        dayjs::max(black_box(&dates).iter().copied())
+    });
+    bench("to_object", || black_box(date).to_object());
+    bench("to_object_v2 offset", || {
+        black_box(date.tz(DayjsTimeZone::Offset(3600))).to_object_v2()
+    });
+    bench("format iso", || {
+        black_box(date).format("YYYY-MM-DDTHH:mm:ss.SSSZ")
+    });
+    bench("to_iso_buffered", || {
+        black_box(date).to_iso_buffered().len()
+    });
+    #[cfg(feature = "tz")]
+    {
+        let new_york = date.tz(DayjsTimeZone::city("America/New_York").unwrap());
+        bench("to_object_v2 city", || black_box(new_york).to_object_v2());
+        bench("format zone names", || black_box(new_york).format("zzz z"));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::parse::{self, Cursor};
+use crate::{Dayjs, DayjsError};
+use chrono::Datelike;
+
+impl Dayjs {
+    /// ASN.1 UTCTime `YYMMDDHHMMSSZ`, which only covers 1950 to 2049
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(date.to_utc_time().unwrap(), "240105083000Z");
+    /// ```
+    pub fn to_utc_time(&self) -> Result<String, DayjsError> {
+        match self.time.year() {
+            1950..=2049 => Ok(self.time.format("%y%m%d%H%M%SZ").to_string()),
+            year => Err(DayjsError::OutOfRange(format!(
+                "year {} is out of the UTCTime range",
+                year
+            ))),
+        }
+    }
+
+    /// X.509 validity time: UTCTime through 2049 and GeneralizedTime `YYYYMMDDHHMMSSZ` from 2050,
+    /// as RFC 5280 requires; fractional seconds are dropped
+    ///
+    /// GeneralizedTime has four year digits, so years outside 0 to 9999 are an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(date.to_x509_time().unwrap(), "240105083000Z");
+    /// assert_eq!(date.add(30, Unit::Year).to_x509_time().unwrap(), "20540105083000Z");
+    /// assert!(date.add(8000, Unit::Year).to_x509_time().is_err());
+    /// ```
+    pub fn to_x509_time(&self) -> Result<String, DayjsError> {
+        match self.time.year() {
+            1950..=2049 => self.to_utc_time(),
+            0..=9999 => Ok(self.time.format("%Y%m%d%H%M%SZ").to_string()),
+            year => Err(DayjsError::OutOfRange(format!(
+                "year {} is out of the GeneralizedTime range",
+                year
+            ))),
+        }
+    }
+}
+
+/// `Z` or `±hhmm`
+fn zone(c: &mut Cursor) -> Result<Option<i32>, DayjsError> {
+    if c.eat(b'Z') {
+        return Ok(None);
+    }
+    let negative = c.sign()?;
+    let offset = parse::offset(c, negative, false)?;
+    Ok(Some(offset))
+}
+
+/// parse an ASN.1 UTCTime, `YYMMDDHHMM[SS](Z|±hhmm)`
+///
+/// years below 50 are 20xx, the RFC 5280 pivot; DER requires seconds and `Z`, the other BER
+/// forms are accepted too
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::asn1::from_utc_time("491231235959Z").unwrap();
+/// assert_eq!(date.to_string(), "2049-12-31T23:59:59+00:00");
+/// let date = dayjs::asn1::from_utc_time("500101000000Z").unwrap();
+/// assert_eq!(date.to_string(), "1950-01-01T00:00:00+00:00");
+/// ```
+pub fn from_utc_time(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "UTCTime");
+    let year = match c.digits(2, "year")? {
+        year @ 0..=49 => year + 2000,
+        year => year + 1900,
+    };
+    let month = c.digits(2, "month")?;
+    let day = c.digits(2, "day")?;
+    let hour = c.digits(2, "hour")?;
+    let minute = c.digits(2, "minute")?;
+    let second = if c.digit_run() > 0 {
+        c.digits(2, "second")?
+    } else {
+        0
+    };
+    let offset = zone(&mut c)?;
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    let date = parse::date(&c, year, month, day)?;
+    let time = parse::time(&c, hour, minute, second, 0)?;
+    Ok(parse::zoned(date.and_time(time), offset))
+}
+
+/// parse a DER GeneralizedTime, `YYYYMMDDHHMMSS[.fraction]Z`
+///
+/// DER allows no offset and no trailing zeros in the fraction, see
+/// [`crate::ldap::from_generalized_time`] for the lenient form
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::asn1::from_generalized_time("20540105083000.5Z").unwrap();
+/// assert_eq!(date.to_string(), "2054-01-05T08:30:00.500+00:00");
+/// assert!(dayjs::asn1::from_generalized_time("20540105083000.50Z").is_err());
+/// ```
+pub fn from_generalized_time(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "DER GeneralizedTime");
+    let year = c.digits(4, "year")?;
+    let month = c.digits(2, "month")?;
+    let day = c.digits(2, "day")?;
+    let hour = c.digits(2, "hour")?;
+    let minute = c.digits(2, "minute")?;
+    let second = c.digits(2, "second")?;
+    let nanos = if c.eat(b'.') {
+        let n = c.digit_run();
+        if n > 0 && c.peek_at(n - 1) == Some(b'0') {
+            return Err(c.error("trailing zeros are not allowed in the fraction"));
+        }
+        c.fraction()?
+    } else {
+        0
+    };
+    c.expect(b'Z')?;
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    let date = parse::date(&c, year, month, day)?;
+    let time = parse::time(&c, hour, minute, second, nanos)?;
+    Ok(parse::zoned(date.and_time(time), None))
+}
@@ -0,0 +1,163 @@
+use crate::{Dayjs, DayjsError, DayjsTimeZone};
+use chrono::DateTime;
+
+/// length of [`Dayjs::to_bytes`]
+pub const ENCODED_LEN: usize = 12;
+
+const FLAG_OFFSET: u16 = 1;
+const FLAG_ZONE: u16 = 1 << 1;
+
+impl Dayjs {
+    /// fixed 12 byte encoding for binary device protocols, all fields big endian
+    ///
+    /// - bytes 0..8: `i64` milliseconds since 1970-01-01 UTC
+    /// - bytes 8..10: `i16` offset from UTC in minutes, east positive
+    /// - bytes 10..12: `u16` flags, bit 0 is set for an explicit offset or zone and bit 1 for a
+    ///   named or system zone, which decodes as its offset at the instant; other bits must be zero
+    ///
+    /// sub-millisecond precision and seconds of the offset are dropped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400123);
+    /// let bytes = date.to_bytes();
+    /// assert_eq!(bytes, [0, 0, 1, 140, 216, 190, 79, 187, 0, 0, 0, 0]);
+    /// assert_eq!(dayjs::binary::from_bytes(&bytes).unwrap(), date);
+    /// ```
+    pub fn to_bytes(&self) -> [u8; ENCODED_LEN] {
+        let flags = match self.tz {
+            DayjsTimeZone::DEFAULT => 0,
+            DayjsTimeZone::Offset(_) => FLAG_OFFSET,
+            DayjsTimeZone::Local => FLAG_OFFSET | FLAG_ZONE,
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(_) => FLAG_OFFSET | FLAG_ZONE,
+        };
+        let minutes = self.datetime().offset().local_minus_utc() / 60;
+        let mut bytes = [0; ENCODED_LEN];
+        bytes[..8].copy_from_slice(&self.time.timestamp_millis().to_be_bytes());
+        bytes[8..10].copy_from_slice(&(minutes as i16).to_be_bytes());
+        bytes[10..].copy_from_slice(&flags.to_be_bytes());
+        bytes
+    }
+}
+
+/// decode the 12 byte encoding of [`Dayjs::to_bytes`]
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::DayjsTimeZone;
+/// let bytes = [0, 0, 1, 140, 216, 190, 79, 187, 1, 224, 0, 1];
+/// let date = dayjs::binary::from_bytes(&bytes).unwrap();
+/// assert_eq!(date.tz, DayjsTimeZone::Offset(8 * 3600));
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00.123+00:00");
+/// ```
+pub fn from_bytes(bytes: &[u8]) -> Result<Dayjs, DayjsError> {
+    let bytes: &[u8; ENCODED_LEN] = bytes.try_into().map_err(|_| {
+        DayjsError::InvalidEncoding(format!(
+            "expected {} bytes, got {}",
+            ENCODED_LEN,
+            bytes.len()
+        ))
+    })?;
+    let millis = i64::from_be_bytes(bytes[..8].try_into().unwrap());
+    let minutes = i16::from_be_bytes([bytes[8], bytes[9]]);
+    let flags = u16::from_be_bytes([bytes[10], bytes[11]]);
+    if flags & !(FLAG_OFFSET | FLAG_ZONE) != 0 {
+        return Err(DayjsError::InvalidEncoding(format!(
+            "reserved flag bits are set in {:#06x}",
+            flags
+        )));
+    }
+    let time =
+        DateTime::from_timestamp_millis(millis).ok_or(DayjsError::InvalidTimestamp(millis))?;
+    let tz = if flags & FLAG_OFFSET != 0 {
+        if !(-24 * 60 < minutes && minutes < 24 * 60) {
+            return Err(DayjsError::OutOfRange(format!(
+                "offset of {} minutes is out of range",
+                minutes
+            )));
+        }
+        DayjsTimeZone::Offset(i32::from(minutes) * 60)
+    } else {
+        DayjsTimeZone::DEFAULT
+    };
+    Ok(Dayjs { tz, time })
+}
+
+fn write_varint(out: &mut Vec<u8>, value: i64) {
+    // zig-zag, so small negative deltas stay short too
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n >= 0x80 {
+        out.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn overflow(pos: usize) -> DayjsError {
+    DayjsError::InvalidEncoding(format!("varint overflows 64 bits at byte {}", pos))
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<i64, DayjsError> {
+    let mut n = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*pos).ok_or_else(|| {
+            DayjsError::InvalidEncoding(format!("truncated varint at byte {}", *pos))
+        })?;
+        *pos += 1;
+        if shift == 63 && byte > 1 {
+            return Err(overflow(*pos - 1));
+        }
+        n |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Ok((n >> 1) as i64 ^ -((n & 1) as i64));
+        }
+    }
+    Err(overflow(*pos - 1))
+}
+
+/// encode a timestamp column as the first instant followed by the delta to each previous one,
+/// in milliseconds as zig-zag varints
+///
+/// sorted columns at second to minute intervals take two or three bytes per instant;
+/// sub-millisecond precision and timezones are dropped
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::Unit;
+/// let start = dayjs::from_timestamp(1704443400);
+/// let column: Vec<_> = (0..100).map(|i| start.add(i * 15, Unit::Second)).collect();
+/// let bytes = dayjs::binary::encode_deltas(&column);
+/// assert_eq!(bytes.len(), 6 + 99 * 3);
+/// assert_eq!(dayjs::binary::decode_deltas(&bytes).unwrap(), column);
+/// ```
+pub fn encode_deltas(column: &[Dayjs]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(column.len() * 2 + 8);
+    let mut previous = 0i64;
+    for date in column {
+        let millis = date.time.timestamp_millis();
+        write_varint(&mut out, millis.wrapping_sub(previous));
+        previous = millis;
+    }
+    out
+}
+
+/// decode the output of [`encode_deltas`], instants are in UTC
+pub fn decode_deltas(bytes: &[u8]) -> Result<Vec<Dayjs>, DayjsError> {
+    let mut column = Vec::new();
+    let mut pos = 0;
+    let mut millis = 0i64;
+    while pos < bytes.len() {
+        millis = millis.wrapping_add(read_varint(bytes, &mut pos)?);
+        let time =
+            DateTime::from_timestamp_millis(millis).ok_or(DayjsError::InvalidTimestamp(millis))?;
+        column.push(Dayjs {
+            tz: DayjsTimeZone::DEFAULT,
+            time,
+        });
+    }
+    Ok(column)
+}
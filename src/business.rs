@@ -0,0 +1,127 @@
+use crate::{Dayjs, Unit};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+
+/// days of the week without business, saturday and sunday by default
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Weekend(u8);
+
+impl Weekend {
+    pub const SATURDAY_SUNDAY: Weekend = Weekend(0b110_0000);
+    /// common in the Middle East
+    pub const FRIDAY_SATURDAY: Weekend = Weekend(0b011_0000);
+
+    pub fn new(days: impl IntoIterator<Item = Weekday>) -> Weekend {
+        Weekend(
+            days.into_iter()
+                .fold(0, |bits, day| bits | 1 << day.num_days_from_monday()),
+        )
+    }
+
+    pub fn contains(&self, day: Weekday) -> bool {
+        self.0 & 1 << day.num_days_from_monday() != 0
+    }
+
+    /// business days in a week
+    fn business_days(&self) -> i64 {
+        7 - i64::from(self.0.count_ones())
+    }
+
+    /// business days in `[from, to)`, negative when `to` is before `from`
+    fn count(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        if to < from {
+            return -self.count(to, from);
+        }
+        let days = (to - from).num_days();
+        let rest = (0..days % 7)
+            .filter(|i| !self.contains((from + Duration::days(*i)).weekday()))
+            .count() as i64;
+        days / 7 * self.business_days() + rest
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Weekend::SATURDAY_SUNDAY
+    }
+}
+
+impl Dayjs {
+    /// whether the day in the instance timezone is not a weekend day
+    pub fn is_business_day(&self, weekend: &Weekend) -> bool {
+        !weekend.contains(self.day())
+    }
+
+    /// move by `n` business days keeping the wall clock time, weekend days are skipped and
+    /// negative values go back; unchanged when every day is weekend, and the first or last
+    /// instant of the supported range when the move goes past it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Weekend;
+    /// let friday = dayjs::from_timestamp(1704443400);
+    /// let weekend = Weekend::default();
+    /// assert_eq!(friday.add_business_days(1, &weekend).format("dddd D"), "Monday 8");
+    /// assert_eq!(friday.add_business_days(-5, &weekend).format("dddd D"), "Friday 29");
+    /// assert_eq!(friday.add_business_days(1, &Weekend::FRIDAY_SATURDAY).format("dddd D"), "Sunday 7");
+    /// ```
+    pub fn add_business_days(&self, n: i64, weekend: &Weekend) -> Dayjs {
+        let per_week = weekend.business_days().unsigned_abs();
+        if per_week == 0 || n == 0 {
+            return *self;
+        }
+        let step = n.signum();
+        let edge = Dayjs {
+            time: match step {
+                1 => DateTime::<Utc>::MAX_UTC,
+                _ => DateTime::<Utc>::MIN_UTC,
+            },
+            ..*self
+        };
+        // whole weeks first, then day by day
+        let weeks = (n.unsigned_abs() - 1) / per_week;
+        let days = match weeks.checked_mul(7).map(i64::try_from) {
+            Some(Ok(days)) => days,
+            _ => return edge,
+        };
+        let mut date = self.add(days * step, Unit::Day);
+        if days > 0 && date == *self {
+            return edge;
+        }
+        let mut left = n.unsigned_abs() - weeks * per_week;
+        while left > 0 {
+            let next = date.add(step, Unit::Day);
+            if next == date {
+                return edge;
+            }
+            date = next;
+            if date.is_business_day(weekend) {
+                left -= 1;
+            }
+        }
+        date
+    }
+
+    /// the next business day at the same wall clock time
+    pub fn next_business_day(&self, weekend: &Weekend) -> Dayjs {
+        self.add_business_days(1, weekend)
+    }
+
+    /// business days from the date of this instance up to the date of `other`, in the instance
+    /// timezone, counting this date but not the other, negative when `other` is earlier
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Weekend;
+    /// let monday = dayjs::from_timestamp(1704702600);
+    /// let next_monday = dayjs::from_timestamp(1705307400);
+    /// assert_eq!(monday.business_days_between(&next_monday, &Weekend::default()), 5);
+    /// assert_eq!(next_monday.business_days_between(&monday, &Weekend::default()), -5);
+    /// ```
+    pub fn business_days_between(&self, other: &Dayjs, weekend: &Weekend) -> i64 {
+        let from = self.datetime().date_naive();
+        let to = other.tz(self.tz).datetime().date_naive();
+        weekend.count(from, to)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::locale::Locale;
+use crate::{dayjs, Dayjs, Unit};
+use chrono::{Datelike, Duration};
+
+/// overrides of the locale templates of [`Dayjs::calendar_with`], `None` keeps the locale's
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CalendarFormats<'a> {
+    pub same_day: Option<&'a str>,
+    pub next_day: Option<&'a str>,
+    pub next_week: Option<&'a str>,
+    pub last_day: Option<&'a str>,
+    pub last_week: Option<&'a str>,
+    pub same_else: Option<&'a str>,
+}
+
+impl Dayjs {
+    /// calendar time against the day of `reference` or now in the active locale, e.g.
+    /// "Today at 2:30 PM", "Last Monday at 2:30 PM" or "07/10/2011" more than a week away
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let reference = dayjs::from_str("2024-01-05T12:00:00Z");
+    /// let date = dayjs::from_str("2024-01-05T14:30:00Z");
+    /// assert_eq!(date.calendar(Some(&reference)), "Today at 2:30 PM");
+    /// let date = dayjs::from_str("2024-01-04T09:00:00Z");
+    /// assert_eq!(date.calendar(Some(&reference)), "Yesterday at 9:00 AM");
+    /// let date = dayjs::from_str("2024-01-01T09:00:00Z");
+    /// assert_eq!(date.calendar(Some(&reference)), "Last Monday at 9:00 AM");
+    /// let date = dayjs::from_str("2023-12-20T09:00:00Z");
+    /// assert_eq!(date.calendar(Some(&reference)), "12/20/2023");
+    /// ```
+    pub fn calendar(&self, reference: Option<&Dayjs>) -> String {
+        self.calendar_with(reference, &CalendarFormats::default())
+    }
+
+    /// [`Dayjs::calendar`] with some templates replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::CalendarFormats;
+    /// let reference = dayjs::from_str("2024-01-05T12:00:00Z");
+    /// let formats = CalendarFormats { same_else: Some("D MMM YYYY"), ..Default::default() };
+    /// let date = dayjs::from_str("2023-12-20T09:00:00Z");
+    /// assert_eq!(date.calendar_with(Some(&reference), &formats), "20 Dec 2023");
+    /// ```
+    pub fn calendar_with(&self, reference: Option<&Dayjs>, formats: &CalendarFormats) -> String {
+        let locale = Locale::current().calendar;
+        let day = reference
+            .copied()
+            .unwrap_or_else(dayjs)
+            .tz(self.tz)
+            .start_of_unit(Unit::Day);
+        let (template, fallback) = match self.diff_f64(&day, Unit::Day) {
+            diff if diff < -6.0 => (formats.same_else, locale[5]),
+            diff if diff < -1.0 => (formats.last_week, locale[4]),
+            diff if diff < 0.0 => (formats.last_day, locale[3]),
+            diff if diff < 1.0 => (formats.same_day, locale[0]),
+            diff if diff < 2.0 => (formats.next_day, locale[1]),
+            diff if diff < 7.0 => (formats.next_week, locale[2]),
+            _ => (formats.same_else, locale[5]),
+        };
+        self.format(template.unwrap_or(fallback))
+    }
+
+    /// relative weekday phrase against `reference`, e.g. "last Tuesday" or "next Friday"
+    ///
+    /// returns `None` when the day is more than a week away from the reference day
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let monday = dayjs::from_timestamp(1707145445);
+    /// let friday = dayjs::from_timestamp(1707491045);
+    /// assert_eq!(friday.relative_weekday(&monday, &dayjs::locale::EN), Some("next Friday"));
+    /// ```
+    pub fn relative_weekday(&self, reference: &Dayjs, locale: &Locale) -> Option<&'static str> {
+        let date = self.datetime();
+        let days = (date.date_naive() - reference.datetime().date_naive()).num_days();
+        let column = match days {
+            -7..=-1 => 0,
+            0 => 1,
+            1..=7 => 2,
+            _ => return None,
+        };
+        let weekday = date.weekday().num_days_from_sunday() as usize;
+        Some(locale.weekdays_relative[weekday][column])
+    }
+}
+
+/// parse a relative weekday phrase such as "next Friday" against `reference`, keeping its time of day
+///
+/// "last" and "next" never resolve to the reference day itself, "this" looks up to 6 days ahead
+pub fn parse_relative_weekday(s: &str, reference: &Dayjs, locale: &Locale) -> Option<Dayjs> {
+    let s = s.trim().to_lowercase();
+    let (weekday, column) =
+        locale
+            .weekdays_relative
+            .iter()
+            .enumerate()
+            .find_map(|(weekday, phrases)| {
+                let column = phrases.iter().position(|p| p.to_lowercase() == s)?;
+                Some((weekday as i64, column))
+            })?;
+    let local = reference.datetime().naive_local();
+    let current = local.weekday().num_days_from_sunday() as i64;
+    let forward = (weekday - current).rem_euclid(7);
+    let days = match (column, forward) {
+        (0, 0) => -7,
+        (0, _) => forward - 7,
+        (2, 0) => 7,
+        _ => forward,
+    };
+    // step on the wall clock, a DST change in between keeps the time of day
+    let local = local.checked_add_signed(Duration::days(days))?;
+    Some(Dayjs {
+        tz: reference.tz,
+        time: reference.tz.local_to_utc(local),
+    })
+}
@@ -0,0 +1,55 @@
+use crate::locale::LOCALES;
+
+/// optional subsystems compiled into this build, see [`capabilities`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// IANA zones through [`crate::DayjsTimeZone::City`], the `tz` feature
+    pub tz_database: bool,
+    /// release of the zone database, e.g. "2024a"
+    pub tz_version: Option<&'static str>,
+    /// built-in locale names, more can be added with [`crate::Locale::register`]
+    pub locales: Vec<&'static str>,
+    /// chinese lunar calendar, not part of this crate yet
+    pub lunar: bool,
+    /// sun and moon times, not part of this crate yet
+    pub astro: bool,
+}
+
+impl Capabilities {
+    /// names of the subsystems that are not compiled in, for startup reports
+    pub fn missing(&self) -> Vec<&'static str> {
+        [
+            ("tz", self.tz_database),
+            ("lunar", self.lunar),
+            ("astro", self.astro),
+        ]
+        .into_iter()
+        .filter(|(_, present)| !present)
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+/// which optional subsystems this build has, so callers can degrade gracefully
+///
+/// # Examples
+///
+/// ```
+/// let capabilities = dayjs::capabilities();
+/// assert!(capabilities.locales.contains(&"en"));
+/// assert_eq!(capabilities.tz_database, cfg!(feature = "tz"));
+/// assert!(capabilities.missing().contains(&"lunar"));
+/// ```
+pub fn capabilities() -> Capabilities {
+    #[cfg(feature = "tz")]
+    let tz_version = Some(chrono_tz::IANA_TZDB_VERSION);
+    #[cfg(not(feature = "tz"))]
+    let tz_version = None;
+    Capabilities {
+        tz_database: tz_version.is_some(),
+        tz_version,
+        locales: LOCALES.iter().map(|locale| locale.name).collect(),
+        lunar: false,
+        astro: false,
+    }
+}
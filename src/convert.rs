@@ -0,0 +1,892 @@
+use crate::format::expand_localized;
+use crate::{from_format_dayjs, Dayjs, DayjsError, Locale};
+use chrono::{Datelike, Duration};
+
+/// date format template languages, see [`convert_format`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FormatSyntax {
+    /// dayjs tokens as in [`crate::Dayjs::format`], `[...]` escapes text
+    Dayjs,
+    /// moment.js tokens, dayjs tokens plus `DDDD` and `DDD` for the day of the year
+    Moment,
+    /// chrono and C `strftime` specifiers
+    Strftime,
+    /// Java `SimpleDateFormat` patterns, `'...'` quotes text
+    Java,
+    /// Excel number formats, `"..."` quotes text
+    Excel,
+    /// Go layouts with the reference time `Mon Jan 2 15:04:05 -0700 MST 2006`, text cannot be
+    /// escaped
+    Go,
+}
+
+impl FormatSyntax {
+    fn grammar(self) -> &'static str {
+        match self {
+            FormatSyntax::Dayjs => "dayjs template",
+            FormatSyntax::Moment => "moment template",
+            FormatSyntax::Strftime => "strftime template",
+            FormatSyntax::Java => "SimpleDateFormat pattern",
+            FormatSyntax::Excel => "Excel number format",
+            FormatSyntax::Go => "Go layout",
+        }
+    }
+}
+
+/// what a token stands for, independent of the syntax
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Field {
+    Year,
+    Year2,
+    Quarter,
+    MonthName,
+    MonthShort,
+    MonthPadded,
+    Month,
+    DayOfYearPadded,
+    DayOfYear,
+    DayPadded,
+    Day,
+    DayOrdinal,
+    WeekdayName,
+    WeekdayShort,
+    /// sunday is 0
+    Weekday,
+    HourPadded,
+    Hour,
+    Hour12Padded,
+    Hour12,
+    /// 1 to 24
+    Hour24Padded,
+    Hour24,
+    MinutePadded,
+    Minute,
+    SecondPadded,
+    Second,
+    Millis,
+    UnixSeconds,
+    UnixMillis,
+    Meridiem,
+    MeridiemLower,
+    Offset,
+    OffsetBasic,
+    /// `Z` at UTC, the Java `X` letter
+    OffsetZulu,
+    OffsetBasicZulu,
+    ZoneName,
+    ZoneShort,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Piece {
+    Literal(String),
+    Field(Field),
+}
+
+const DAYJS: [(&str, Field); 32] = [
+    ("YYYY", Field::Year),
+    ("YY", Field::Year2),
+    ("Q", Field::Quarter),
+    ("MMMM", Field::MonthName),
+    ("MMM", Field::MonthShort),
+    ("MM", Field::MonthPadded),
+    ("M", Field::Month),
+    ("DD", Field::DayPadded),
+    ("Do", Field::DayOrdinal),
+    ("D", Field::Day),
+    ("dddd", Field::WeekdayName),
+    ("ddd", Field::WeekdayShort),
+    ("d", Field::Weekday),
+    ("HH", Field::HourPadded),
+    ("H", Field::Hour),
+    ("hh", Field::Hour12Padded),
+    ("h", Field::Hour12),
+    ("kk", Field::Hour24Padded),
+    ("k", Field::Hour24),
+    ("mm", Field::MinutePadded),
+    ("m", Field::Minute),
+    ("ss", Field::SecondPadded),
+    ("s", Field::Second),
+    ("SSS", Field::Millis),
+    ("X", Field::UnixSeconds),
+    ("x", Field::UnixMillis),
+    ("A", Field::Meridiem),
+    ("a", Field::MeridiemLower),
+    ("ZZ", Field::OffsetBasic),
+    ("Z", Field::Offset),
+    ("zzz", Field::ZoneName),
+    ("z", Field::ZoneShort),
+];
+
+const MOMENT: [(&str, Field); 2] = [("DDDD", Field::DayOfYearPadded), ("DDD", Field::DayOfYear)];
+
+const STRFTIME: [(&str, Field); 28] = [
+    ("%Y", Field::Year),
+    ("%y", Field::Year2),
+    ("%B", Field::MonthName),
+    ("%b", Field::MonthShort),
+    ("%h", Field::MonthShort),
+    ("%m", Field::MonthPadded),
+    ("%-m", Field::Month),
+    ("%j", Field::DayOfYearPadded),
+    ("%-j", Field::DayOfYear),
+    ("%d", Field::DayPadded),
+    ("%-d", Field::Day),
+    ("%A", Field::WeekdayName),
+    ("%a", Field::WeekdayShort),
+    ("%w", Field::Weekday),
+    ("%H", Field::HourPadded),
+    ("%-H", Field::Hour),
+    ("%I", Field::Hour12Padded),
+    ("%-I", Field::Hour12),
+    ("%M", Field::MinutePadded),
+    ("%-M", Field::Minute),
+    ("%S", Field::SecondPadded),
+    ("%-S", Field::Second),
+    ("%3f", Field::Millis),
+    ("%s", Field::UnixSeconds),
+    ("%p", Field::Meridiem),
+    ("%P", Field::MeridiemLower),
+    ("%:z", Field::Offset),
+    ("%z", Field::OffsetBasic),
+];
+
+const JAVA: [(&str, Field); 30] = [
+    ("yyyy", Field::Year),
+    ("yy", Field::Year2),
+    ("MMMM", Field::MonthName),
+    ("MMM", Field::MonthShort),
+    ("MM", Field::MonthPadded),
+    ("M", Field::Month),
+    ("DDD", Field::DayOfYearPadded),
+    ("D", Field::DayOfYear),
+    ("dd", Field::DayPadded),
+    ("d", Field::Day),
+    ("EEEE", Field::WeekdayName),
+    ("EEE", Field::WeekdayShort),
+    ("HH", Field::HourPadded),
+    ("H", Field::Hour),
+    ("hh", Field::Hour12Padded),
+    ("h", Field::Hour12),
+    ("kk", Field::Hour24Padded),
+    ("k", Field::Hour24),
+    ("mm", Field::MinutePadded),
+    ("m", Field::Minute),
+    ("ss", Field::SecondPadded),
+    ("s", Field::Second),
+    ("SSS", Field::Millis),
+    ("a", Field::Meridiem),
+    ("xxx", Field::Offset),
+    ("Z", Field::OffsetBasic),
+    ("XXX", Field::OffsetZulu),
+    ("XX", Field::OffsetBasicZulu),
+    ("zzzz", Field::ZoneName),
+    ("z", Field::ZoneShort),
+];
+
+const EXCEL: [(&str, Field); 19] = [
+    ("yyyy", Field::Year),
+    ("yy", Field::Year2),
+    ("mmmm", Field::MonthName),
+    ("mmm", Field::MonthShort),
+    ("mm", Field::MonthPadded),
+    ("m", Field::Month),
+    ("dddd", Field::WeekdayName),
+    ("ddd", Field::WeekdayShort),
+    ("dd", Field::DayPadded),
+    ("d", Field::Day),
+    ("hh", Field::HourPadded),
+    ("h", Field::Hour),
+    ("mm", Field::MinutePadded),
+    ("m", Field::Minute),
+    ("ss", Field::SecondPadded),
+    ("s", Field::Second),
+    ("000", Field::Millis),
+    ("AM/PM", Field::Meridiem),
+    ("am/pm", Field::MeridiemLower),
+];
+
+const GO: [(&str, Field); 25] = [
+    ("2006", Field::Year),
+    ("06", Field::Year2),
+    ("January", Field::MonthName),
+    ("Jan", Field::MonthShort),
+    ("01", Field::MonthPadded),
+    ("1", Field::Month),
+    ("002", Field::DayOfYearPadded),
+    ("02", Field::DayPadded),
+    ("2", Field::Day),
+    ("Monday", Field::WeekdayName),
+    ("Mon", Field::WeekdayShort),
+    ("15", Field::HourPadded),
+    ("03", Field::Hour12Padded),
+    ("3", Field::Hour12),
+    ("04", Field::MinutePadded),
+    ("4", Field::Minute),
+    ("05", Field::SecondPadded),
+    ("5", Field::Second),
+    ("000", Field::Millis),
+    ("PM", Field::Meridiem),
+    ("pm", Field::MeridiemLower),
+    ("-07:00", Field::Offset),
+    ("-0700", Field::OffsetBasic),
+    ("Z07:00", Field::OffsetZulu),
+    ("Z0700", Field::OffsetBasicZulu),
+];
+
+fn error(syntax: FormatSyntax, template: &str, message: &str) -> DayjsError {
+    DayjsError::parse(syntax.grammar(), template, message)
+}
+
+/// push literal text, joining it to literal text before it
+fn push_literal(pieces: &mut Vec<Piece>, text: &str) {
+    match pieces.last_mut() {
+        Some(Piece::Literal(last)) => last.push_str(text),
+        _ => pieces.push(Piece::Literal(text.to_string())),
+    }
+}
+
+/// longest token of `table` at the start of `s`
+fn longest<'t>(table: &[(&'t str, Field)], s: &str) -> Option<(&'t str, Field)> {
+    table
+        .iter()
+        .filter(|(token, _)| s.starts_with(token))
+        .max_by_key(|(token, _)| token.len())
+        .copied()
+}
+
+/// dayjs or moment tokens, `[...]` escapes text
+fn read_tokens(template: &str, table: &[(&str, Field)]) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if let Some(end) = rest
+            .strip_prefix('[')
+            .and_then(|r| r.find(']'))
+            .filter(|end| *end > 0)
+        {
+            push_literal(&mut pieces, &rest[1..end + 1]);
+            rest = &rest[end + 2..];
+        } else if let Some((token, field)) = longest(table, rest) {
+            pieces.push(Piece::Field(field));
+            rest = &rest[token.len()..];
+        } else {
+            push_literal(&mut pieces, &rest[..c.len_utf8()]);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    pieces
+}
+
+/// Go layout chunks, milliseconds are `000` after a dot or comma and `_2` or `MST` have no
+/// equivalent
+fn read_go(template: &str) -> Result<Vec<Piece>, DayjsError> {
+    let mut pieces = Vec::new();
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if let Some(unsupported) = ["_2", "__2", "MST", ".999", ",999", ".9", ",9"]
+            .into_iter()
+            .find(|chunk| rest.starts_with(chunk))
+        {
+            let message = format!("unsupported chunk {}", unsupported);
+            return Err(error(FormatSyntax::Go, template, &message));
+        }
+        if rest.starts_with("000")
+            && !matches!(pieces.last(), Some(Piece::Literal(l)) if l.ends_with(['.', ',']))
+        {
+            push_literal(&mut pieces, "000");
+            rest = &rest[3..];
+        } else if let Some((token, field)) = longest(&GO, rest) {
+            pieces.push(Piece::Field(field));
+            rest = &rest[token.len()..];
+        } else {
+            push_literal(&mut pieces, &rest[..c.len_utf8()]);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    Ok(pieces)
+}
+
+fn read_strftime(template: &str) -> Result<Vec<Piece>, DayjsError> {
+    let mut pieces = Vec::new();
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if c != '%' {
+            push_literal(&mut pieces, &rest[..c.len_utf8()]);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        let literal = [("%%", "%"), ("%n", "\n"), ("%t", "\t")]
+            .into_iter()
+            .find(|(spec, _)| rest.starts_with(spec));
+        if rest.starts_with("%.3f") {
+            push_literal(&mut pieces, ".");
+            pieces.push(Piece::Field(Field::Millis));
+            rest = &rest[4..];
+        } else if let Some((spec, text)) = literal {
+            push_literal(&mut pieces, text);
+            rest = &rest[spec.len()..];
+        } else if let Some((spec, field)) = longest(&STRFTIME, rest) {
+            pieces.push(Piece::Field(field));
+            rest = &rest[spec.len()..];
+        } else {
+            let spec: String = rest.chars().take(2).collect();
+            let message = format!("unsupported specifier {}", spec);
+            return Err(error(FormatSyntax::Strftime, template, &message));
+        }
+    }
+    Ok(pieces)
+}
+
+/// runs of one letter are fields, `quote` delimits literal text
+fn letter_runs(template: &str, quote: char) -> Vec<(bool, String)> {
+    let mut runs: Vec<(bool, String)> = Vec::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == quote {
+            let mut text = String::new();
+            while let Some(q) = chars.next() {
+                if q != quote {
+                    text.push(q);
+                } else if chars.peek() == Some(&quote) {
+                    // a doubled quote inside quotes is the quote itself
+                    text.push(quote);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            runs.push((false, if text.is_empty() { quote.into() } else { text }));
+        } else if c.is_ascii_alphabetic() {
+            match runs.last_mut() {
+                Some((true, run)) if run.starts_with(c) => run.push(c),
+                _ => runs.push((true, c.to_string())),
+            }
+        } else {
+            runs.push((false, c.to_string()));
+        }
+    }
+    runs
+}
+
+fn read_java(template: &str) -> Result<Vec<Piece>, DayjsError> {
+    let mut pieces = Vec::new();
+    for (letters, run) in letter_runs(template, '\'') {
+        if !letters {
+            push_literal(&mut pieces, &run);
+            continue;
+        }
+        let n = run.len();
+        let field = match run.as_bytes()[0] {
+            b'y' | b'u' if n == 2 => Some(Field::Year2),
+            b'y' | b'u' => Some(Field::Year),
+            b'M' => Some(
+                [Field::Month, Field::MonthPadded, Field::MonthShort][..]
+                    .get(n - 1)
+                    .copied()
+                    .unwrap_or(Field::MonthName),
+            ),
+            b'D' if n == 3 => Some(Field::DayOfYearPadded),
+            b'D' => Some(Field::DayOfYear),
+            b'd' if n == 1 => Some(Field::Day),
+            b'd' => Some(Field::DayPadded),
+            b'E' if n >= 4 => Some(Field::WeekdayName),
+            b'E' => Some(Field::WeekdayShort),
+            b'H' if n == 1 => Some(Field::Hour),
+            b'H' => Some(Field::HourPadded),
+            b'h' if n == 1 => Some(Field::Hour12),
+            b'h' => Some(Field::Hour12Padded),
+            b'k' if n == 1 => Some(Field::Hour24),
+            b'k' => Some(Field::Hour24Padded),
+            b'z' if n >= 4 => Some(Field::ZoneName),
+            b'z' => Some(Field::ZoneShort),
+            b'm' if n == 1 => Some(Field::Minute),
+            b'm' => Some(Field::MinutePadded),
+            b's' if n == 1 => Some(Field::Second),
+            b's' => Some(Field::SecondPadded),
+            b'S' if n == 3 => Some(Field::Millis),
+            b'a' => Some(Field::Meridiem),
+            b'X' if n == 3 => Some(Field::OffsetZulu),
+            b'X' if n == 2 => Some(Field::OffsetBasicZulu),
+            b'x' if n == 3 => Some(Field::Offset),
+            b'x' if n == 2 => Some(Field::OffsetBasic),
+            b'Z' => Some(Field::OffsetBasic),
+            _ => None,
+        };
+        match field {
+            Some(field) => pieces.push(Piece::Field(field)),
+            None => {
+                let message = format!("unsupported pattern letters {}", run);
+                return Err(error(FormatSyntax::Java, template, &message));
+            }
+        }
+    }
+    Ok(pieces)
+}
+
+/// Excel codes are letter runs, `AM/PM`, `A/P` and `.000`, the rest is literal text
+fn excel_codes(template: &str) -> Vec<(bool, String)> {
+    let mut codes: Vec<(bool, String)> = Vec::new();
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        let lower = rest.to_ascii_lowercase();
+        let (code, len) = if let Some(special) = ["am/pm", "a/p", ".000"]
+            .into_iter()
+            .find(|special| lower.starts_with(special))
+        {
+            ((true, rest[..special.len()].to_string()), special.len())
+        } else if let Some(end) = rest.strip_prefix('[').and_then(|r| r.find(']')) {
+            let inner = &lower[1..end + 1];
+            let elapsed = inner
+                .chars()
+                .next()
+                .is_some_and(|c| "hms".contains(c) && inner.chars().all(|i| i == c));
+            // colors and locales like [Red] and [$-409] do not print
+            let code = if elapsed { &rest[..end + 2] } else { "" };
+            ((elapsed, code.to_string()), end + 2)
+        } else if c == '"' {
+            let end = rest[1..].find('"').map_or(rest.len(), |end| end + 2);
+            ((false, rest[1..end].trim_end_matches('"').to_string()), end)
+        } else if c == '\\' {
+            let len = rest[1..].chars().next().map_or(1, |c| 1 + c.len_utf8());
+            ((false, rest[1..len].to_string()), len)
+        } else if c.is_ascii_alphabetic() {
+            let len = rest
+                .find(|r: char| !r.eq_ignore_ascii_case(&c))
+                .unwrap_or(rest.len());
+            ((true, rest[..len].to_string()), len)
+        } else {
+            ((false, c.to_string()), c.len_utf8())
+        };
+        codes.push(code);
+        rest = &rest[len..];
+    }
+    codes
+}
+
+/// `m` is minutes right after hours or before seconds, and hours are 12 hour with `AM/PM`
+fn read_excel(template: &str) -> Result<Vec<Piece>, DayjsError> {
+    let codes = excel_codes(template);
+    let letter = |code: &(bool, String)| {
+        code.0
+            .then(|| code.1.chars().next().map(|c| c.to_ascii_lowercase()))
+            .flatten()
+    };
+    let twelve_hour = codes
+        .iter()
+        .any(|code| code.1.contains('/') && letter(code) == Some('a'));
+    let mut pieces = Vec::new();
+    for (i, code) in codes.iter().enumerate() {
+        let (is_code, text) = code;
+        if !is_code {
+            push_literal(&mut pieces, text);
+            continue;
+        }
+        let n = text.len();
+        let previous = codes[..i].iter().rev().find_map(letter);
+        let next = codes[i + 1..].iter().find_map(letter);
+        let field = match letter(code) {
+            _ if text == ".000" => {
+                push_literal(&mut pieces, ".");
+                Some(Field::Millis)
+            }
+            Some('[') => {
+                let message = format!("elapsed time {} needs a duration", text);
+                return Err(error(FormatSyntax::Excel, template, &message));
+            }
+            _ if text == "am/pm" => Some(Field::MeridiemLower),
+            _ if text.contains('/') => Some(Field::Meridiem),
+            Some('y') if n == 2 => Some(Field::Year2),
+            Some('y') => Some(Field::Year),
+            Some('m') if previous == Some('h') || next == Some('s') => {
+                Some([Field::Minute, Field::MinutePadded][n.min(2) - 1])
+            }
+            Some('m') => Some(
+                [
+                    Field::Month,
+                    Field::MonthPadded,
+                    Field::MonthShort,
+                    Field::MonthName,
+                ][n.min(4) - 1],
+            ),
+            Some('d') => Some(
+                [
+                    Field::Day,
+                    Field::DayPadded,
+                    Field::WeekdayShort,
+                    Field::WeekdayName,
+                ][n.min(4) - 1],
+            ),
+            Some('h') if twelve_hour => Some([Field::Hour12, Field::Hour12Padded][n.min(2) - 1]),
+            Some('h') => Some([Field::Hour, Field::HourPadded][n.min(2) - 1]),
+            Some('s') => Some([Field::Second, Field::SecondPadded][n.min(2) - 1]),
+            _ => None,
+        };
+        match field {
+            Some(field) => pieces.push(Piece::Field(field)),
+            None => {
+                let message = format!("unsupported format code {}", text);
+                return Err(error(FormatSyntax::Excel, template, &message));
+            }
+        }
+    }
+    Ok(pieces)
+}
+
+/// render a duration with an Excel number format, `[h]`, `[m]` and `[s]` are elapsed time and
+/// shorter units wrap around, see [`crate::duration::format_excel`]
+pub(crate) fn excel_duration(duration: &Duration, code: &str) -> Result<String, DayjsError> {
+    let total = duration.num_milliseconds().unsigned_abs();
+    let mut out = String::from(if *duration < Duration::zero() {
+        "-"
+    } else {
+        ""
+    });
+    for (is_code, text) in excel_codes(code) {
+        if !is_code {
+            out.push_str(&text);
+            continue;
+        }
+        let elapsed = text.starts_with('[');
+        let letters = text.trim_matches(['[', ']']).to_ascii_lowercase();
+        let (millis, wrap) = match letters.chars().next() {
+            Some('h') => (3_600_000, 24),
+            Some('m') => (60_000, 60),
+            Some('s') => (1_000, 60),
+            _ if text == ".000" => {
+                out.push_str(&format!(".{:03}", total % 1000));
+                continue;
+            }
+            _ => {
+                let message = format!("unsupported duration code {}", text);
+                return Err(error(FormatSyntax::Excel, code, &message));
+            }
+        };
+        let value = if elapsed {
+            total / millis
+        } else {
+            total / millis % wrap
+        };
+        out.push_str(&format!("{:0width$}", value, width = letters.len()));
+    }
+    Ok(out)
+}
+
+impl Dayjs {
+    /// format with an Excel custom number format like `yyyy\-mm\-dd hh:mm`, `m` is minutes right
+    /// after hours or before seconds and hours are 12 hour with `AM/PM`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415250);
+    /// assert_eq!(date.format_excel("yyyy\\-mm\\-dd hh:mm").unwrap(), "2024-01-05 08:30");
+    /// assert_eq!(date.format_excel("[$-409]dddd, mmmm d h AM/PM").unwrap(), "Friday, January 5 8 AM");
+    /// assert!(date.format_excel("[h]:mm").is_err());
+    /// ```
+    pub fn format_excel(&self, code: &str) -> Result<String, DayjsError> {
+        let template = convert_format(code, FormatSyntax::Excel, FormatSyntax::Dayjs)?;
+        Ok(self.format(&template))
+    }
+}
+
+impl Dayjs {
+    /// format with a Java `SimpleDateFormat` or `DateTimeFormatter` pattern like
+    /// `yyyy-MM-dd'T'HH:mm:ssXXX`, names are in the active locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::DayjsTimeZone;
+    /// let date = dayjs::from_timestamp(1704443415250);
+    /// assert_eq!(date.format_java("yyyy-MM-dd'T'HH:mm:ssXXX").unwrap(), "2024-01-05T08:30:15Z");
+    /// let tokyo = date.tz(DayjsTimeZone::Offset(9 * 3600));
+    /// assert_eq!(tokyo.format_java("EEE, d MMM yyyy HH:mm:ss Z").unwrap(), "Fri, 5 Jan 2024 17:30:15 +0900");
+    /// assert_eq!(tokyo.format_java("D 'o''clock' XX").unwrap(), "5 o'clock +0900");
+    /// ```
+    pub fn format_java(&self, pattern: &str) -> Result<String, DayjsError> {
+        self.render(read_java(pattern)?, FormatSyntax::Java, pattern)
+    }
+
+    /// format with a Go layout like `2006-01-02 15:04:05 -0700`, names are in the active locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415250);
+    /// assert_eq!(date.format_go("2006-01-02 15:04:05.000 -0700").unwrap(), "2024-01-05 08:30:15.250 +0000");
+    /// assert_eq!(date.format_go("Mon Jan 2 3:04PM Z07:00").unwrap(), "Fri Jan 5 8:30AM Z");
+    /// assert!(date.format_go("2006-01-02 MST").is_err());
+    /// ```
+    pub fn format_go(&self, layout: &str) -> Result<String, DayjsError> {
+        self.render(read_go(layout)?, FormatSyntax::Go, layout)
+    }
+
+    /// format pieces of another syntax with dayjs tokens
+    fn render(
+        &self,
+        pieces: Vec<Piece>,
+        syntax: FormatSyntax,
+        template: &str,
+    ) -> Result<String, DayjsError> {
+        let utc = self.utc_offset() == 0;
+        let mut out = String::new();
+        for piece in pieces {
+            match piece {
+                Piece::Literal(text) => out.push_str(&text),
+                Piece::Field(Field::OffsetZulu | Field::OffsetBasicZulu) if utc => out.push('Z'),
+                Piece::Field(Field::DayOfYearPadded) => {
+                    out.push_str(&format!("{:03}", self.datetime().ordinal()))
+                }
+                Piece::Field(Field::DayOfYear) => {
+                    out.push_str(&self.datetime().ordinal().to_string())
+                }
+                Piece::Field(field) => match token(FormatSyntax::Dayjs, field, None) {
+                    Some(token) => out.push_str(&self.format(token)),
+                    None => {
+                        let message = format!("{:?} cannot be formatted", field);
+                        return Err(error(syntax, template, &message));
+                    }
+                },
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// parse with a Java `SimpleDateFormat` or `DateTimeFormatter` pattern, see
+/// [`crate::from_format_dayjs`] for defaults; `X` offsets accept `Z` for UTC
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_format_java("2024-01-05T17:30:15+09:00", "yyyy-MM-dd'T'HH:mm:ssXXX");
+/// assert_eq!(date.unwrap().to_string(), "2024-01-05T08:30:15+00:00");
+/// let date = dayjs::from_format_java("05/01/2024 8:30 PM", "dd/MM/yyyy h:mm a").unwrap();
+/// assert_eq!(date.format("YYYY-MM-DD HH:mm"), "2024-01-05 20:30");
+/// ```
+pub fn from_format_java(input: &str, pattern: &str) -> Result<Dayjs, DayjsError> {
+    let template = convert_format(pattern, FormatSyntax::Java, FormatSyntax::Dayjs)?;
+    from_format_dayjs(input, &template)
+}
+
+/// parse with a Go layout, see [`crate::from_format_dayjs`] for defaults; `Z07:00` accepts `Z`
+/// for UTC
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_format_go("2024-01-05 17:30:15 +0900", "2006-01-02 15:04:05 -0700");
+/// assert_eq!(date.unwrap().to_string(), "2024-01-05T08:30:15+00:00");
+/// ```
+pub fn from_format_go(input: &str, layout: &str) -> Result<Dayjs, DayjsError> {
+    let template = convert_format(layout, FormatSyntax::Go, FormatSyntax::Dayjs)?;
+    from_format_dayjs(input, &template)
+}
+
+fn read(template: &str, syntax: FormatSyntax) -> Result<Vec<Piece>, DayjsError> {
+    match syntax {
+        // localized tokens like "LL" read as the active locale's template
+        FormatSyntax::Dayjs => Ok(read_tokens(
+            &expand_localized(template, Locale::current()),
+            &DAYJS,
+        )),
+        FormatSyntax::Moment => {
+            let table: Vec<_> = DAYJS.iter().chain(MOMENT.iter()).copied().collect();
+            let template = expand_localized(template, Locale::current());
+            Ok(read_tokens(&template, &table))
+        }
+        FormatSyntax::Strftime => read_strftime(template),
+        FormatSyntax::Java => read_java(template),
+        FormatSyntax::Excel => read_excel(template),
+        FormatSyntax::Go => read_go(template),
+    }
+}
+
+fn table(syntax: FormatSyntax) -> &'static [(&'static str, Field)] {
+    match syntax {
+        FormatSyntax::Dayjs => &DAYJS,
+        FormatSyntax::Moment => &MOMENT,
+        FormatSyntax::Strftime => &STRFTIME,
+        FormatSyntax::Java => &JAVA,
+        FormatSyntax::Excel => &EXCEL,
+        FormatSyntax::Go => &GO,
+    }
+}
+
+/// the token of a field, moment falls back to the dayjs tokens
+fn token(syntax: FormatSyntax, field: Field, previous: Option<Field>) -> Option<&'static str> {
+    // only Java and Go print Z at UTC
+    let field = match (syntax, field) {
+        (FormatSyntax::Java | FormatSyntax::Go, _) => field,
+        (_, Field::OffsetZulu) => Field::Offset,
+        (_, Field::OffsetBasicZulu) => Field::OffsetBasic,
+        _ => field,
+    };
+    let find = |table: &[(&'static str, Field)]| {
+        table
+            .iter()
+            .find(|(_, f)| *f == field)
+            .map(|(token, _)| *token)
+    };
+    match (syntax, field) {
+        (FormatSyntax::Moment, _) => find(&MOMENT).or_else(|| find(&DAYJS)),
+        (FormatSyntax::Excel, Field::Hour12Padded) => Some("hh"),
+        (FormatSyntax::Excel, Field::Hour12) => Some("h"),
+        // a lone "mm" is the month in Excel
+        (FormatSyntax::Excel, Field::Minute | Field::MinutePadded)
+            if !matches!(
+                previous,
+                Some(Field::Hour | Field::HourPadded | Field::Hour12 | Field::Hour12Padded)
+            ) =>
+        {
+            None
+        }
+        (FormatSyntax::Excel, Field::MinutePadded) => Some("mm"),
+        (FormatSyntax::Excel, Field::Minute) => Some("m"),
+        (syntax, _) => find(table(syntax)),
+    }
+}
+
+fn write(pieces: &[Piece], syntax: FormatSyntax, template: &str) -> Result<String, DayjsError> {
+    let mut out = String::new();
+    let mut previous = None;
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Field(field) => {
+                let Some(token) = token(syntax, *field, previous) else {
+                    let message = format!("{:?} has no {} token", field, syntax.grammar());
+                    return Err(DayjsError::parse("format template", template, &message));
+                };
+                out.push_str(token);
+                previous = Some(*field);
+            }
+            Piece::Literal(text) => {
+                let next = match pieces.get(i + 1) {
+                    Some(Piece::Field(field)) => Some(*field),
+                    _ => None,
+                };
+                out.push_str(&escape(syntax, text, next));
+            }
+        }
+    }
+    let has = |fields: &[Field]| {
+        pieces
+            .iter()
+            .any(|p| matches!(p, Piece::Field(f) if fields.contains(f)))
+    };
+    let meridiem = has(&[Field::Meridiem, Field::MeridiemLower]);
+    if syntax == FormatSyntax::Excel && has(&[Field::Hour, Field::HourPadded]) && meridiem {
+        let message = "Excel shows 12 hour clocks with AM/PM";
+        return Err(DayjsError::parse("format template", template, message));
+    }
+    if syntax == FormatSyntax::Excel && has(&[Field::Hour12, Field::Hour12Padded]) && !meridiem {
+        let message = "Excel needs AM/PM for a 12 hour clock";
+        return Err(DayjsError::parse("format template", template, message));
+    }
+    if syntax == FormatSyntax::Go && read_go(&out).ok().as_deref() != Some(pieces) {
+        let message = "text would read as Go layout chunks";
+        return Err(DayjsError::parse("format template", template, message));
+    }
+    Ok(out)
+}
+
+/// quote the runs of text that are not plain
+fn quote_runs(text: &str, plain: impl Fn(char) -> bool, quote: impl Fn(&str) -> String) -> String {
+    let mut out = String::new();
+    let mut run = String::new();
+    for c in text.chars() {
+        if plain(c) {
+            if !run.is_empty() {
+                out.push_str(&quote(&run));
+                run.clear();
+            }
+            out.push(c);
+        } else {
+            run.push(c);
+        }
+    }
+    if !run.is_empty() {
+        out.push_str(&quote(&run));
+    }
+    out
+}
+
+/// literal text that reads back as itself
+fn escape(syntax: FormatSyntax, text: &str, next: Option<Field>) -> String {
+    let plain = |c: char| !c.is_alphanumeric();
+    match syntax {
+        FormatSyntax::Dayjs | FormatSyntax::Moment => {
+            // "]" cannot be escaped but is no token either
+            let quote = |part: &str| {
+                let tokens = read_tokens(part, &DAYJS).len() > 1
+                    || read_tokens(part, &MOMENT).len() > 1
+                    || part.contains(['[', 'L', 'l']);
+                if tokens {
+                    format!("[{}]", part)
+                } else {
+                    part.to_string()
+                }
+            };
+            text.split(']').map(quote).collect::<Vec<_>>().join("]")
+        }
+        FormatSyntax::Strftime => text.replace('%', "%%"),
+        FormatSyntax::Go => text.to_string(),
+        FormatSyntax::Java => quote_runs(
+            text,
+            |c| plain(c) && c != '\'',
+            |run| {
+                if run == "'" {
+                    "''".to_string()
+                } else {
+                    format!("'{}'", run.replace('\'', "''"))
+                }
+            },
+        ),
+        // "ss.000" reads the dot as part of the milliseconds
+        FormatSyntax::Excel if text.ends_with('.') && next == Some(Field::Millis) => {
+            escape(syntax, &text[..text.len() - 1], None)
+        }
+        FormatSyntax::Excel => quote_runs(
+            text,
+            |c| plain(c) && c != '"' && c != '\\',
+            |run| {
+                // no escapes inside quotes, a quote is written as \"
+                run.split('"')
+                    .map(|part| match part {
+                        "" => String::new(),
+                        part => format!("\"{}\"", part),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\\\"")
+            },
+        ),
+    }
+}
+
+/// translate a date format template between dayjs, moment, strftime, Java `SimpleDateFormat` and
+/// Excel syntax, literal text is escaped for the target
+///
+/// fails for tokens that are unsupported in the source or have no equivalent in the target,
+/// like `Do` in strftime or an offset in Excel
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{convert_format, FormatSyntax};
+/// let dayjs = convert_format("%Y-%m-%d %H:%M:%S", FormatSyntax::Strftime, FormatSyntax::Dayjs);
+/// assert_eq!(dayjs.unwrap(), "YYYY-MM-DD HH:mm:ss");
+/// let java = convert_format("ddd, D MMM YYYY [at] h:mm A", FormatSyntax::Dayjs, FormatSyntax::Java);
+/// assert_eq!(java.unwrap(), "EEE, d MMM yyyy 'at' h:mm a");
+/// let excel = convert_format("dd/MM/yyyy HH:mm", FormatSyntax::Java, FormatSyntax::Excel);
+/// assert_eq!(excel.unwrap(), "dd/mm/yyyy hh:mm");
+/// assert!(convert_format("Do MMMM", FormatSyntax::Dayjs, FormatSyntax::Strftime).is_err());
+/// ```
+pub fn convert_format(
+    template: &str,
+    from: FormatSyntax,
+    to: FormatSyntax,
+) -> Result<String, DayjsError> {
+    let pieces = read(template, from)?;
+    write(&pieces, to, template)
+}
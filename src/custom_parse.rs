@@ -0,0 +1,209 @@
+use crate::format::{expand_localized, tokenize, Piece};
+use crate::locale::Locale;
+use crate::parse::{offset, time, zoned, Cursor};
+use crate::{Dayjs, DayjsError, DayjsTimeZone};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+/// fields read from the input
+#[derive(Default)]
+struct Fields {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    millis: u32,
+    pm: Option<bool>,
+    offset: Option<i32>,
+    /// unix milliseconds of `X` or `x`, which fix the instant
+    unix_millis: Option<i64>,
+}
+
+/// index of the longest name the input continues with, ignoring case
+fn name(c: &mut Cursor, names: &[&str], what: &str) -> Result<usize, DayjsError> {
+    let rest = c.rest();
+    let found = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| {
+            !name.is_empty()
+                && rest
+                    .get(..name.len())
+                    .is_some_and(|head| head.to_lowercase() == name.to_lowercase())
+        })
+        .max_by_key(|(_, name)| name.len());
+    match found {
+        Some((index, name)) => {
+            c.advance(name.len());
+            Ok(index)
+        }
+        None => Err(c.error(&format!("expected {}", what))),
+    }
+}
+
+/// a day of the month written as a locale ordinal, e.g. `5th`
+fn ordinal_day(c: &mut Cursor, locale: &Locale) -> Result<u32, DayjsError> {
+    let ordinals: Vec<String> = (1..=31).map(|day| locale.ordinal(day)).collect();
+    let names: Vec<&str> = ordinals.iter().map(String::as_str).collect();
+    Ok(name(c, &names, "day of the month")? as u32 + 1)
+}
+
+/// one or two digits, the first two of a longer run like `0105` in `MMDD`
+fn up_to_two(c: &mut Cursor, what: &str) -> Result<u32, DayjsError> {
+    if c.digit_run() > 2 {
+        c.digits(2, what)
+    } else {
+        Ok(c.number(2, what)? as u32)
+    }
+}
+
+/// parse with dayjs style tokens in `locale`, literal text is skipped by its length
+pub(crate) fn parse_format(
+    input: &str,
+    template: &str,
+    locale: &Locale,
+) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(input, "dayjs format");
+    let mut f = Fields::default();
+    for piece in tokenize(&expand_localized(template, locale)) {
+        let token = match piece {
+            Piece::Literal(s) | Piece::Escaped(s) => {
+                for _ in s.chars() {
+                    match c.rest().chars().next() {
+                        Some(ch) => c.advance(ch.len_utf8()),
+                        None => return Err(c.error(&format!("expected {:?}", s))),
+                    }
+                }
+                continue;
+            }
+            Piece::Token(token) => token,
+        };
+        match token {
+            "YYYY" => f.year = Some(c.digits(4, "year")? as i32),
+            "YY" => {
+                let year = c.digits(2, "year")? as i32;
+                f.year = Some(year + if year > 68 { 1900 } else { 2000 });
+            }
+            "Q" => {
+                c.digits(1, "quarter")?;
+            }
+            "MMMM" => {
+                let names: Vec<&str> = locale
+                    .months_format
+                    .iter()
+                    .chain(&locale.months_standalone)
+                    .copied()
+                    .collect();
+                f.month = Some(name(&mut c, &names, "month name")? as u32 % 12 + 1);
+            }
+            "MMM" => f.month = Some(name(&mut c, &locale.months_short, "month name")? as u32 + 1),
+            "MM" | "M" => f.month = Some(up_to_two(&mut c, "month")?),
+            "DD" | "D" => f.day = Some(up_to_two(&mut c, "day")?),
+            "Do" => f.day = Some(ordinal_day(&mut c, locale)?),
+            // the weekday is read but not checked against the date, like dayjs
+            "dddd" => {
+                name(&mut c, &locale.weekdays, "weekday name")?;
+            }
+            "ddd" => {
+                name(&mut c, &locale.weekdays_short, "weekday name")?;
+            }
+            "d" => {
+                c.digits(1, "weekday")?;
+            }
+            "HH" | "H" | "hh" | "h" => f.hour = up_to_two(&mut c, "hour")?,
+            "kk" | "k" => f.hour = up_to_two(&mut c, "hour")? % 24,
+            "mm" | "m" => f.minute = up_to_two(&mut c, "minute")?,
+            "ss" | "s" => f.second = up_to_two(&mut c, "second")?,
+            "SSS" => f.millis = c.digits(3, "millisecond")?,
+            "A" | "a" => f.pm = Some(name(&mut c, &locale.meridiem, "AM or PM")? == 1),
+            "X" | "x" => {
+                let negative = c.eat(b'-');
+                let n = c.number(19, "unix time")? as i64;
+                let n = if negative { -n } else { n };
+                f.unix_millis = Some(if token == "X" {
+                    n.saturating_mul(1000)
+                } else {
+                    n
+                });
+            }
+            "zzz" | "z" => return Err(c.error("timezone names cannot be parsed")),
+            _ => {
+                // "Z" and "ZZ", `Z` keeps the default timezone like RFC 3339
+                f.offset = if c.eat(b'Z') {
+                    None
+                } else {
+                    let negative = c.sign()?;
+                    Some(offset(&mut c, negative, false)?)
+                };
+            }
+        }
+    }
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    if let Some(millis) = f.unix_millis {
+        let time = DateTime::from_timestamp_millis(millis)
+            .ok_or_else(|| c.error("unix time out of range"))?;
+        let tz = f
+            .offset
+            .map_or(DayjsTimeZone::DEFAULT, DayjsTimeZone::Offset);
+        return Ok(Dayjs { tz, time });
+    }
+    let hour = match f.pm {
+        Some(_) if !(1..=12).contains(&f.hour) => {
+            return Err(c.error("hour out of range for a 12 hour clock"))
+        }
+        Some(pm) => f.hour % 12 + if pm { 12 } else { 0 },
+        None => f.hour,
+    };
+    let year = f.year.unwrap_or_else(|| Utc::now().year());
+    let date = NaiveDate::from_ymd_opt(year, f.month.unwrap_or(1), f.day.unwrap_or(1))
+        .ok_or_else(|| c.error("date out of range"))?;
+    let time = time(&c, hour, f.minute, f.second, f.millis * 1_000_000)?;
+    Ok(zoned(date.and_time(time), f.offset))
+}
+
+/// parse with a dayjs style template, so templates can be shared with dayjs frontend code
+///
+/// supports the [`Dayjs::format`] tokens, names are read in the active locale and literal
+/// text is skipped by its length; a missing year is the current year, a missing month or day
+/// is the first, and numeric offsets are kept in the tz
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_format_dayjs("12-25-2023", "MM-DD-YYYY").unwrap();
+/// assert_eq!(date.to_string(), "2023-12-25T00:00:00+00:00");
+/// let date = dayjs::from_format_dayjs("Jan 5th 24, 8:30 pm +08:00", "MMM Do YY, h:mm a Z");
+/// assert_eq!(date.unwrap().format("YYYY-MM-DD HH:mm Z"), "2024-01-05 20:30 +08:00");
+/// assert!(dayjs::from_format_dayjs("02-30-2024", "MM-DD-YYYY").is_err());
+/// ```
+pub fn from_format_dayjs(input: &str, template: &str) -> Result<Dayjs, DayjsError> {
+    parse_format(input, template, Locale::current())
+}
+
+/// like [`from_format_dayjs`] but the input must be exactly what formatting the result with
+/// the template gives, like `dayjs(input, template, true)`
+///
+/// # Examples
+///
+/// ```
+/// assert!(dayjs::from_format_dayjs_strict("2023-12-25", "YYYY-MM-DD").is_ok());
+/// assert!(dayjs::from_format_dayjs_strict("2023-12-5", "YYYY-MM-DD").is_err());
+/// assert!(dayjs::from_format_dayjs_strict("2023/12/25", "YYYY-MM-DD").is_err());
+/// assert!(dayjs::from_format_dayjs_strict("Mon 2023-12-25", "ddd YYYY-MM-DD").is_ok());
+/// assert!(dayjs::from_format_dayjs_strict("Tue 2023-12-25", "ddd YYYY-MM-DD").is_err());
+/// ```
+pub fn from_format_dayjs_strict(input: &str, template: &str) -> Result<Dayjs, DayjsError> {
+    let locale = Locale::current();
+    let date = parse_format(input, template, locale)?;
+    if date.format_locale(template, locale) != input {
+        return Err(DayjsError::parse(
+            "dayjs format",
+            input,
+            &format!("does not match {:?} exactly", template),
+        ));
+    }
+    Ok(date)
+}
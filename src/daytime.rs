@@ -0,0 +1,203 @@
+use crate::{Dayjs, DayjsError, DayjsTimeZone};
+use chrono::{Duration, NaiveTime, Timelike};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// wall clock time of day, e.g. a 09:00 handoff or send time
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayjsTime(NaiveTime);
+
+impl DayjsTime {
+    pub const MIDNIGHT: DayjsTime = DayjsTime(NaiveTime::MIN);
+
+    /// time of day from hour, minute and second
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::DayjsTime;
+    /// assert_eq!(DayjsTime::new(9, 30, 0).unwrap().to_string(), "09:30:00");
+    /// assert!(DayjsTime::new(24, 0, 0).is_err());
+    /// ```
+    pub fn new(hour: u32, minute: u32, second: u32) -> Result<DayjsTime, DayjsError> {
+        NaiveTime::from_hms_opt(hour, minute, second)
+            .map(DayjsTime)
+            .ok_or_else(|| {
+                DayjsError::OutOfRange(format!(
+                    "{:02}:{:02}:{:02} is not a time of day",
+                    hour, minute, second
+                ))
+            })
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.0.minute()
+    }
+
+    pub fn second(&self) -> u32 {
+        self.0.second()
+    }
+
+    pub(crate) fn naive(&self) -> NaiveTime {
+        self.0
+    }
+}
+
+impl Display for DayjsTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format("%H:%M:%S"))
+    }
+}
+
+impl FromStr for DayjsTime {
+    type Err = DayjsError;
+
+    /// parse `HH:mm` or `HH:mm:ss`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ["%H:%M:%S", "%H:%M"]
+            .iter()
+            .find_map(|f| NaiveTime::parse_from_str(s, f).ok())
+            .map(DayjsTime)
+            .ok_or_else(|| DayjsError::parse("time of day", s, "expected HH:mm or HH:mm:ss"))
+    }
+}
+
+impl Dayjs {
+    /// wall clock time of day in the instance timezone
+    pub fn time_of_day(&self) -> DayjsTime {
+        DayjsTime(
+            self.datetime()
+                .time()
+                .with_nanosecond(0)
+                .unwrap_or(NaiveTime::MIN),
+        )
+    }
+
+    /// the same date at another wall clock time in the instance timezone
+    ///
+    /// a time skipped by a DST gap lands after the gap, an ambiguous time takes the earlier instant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// let nine = date.with_time("09:00".parse().unwrap());
+    /// assert_eq!(nine.to_string(), "2024-01-05T09:00:00+00:00");
+    /// ```
+    pub fn with_time(&self, time: DayjsTime) -> Dayjs {
+        let local = self.datetime().date_naive().and_time(time.naive());
+        Dayjs {
+            time: self.tz.local_to_utc(local),
+            ..*self
+        }
+    }
+
+    /// the first instant from this one on when the wall clock in `tz` reads `time`, e.g. the
+    /// next 9am for a recipient, carrying `tz`; see [`Dayjs::with_time`] for the same date
+    ///
+    /// a time skipped by a DST gap lands after the gap; on the last supported day, which has no
+    /// next one, it is that day's even when already passed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::DayjsTimeZone;
+    /// let tokyo = DayjsTimeZone::Offset(9 * 3600);
+    /// let nine = "09:00".parse().unwrap();
+    /// // 2024-01-05T08:30:00Z is already 17:30 in tokyo
+    /// let send = dayjs::from_timestamp(1704443400).at_local_time_in_zone(nine, &tokyo);
+    /// assert_eq!(send.to_string(), "2024-01-06T00:00:00+00:00");
+    /// assert_eq!(send.format("YYYY-MM-DD HH:mm"), "2024-01-06 09:00");
+    /// ```
+    pub fn at_local_time_in_zone(&self, time: DayjsTime, tz: &DayjsTimeZone) -> Dayjs {
+        let today = self.tz(*tz).with_time(time);
+        if today.time >= self.time {
+            return today;
+        }
+        // the last supported day has no next one
+        let Some(tomorrow) = today.datetime().date_naive().succ_opt() else {
+            return today;
+        };
+        Dayjs {
+            time: tz.local_to_utc(tomorrow.and_time(time.naive())),
+            ..today
+        }
+    }
+
+    /// time left from this instant until `deadline`, zero once it has passed, e.g. a cache TTL
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let now = dayjs::from_timestamp(1704443400);
+    /// let deadline = now.add(90, dayjs::Unit::Second);
+    /// assert_eq!(now.ttl_until(&deadline).as_secs(), 90);
+    /// assert!(deadline.ttl_until(&now).is_zero());
+    /// ```
+    pub fn ttl_until(&self, deadline: &Dayjs) -> std::time::Duration {
+        (deadline.time - self.time).to_std().unwrap_or_default()
+    }
+
+    /// whole seconds until the wall clock in `tz` next reads `time`, rounded up so a key expiring
+    /// after them outlives the boundary; at the boundary itself it is the next day's, so never 0
+    /// before the last supported day
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTime, DayjsTimeZone};
+    /// let shanghai = DayjsTimeZone::Offset(8 * 3600);
+    /// // 16:30 in shanghai, expire at the next local midnight
+    /// let now = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(now.seconds_until_local(DayjsTime::MIDNIGHT, &shanghai), 27_000);
+    /// ```
+    pub fn seconds_until_local(&self, time: DayjsTime, tz: &DayjsTimeZone) -> u64 {
+        let after = Dayjs {
+            time: self
+                .time
+                .checked_add_signed(Duration::nanoseconds(1))
+                .unwrap_or(self.time),
+            ..*self
+        };
+        let ttl = self.ttl_until(&after.at_local_time_in_zone(time, tz));
+        ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0)
+    }
+}
+
+/// the instant `time` on the calendar date of `date` is reached in each zone, earliest first,
+/// e.g. to queue "9am local everywhere" in one pass; zones reaching it together keep their order
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::DayjsTimeZone;
+/// let zones = [DayjsTimeZone::Offset(-5 * 3600), DayjsTimeZone::Offset(9 * 3600)];
+/// let date = dayjs::from_timestamp(1704443400);
+/// let sends: Vec<_> = dayjs::fanout_local_time("09:00".parse().unwrap(), &zones, &date)
+///     .map(|(_, at)| at.to_string())
+///     .collect();
+/// assert_eq!(sends, ["2024-01-05T00:00:00+00:00", "2024-01-05T14:00:00+00:00"]);
+/// ```
+pub fn fanout_local_time(
+    time: DayjsTime,
+    zones: &[DayjsTimeZone],
+    date: &Dayjs,
+) -> impl Iterator<Item = (DayjsTimeZone, Dayjs)> {
+    let local = date.datetime().date_naive().and_time(time.naive());
+    let mut sends: Vec<_> = zones
+        .iter()
+        .map(|tz| {
+            let at = Dayjs {
+                tz: *tz,
+                time: tz.local_to_utc(local),
+            };
+            (*tz, at)
+        })
+        .collect();
+    sends.sort_by_key(|(_, at)| at.time);
+    sends.into_iter()
+}
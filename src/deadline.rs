@@ -0,0 +1,115 @@
+use crate::{parse_rfc3339_strict, Dayjs, DayjsError, Epoch, Precision};
+use chrono::SecondsFormat;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// HTTP header carrying a [`Deadline`] between services
+pub const HEADER: &str = "X-Deadline";
+
+/// wire form of a [`Deadline`] header value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HeaderFormat {
+    /// `2024-01-05T08:30:00.000Z`
+    Rfc3339,
+    /// `1704443400000`
+    EpochMillis,
+}
+
+/// instant a request must be answered by, passed along with the calls it makes
+///
+/// the header value is read in either [`HeaderFormat`], see [`Deadline::from_header`]
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::deadline::{Deadline, HeaderFormat};
+/// let deadline = Deadline::from_header("1704443400250").unwrap();
+/// assert!(deadline.is_exceeded());
+/// assert_eq!(deadline.to_header(HeaderFormat::Rfc3339), "2024-01-05T08:30:00.250Z");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Deadline(pub Dayjs);
+
+impl Deadline {
+    /// the deadline `timeout` from now, saturating at the latest supported instant
+    pub fn after(timeout: std::time::Duration) -> Deadline {
+        let timeout = chrono::Duration::from_std(timeout).unwrap_or(chrono::Duration::MAX);
+        let now = crate::dayjs();
+        Deadline(Dayjs {
+            time: now
+                .time
+                .checked_add_signed(timeout)
+                .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC),
+            ..now
+        })
+    }
+
+    /// time left until the deadline, zero once it has passed
+    pub fn remaining(&self) -> std::time::Duration {
+        crate::dayjs().ttl_until(&self.0)
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        self.0.time <= chrono::Utc::now()
+    }
+
+    /// the header value in `format`, UTC with milliseconds
+    pub fn to_header(&self, format: HeaderFormat) -> String {
+        match format {
+            HeaderFormat::Rfc3339 => self.0.time.to_rfc3339_opts(SecondsFormat::Millis, true),
+            HeaderFormat::EpochMillis => self
+                .0
+                .to_epoch(Epoch::Unix, Precision::Milliseconds)
+                .to_string(),
+        }
+    }
+
+    /// read a header value, digits are epoch milliseconds and anything else RFC 3339; the
+    /// deadline is kept in UTC
+    pub fn from_header(value: &str) -> Result<Deadline, DayjsError> {
+        let value = value.trim();
+        if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+            let millis = value
+                .parse()
+                .map_err(|_| DayjsError::parse("deadline", value, "too many digits"))?;
+            let time = chrono::DateTime::from_timestamp_millis(millis)
+                .ok_or(DayjsError::InvalidTimestamp(millis))?;
+            return Ok(Deadline(Dayjs {
+                time,
+                ..Default::default()
+            }));
+        }
+        parse_rfc3339_strict(value).map(|date| Deadline(date.utc()))
+    }
+
+    /// the tokio instant of the deadline, for `tokio::time::timeout_at` and `sleep_until`;
+    /// deadlines the monotonic clock cannot reach are 30 years away, like tokio's far future
+    #[cfg(feature = "tokio")]
+    pub fn to_tokio_instant(&self) -> tokio::time::Instant {
+        let now = tokio::time::Instant::now();
+        now.checked_add(self.remaining())
+            .unwrap_or_else(|| now + std::time::Duration::from_secs(86_400 * 365 * 30))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<Deadline> for tokio::time::Instant {
+    fn from(deadline: Deadline) -> Self {
+        deadline.to_tokio_instant()
+    }
+}
+
+impl Display for Deadline {
+    /// the [`HeaderFormat::Rfc3339`] header value
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_header(HeaderFormat::Rfc3339))
+    }
+}
+
+impl FromStr for Deadline {
+    type Err = DayjsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Deadline::from_header(s)
+    }
+}
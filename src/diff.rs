@@ -0,0 +1,81 @@
+use crate::duration::unit_millis;
+use crate::{Dayjs, Unit};
+
+/// months from `b` to `a` with the fraction of the month around the anchor, the dayjs
+/// `monthDiff` algorithm
+fn month_diff(a: &Dayjs, b: &Dayjs) -> f64 {
+    if a.date() < b.date() {
+        return -month_diff(b, a);
+    }
+    let whole = i64::from(b.year() - a.year()) * 12 + i64::from(b.month()) - i64::from(a.month());
+    let anchor = a.add(whole, Unit::Month);
+    let before = b.time < anchor.time;
+    let next = a.add(whole + if before { -1 } else { 1 }, Unit::Month);
+    let span = if before { anchor - next } else { next - anchor };
+    let fraction = (*b - anchor).num_milliseconds() as f64 / span.num_milliseconds() as f64;
+    let months = -(whole as f64 + fraction);
+    if months.is_nan() || months == 0.0 {
+        0.0
+    } else {
+        months
+    }
+}
+
+impl Dayjs {
+    /// difference `self - other` in a unit with the fraction, like `a.diff(b, unit, true)` in
+    /// dayjs
+    ///
+    /// months, quarters and years count calendar months in the instance timezone, days and
+    /// weeks ignore DST offset changes, shorter units are elapsed time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let a = dayjs::from_timestamp(1704067200);
+    /// let b = a.add(1, Unit::Month).add(15, Unit::Day).add(12, Unit::Hour);
+    /// assert_eq!(b.diff_f64(&a, Unit::Month), 1.5);
+    /// assert_eq!(b.diff_f64(&a, Unit::Day), 46.5);
+    /// assert_eq!(a.diff_f64(&b, Unit::Hour), -1116.0);
+    /// ```
+    pub fn diff_f64(&self, other: &Dayjs, unit: Unit) -> f64 {
+        let that = other.tz(self.tz);
+        let elapsed = (self.time - that.time).num_milliseconds() as f64;
+        let zone_delta = f64::from(that.utc_offset() - self.utc_offset()) * 60_000.0;
+        match unit {
+            Unit::Month => month_diff(self, &that),
+            Unit::Quarter => month_diff(self, &that) / 3.0,
+            Unit::Year => month_diff(self, &that) / 12.0,
+            Unit::Day | Unit::Week | Unit::Isoweek => (elapsed - zone_delta) / unit_millis(unit),
+            _ => elapsed / unit_millis(unit),
+        }
+    }
+
+    /// difference `self - other` in whole units, truncated towards zero like `a.diff(b, unit)` in
+    /// dayjs, see [`Dayjs::diff_f64`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let end_of_january = dayjs::from_str("2024-01-31T00:00:00Z");
+    /// let february = dayjs::from_str("2024-02-01T00:00:00Z");
+    /// assert_eq!(february.diff(&end_of_january, Unit::Month), 0);
+    /// assert_eq!(february.diff(&end_of_january, Unit::Hour), 24);
+    /// assert_eq!(end_of_january.diff(&february, Unit::Minute), -1440);
+    /// ```
+    pub fn diff(&self, other: &Dayjs, unit: Unit) -> i64 {
+        self.diff_f64(other, unit) as i64
+    }
+
+    /// whole calendar months from `other` to `self`, the month after january 31 ends on the last
+    /// day of february
+    pub fn diff_months(&self, other: &Dayjs) -> i64 {
+        self.diff(other, Unit::Month)
+    }
+
+    /// whole calendar years from `other` to `self`
+    pub fn diff_years(&self, other: &Dayjs) -> i64 {
+        self.diff(other, Unit::Year)
+    }
+}
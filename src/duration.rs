@@ -0,0 +1,322 @@
+use crate::locale::Locale;
+use crate::{DayjsError, Unit};
+use chrono::Duration;
+use std::fmt::{Display, Formatter};
+
+/// relative time phrase picked by the thresholds
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Phrase {
+    Second,
+    Minute,
+    Minutes,
+    Hour,
+    Hours,
+    Day,
+    Days,
+    Month,
+    Months,
+    Year,
+    Years,
+}
+
+struct Threshold {
+    phrase: Phrase,
+    /// largest rounded value rendered with this phrase
+    max: Option<i64>,
+    /// unit the difference is measured in from this step on
+    unit: Option<Unit>,
+}
+
+/// dayjs relative time thresholds
+const THRESHOLDS: [Threshold; 11] = [
+    Threshold {
+        phrase: Phrase::Second,
+        max: Some(44),
+        unit: Some(Unit::Second),
+    },
+    Threshold {
+        phrase: Phrase::Minute,
+        max: Some(89),
+        unit: None,
+    },
+    Threshold {
+        phrase: Phrase::Minutes,
+        max: Some(44),
+        unit: Some(Unit::Minute),
+    },
+    Threshold {
+        phrase: Phrase::Hour,
+        max: Some(89),
+        unit: None,
+    },
+    Threshold {
+        phrase: Phrase::Hours,
+        max: Some(21),
+        unit: Some(Unit::Hour),
+    },
+    Threshold {
+        phrase: Phrase::Day,
+        max: Some(35),
+        unit: None,
+    },
+    Threshold {
+        phrase: Phrase::Days,
+        max: Some(25),
+        unit: Some(Unit::Day),
+    },
+    Threshold {
+        phrase: Phrase::Month,
+        max: Some(45),
+        unit: None,
+    },
+    Threshold {
+        phrase: Phrase::Months,
+        max: Some(10),
+        unit: Some(Unit::Month),
+    },
+    Threshold {
+        phrase: Phrase::Year,
+        max: Some(17),
+        unit: None,
+    },
+    Threshold {
+        phrase: Phrase::Years,
+        max: None,
+        unit: Some(Unit::Year),
+    },
+];
+
+/// pick a phrase for a signed difference, `diff` measures the difference in a unit
+///
+/// returns the phrase, the rounded absolute value and whether the difference is in the future
+pub(crate) fn relative(diff: impl Fn(Unit) -> f64) -> (Phrase, u64, bool) {
+    let mut result = 0.0;
+    for (i, threshold) in THRESHOLDS.iter().enumerate() {
+        if let Some(unit) = threshold.unit {
+            result = diff(unit);
+        }
+        let abs = result.abs().round() as i64;
+        if threshold.max.is_none_or(|max| abs <= max) {
+            // 1 minutes -> a minute
+            let phrase = if abs <= 1 && i > 0 {
+                THRESHOLDS[i - 1].phrase
+            } else {
+                threshold.phrase
+            };
+            return (phrase, abs as u64, result > 0.0);
+        }
+    }
+    (Phrase::Years, result.abs().round() as u64, result > 0.0)
+}
+
+/// render a phrase with the locale's plural rules, `suffix` is `Some(future)` when it goes
+/// into the future or past template
+pub(crate) fn phrase(locale: &Locale, phrase: Phrase, n: u64, suffix: Option<bool>) -> String {
+    let relative = &locale.relative_time;
+    let rt = match suffix {
+        None => &relative.units,
+        Some(true) => &relative.future_units,
+        Some(false) => &relative.past_units,
+    };
+    match phrase {
+        Phrase::Second => rt.second.to_string(),
+        Phrase::Minute => rt.minute.to_string(),
+        Phrase::Minutes => locale.pluralize(rt.minutes, n),
+        Phrase::Hour => rt.hour.to_string(),
+        Phrase::Hours => locale.pluralize(rt.hours, n),
+        Phrase::Day => rt.day.to_string(),
+        Phrase::Days => locale.pluralize(rt.days, n),
+        Phrase::Month => rt.month.to_string(),
+        Phrase::Months => locale.pluralize(rt.months, n),
+        Phrase::Year => rt.year.to_string(),
+        Phrase::Years => locale.pluralize(rt.years, n),
+    }
+}
+
+/// approximate length of a unit, months are 1/12 of a 365 day year
+pub(crate) fn unit_millis(unit: Unit) -> f64 {
+    const DAY: f64 = 86_400_000.0;
+    match unit {
+        Unit::Millisecond => 1.0,
+        Unit::Second => 1_000.0,
+        Unit::Minute => 60_000.0,
+        Unit::Hour => 3_600_000.0,
+        Unit::Day => DAY,
+        Unit::Week | Unit::Isoweek => DAY * 7.0,
+        Unit::Month => DAY * 365.0 / 12.0,
+        Unit::Quarter => DAY * 365.0 / 4.0,
+        Unit::Year => DAY * 365.0,
+    }
+}
+
+/// humanize a duration, e.g. "5 часов", or "через 5 часов" with suffix
+///
+/// # Examples
+///
+/// ```
+/// let d = chrono::Duration::hours(5);
+/// assert_eq!(dayjs::duration::humanize_locale(&d, false, &dayjs::locale::RU), "5 часов");
+/// assert_eq!(dayjs::duration::humanize_locale(&-d, true, &dayjs::locale::EN), "5 hours ago");
+/// ```
+pub fn humanize_locale(duration: &Duration, with_suffix: bool, locale: &Locale) -> String {
+    let ms = duration.num_milliseconds() as f64;
+    let (p, n, future) = relative(|unit| ms / unit_millis(unit));
+    if !with_suffix {
+        return phrase(locale, p, n, None);
+    }
+    let text = phrase(locale, p, n, Some(future));
+    let rt = &locale.relative_time;
+    let template = if future { rt.future } else { rt.past };
+    template.replace("%s", &text)
+}
+
+/// unit names used by [`format_locale`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// "3 hours 4 minutes"
+    Long,
+    /// "3 hr 4 min"
+    Short,
+    /// "3h 4m"
+    Narrow,
+}
+
+/// format a duration with the locale's unit names, zero parts are left out
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::duration::{format_locale, DurationStyle};
+/// let d = chrono::Duration::minutes(184);
+/// assert_eq!(format_locale(&d, &dayjs::locale::EN, DurationStyle::Long), "3 hours 4 minutes");
+/// assert_eq!(format_locale(&d, &dayjs::locale::EN, DurationStyle::Short), "3 hr 4 min");
+/// ```
+pub fn format_locale(duration: &Duration, locale: &Locale, style: DurationStyle) -> String {
+    let units = match style {
+        DurationStyle::Long => &locale.units_long,
+        DurationStyle::Short => &locale.units_short,
+        DurationStyle::Narrow => &locale.units_narrow,
+    };
+    let seconds = duration.num_seconds().unsigned_abs();
+    let parts = [
+        seconds / 86_400,
+        seconds / 3_600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    ];
+    let mut out: Vec<String> = parts
+        .iter()
+        .zip(units)
+        .filter(|(n, _)| **n > 0)
+        .map(|(n, forms)| locale.pluralize(forms, *n))
+        .collect();
+    if out.is_empty() {
+        out.push(locale.pluralize(units[3], 0));
+    }
+    let text = out.join(" ");
+    if duration.num_seconds() < 0 {
+        format!("-{}", text)
+    } else {
+        text
+    }
+}
+
+/// format with an Excel custom number format like `[h]:mm:ss`, bracketed units are elapsed time
+/// and shorter units wrap around
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use dayjs::duration::format_excel;
+/// let d = Duration::minutes(1565) + Duration::milliseconds(250);
+/// assert_eq!(format_excel(&d, "[h]:mm:ss").unwrap(), "26:05:00");
+/// assert_eq!(format_excel(&d, "[mm]:ss.000").unwrap(), "1565:00.250");
+/// assert_eq!(format_excel(&d, "hh:mm").unwrap(), "02:05");
+/// ```
+pub fn format_excel(duration: &Duration, code: &str) -> Result<String, DayjsError> {
+    crate::convert::excel_duration(duration, code)
+}
+
+/// summary of a collection of durations, e.g. request latencies
+///
+/// percentiles use the nearest rank, an empty collection gives zeros
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use dayjs::duration::DurationStats;
+/// let stats = DurationStats::from((1..=100).map(Duration::milliseconds));
+/// assert_eq!(stats.p95, Duration::milliseconds(95));
+/// assert_eq!(stats.mean, Duration::microseconds(50_500));
+/// assert_eq!(
+///     stats.to_string(),
+///     "count 100, min 1ms, mean 50ms, p50 50ms, p95 95ms, p99 99ms, max 100ms"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DurationStats {
+    pub count: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl<I: IntoIterator<Item = Duration>> From<I> for DurationStats {
+    fn from(durations: I) -> Self {
+        let mut sorted: Vec<Duration> = durations.into_iter().collect();
+        sorted.sort();
+        let count = sorted.len();
+        let percentile = |p: usize| match count {
+            0 => Duration::zero(),
+            _ => sorted[(p * count).div_ceil(100).max(1) - 1],
+        };
+        let total: i128 = sorted
+            .iter()
+            .map(|d| i128::from(d.num_seconds()) * 1_000_000_000 + i128::from(d.subsec_nanos()))
+            .sum();
+        let mean = match count {
+            0 => 0,
+            _ => total / count as i128,
+        };
+        DurationStats {
+            count,
+            min: sorted.first().copied().unwrap_or_else(Duration::zero),
+            max: sorted.last().copied().unwrap_or_else(Duration::zero),
+            mean: Duration::seconds((mean / 1_000_000_000) as i64)
+                + Duration::nanoseconds((mean % 1_000_000_000) as i64),
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+        }
+    }
+}
+
+/// milliseconds below a second, otherwise the narrow unit names of the active locale
+fn short(duration: &Duration) -> String {
+    let ms = duration.num_milliseconds();
+    if ms.abs() < 1_000 {
+        return format!("{}ms", ms);
+    }
+    format_locale(duration, Locale::current(), DurationStyle::Narrow)
+}
+
+impl Display for DurationStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "count {}, min {}, mean {}, p50 {}, p95 {}, p99 {}, max {}",
+            self.count,
+            short(&self.min),
+            short(&self.mean),
+            short(&self.p50),
+            short(&self.p95),
+            short(&self.p99),
+            short(&self.max)
+        )
+    }
+}
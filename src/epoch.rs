@@ -0,0 +1,193 @@
+use crate::{Dayjs, DayjsError};
+use chrono::{DateTime, Utc};
+
+/// reference epoch of [`from_epoch`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Epoch {
+    /// 1970-01-01 UTC
+    Unix,
+    /// 2001-01-01 UTC, Apple's Cocoa and Core Data reference date
+    Cocoa,
+    /// 1601-01-01 UTC, WebKit and Chrome timestamps and Windows FILETIME
+    WebKit,
+    /// 1904-01-01 UTC, QuickTime and classic Mac OS
+    QuickTime,
+    /// 1980-01-06 UTC, GPS time, which does not count leap seconds
+    Gps,
+}
+
+/// unit of an epoch value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Precision {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    /// 100 nanosecond ticks, as in FILETIME
+    Ticks,
+    Nanoseconds,
+}
+
+/// 2^53, the largest magnitude below which every integer `f64` score is exact
+pub const MAX_EXACT_SCORE: f64 = 9_007_199_254_740_992.0;
+
+/// GPS - UTC in seconds from each unix time on
+const GPS_LEAP_SECONDS: [(i64, i64); 18] = [
+    (362_793_600, 1),
+    (394_329_600, 2),
+    (425_865_600, 3),
+    (489_024_000, 4),
+    (567_993_600, 5),
+    (631_152_000, 6),
+    (662_688_000, 7),
+    (709_948_800, 8),
+    (741_484_800, 9),
+    (773_020_800, 10),
+    (820_454_400, 11),
+    (867_715_200, 12),
+    (915_148_800, 13),
+    (1_136_073_600, 14),
+    (1_230_768_000, 15),
+    (1_341_100_800, 16),
+    (1_435_708_800, 17),
+    (1_483_228_800, 18),
+];
+
+impl Epoch {
+    /// unix seconds of the epoch
+    fn unix_seconds(self) -> i64 {
+        match self {
+            Epoch::Unix => 0,
+            Epoch::Cocoa => 978_307_200,
+            Epoch::WebKit => -11_644_473_600,
+            Epoch::QuickTime => -2_082_844_800,
+            Epoch::Gps => 315_964_800,
+        }
+    }
+}
+
+impl Precision {
+    fn per_second(self) -> i128 {
+        match self {
+            Precision::Seconds => 1,
+            Precision::Milliseconds => 1_000,
+            Precision::Microseconds => 1_000_000,
+            Precision::Ticks => 10_000_000,
+            Precision::Nanoseconds => 1_000_000_000,
+        }
+    }
+}
+
+/// leap seconds GPS time is ahead of UTC at `unix` seconds, measured in GPS time when `gps`
+fn gps_leap_seconds(unix: i64, gps: bool) -> i64 {
+    GPS_LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(from, leap)| unix >= from + if gps { *leap } else { 0 })
+        .map_or(0, |(_, leap)| *leap)
+}
+
+/// get dayjs instance from a count of `precision` units since `epoch`
+///
+/// values beyond the supported range are clamped to it
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{from_epoch, Epoch, Precision};
+/// // Core Data timestamp
+/// let date = from_epoch(726_136_200, Epoch::Cocoa, Precision::Seconds);
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// // Chrome history timestamp
+/// let date = from_epoch(13_348_917_000_000_000, Epoch::WebKit, Precision::Microseconds);
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// ```
+pub fn from_epoch(value: i64, epoch: Epoch, precision: Precision) -> Dayjs {
+    let per_second = precision.per_second();
+    let value = i128::from(value);
+    let mut seconds = value.div_euclid(per_second) + i128::from(epoch.unix_seconds());
+    let nanos = value.rem_euclid(per_second) * (1_000_000_000 / per_second);
+    if epoch == Epoch::Gps {
+        let unix = i64::try_from(seconds).unwrap_or(i64::MAX);
+        seconds -= i128::from(gps_leap_seconds(unix, true));
+    }
+    let time = i64::try_from(seconds)
+        .ok()
+        .and_then(|seconds| DateTime::from_timestamp(seconds, nanos as u32))
+        .unwrap_or(if seconds < 0 {
+            DateTime::<Utc>::MIN_UTC
+        } else {
+            DateTime::<Utc>::MAX_UTC
+        });
+    Dayjs {
+        time,
+        ..Default::default()
+    }
+}
+
+/// get dayjs instance from a sorted set score of [`Dayjs::to_score`], in UTC
+///
+/// fractions round to the nearest millisecond; NaN, infinities and scores beyond
+/// [`MAX_EXACT_SCORE`] are errors because they no longer name a single millisecond
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_score(1704443400123.0).unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00.123+00:00");
+/// assert!(dayjs::from_score(f64::INFINITY).is_err());
+/// ```
+pub fn from_score(score: f64) -> Result<Dayjs, DayjsError> {
+    if score.is_nan() || score.abs() > MAX_EXACT_SCORE {
+        return Err(DayjsError::OutOfRange(format!(
+            "score {} is not an exact millisecond",
+            score
+        )));
+    }
+    let millis = score.round() as i64;
+    let time =
+        DateTime::from_timestamp_millis(millis).ok_or(DayjsError::InvalidTimestamp(millis))?;
+    Ok(Dayjs {
+        time,
+        ..Default::default()
+    })
+}
+
+impl Dayjs {
+    /// unix milliseconds as an `f64`, for Redis sorted set scores and other double columns
+    ///
+    /// every supported instant is below [`MAX_EXACT_SCORE`] milliseconds, so the score and its
+    /// round trip through [`from_score`] are exact to the millisecond; sub-millisecond precision
+    /// and the tz are dropped. microsecond scores would lose exactness after the year 2255
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400123);
+    /// assert_eq!(date.to_score(), 1704443400123.0);
+    /// assert_eq!(dayjs::from_score(date.to_score()).unwrap(), date);
+    /// ```
+    pub fn to_score(&self) -> f64 {
+        self.time.timestamp_millis() as f64
+    }
+
+    /// count of `precision` units since `epoch`, saturating at the `i64` range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{Epoch, Precision};
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(date.to_epoch(Epoch::Gps, Precision::Seconds), 1_388_478_618);
+    /// ```
+    pub fn to_epoch(&self, epoch: Epoch, precision: Precision) -> i64 {
+        let mut seconds = self.time.timestamp();
+        if epoch == Epoch::Gps {
+            seconds += gps_leap_seconds(seconds, false);
+        }
+        let seconds = i128::from(seconds) - i128::from(epoch.unix_seconds());
+        let per_second = precision.per_second();
+        let subsec = i128::from(self.time.timestamp_subsec_nanos()) / (1_000_000_000 / per_second);
+        let value = seconds * per_second + subsec;
+        value.clamp(i64::MIN.into(), i64::MAX.into()) as i64
+    }
+}
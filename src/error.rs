@@ -0,0 +1,66 @@
+use std::fmt::{Display, Formatter};
+
+/// error of the fallible dayjs APIs
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DayjsError {
+    /// input that does not match a date grammar
+    ParseError {
+        /// name of the grammar, e.g. "RFC 3339 date time"
+        grammar: &'static str,
+        input: String,
+        message: String,
+        /// byte offset of the problem, when the grammar is read byte by byte
+        position: Option<usize>,
+    },
+    /// milliseconds since the unix epoch outside the supported range
+    InvalidTimestamp(i64),
+    /// a timezone name that is not in the database
+    InvalidTimeZone(String),
+    /// a value the target representation cannot hold
+    OutOfRange(String),
+    /// a unit name that [`crate::Unit`] does not know
+    UnknownUnit(String),
+    /// malformed binary input
+    InvalidEncoding(String),
+}
+
+impl DayjsError {
+    pub(crate) fn parse(grammar: &'static str, input: &str, message: &str) -> DayjsError {
+        DayjsError::ParseError {
+            grammar,
+            input: input.to_string(),
+            message: message.to_string(),
+            position: None,
+        }
+    }
+}
+
+impl Display for DayjsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayjsError::ParseError {
+                grammar,
+                input,
+                message,
+                position,
+            } => {
+                write!(f, "invalid {} {:?}: {}", grammar, input, message)?;
+                match position {
+                    Some(position) => write!(f, " at position {}", position),
+                    None => Ok(()),
+                }
+            }
+            DayjsError::InvalidTimestamp(millis) => {
+                write!(f, "timestamp {} is out of range", millis)
+            }
+            DayjsError::InvalidTimeZone(name) => write!(f, "unknown timezone {:?}", name),
+            DayjsError::OutOfRange(message) | DayjsError::InvalidEncoding(message) => {
+                write!(f, "{}", message)
+            }
+            DayjsError::UnknownUnit(unit) => write!(f, "unknown unit {:?}", unit),
+        }
+    }
+}
+
+impl std::error::Error for DayjsError {}
@@ -0,0 +1,710 @@
+use crate::locale::Locale;
+use crate::timezone::{format_offset, NameStyle, OffsetStyle};
+use crate::Dayjs;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::fmt::{Display, Formatter, Write};
+use std::ops::Deref;
+
+thread_local! {
+    /// buffer handed out by [`Dayjs::format_buffered`]
+    static BUFFER: Cell<String> = const { Cell::new(String::new()) };
+}
+
+/// the buffer of the calling thread, empty, or a new one when a guard holds it
+fn take_buffer() -> String {
+    let mut buf = BUFFER.try_with(Cell::take).unwrap_or_default();
+    buf.clear();
+    buf
+}
+
+/// text formatted by [`Dayjs::format_buffered`], dropping it returns the buffer to the thread
+#[derive(Debug)]
+pub struct FormatGuard {
+    buf: String,
+}
+
+impl Deref for FormatGuard {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.buf
+    }
+}
+
+impl Display for FormatGuard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.buf)
+    }
+}
+
+impl Drop for FormatGuard {
+    fn drop(&mut self) {
+        let buf = std::mem::take(&mut self.buf);
+        // keep the larger of two buffers when guards were nested
+        let _ = BUFFER.try_with(|slot| {
+            let other = slot.take();
+            slot.set(if other.capacity() > buf.capacity() {
+                other
+            } else {
+                buf
+            });
+        });
+    }
+}
+
+/// supported tokens, longer tokens must come before their prefixes
+const TOKENS: [&str; 32] = [
+    "YYYY", "YY", "Q", "MMMM", "MMM", "MM", "M", "DD", "Do", "D", "dddd", "ddd", "d", "HH", "H",
+    "hh", "h", "kk", "k", "mm", "m", "ss", "s", "SSS", "X", "x", "A", "a", "ZZ", "Z", "zzz", "z",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Piece<'a> {
+    Literal(&'a str),
+    /// text inside `[...]`
+    Escaped(&'a str),
+    Token(&'static str),
+}
+
+/// split a dayjs style template into literal text and tokens
+pub(crate) fn tokenize(template: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < template.len() {
+        let rest = &template[i..];
+        if let Some(end) = rest
+            .strip_prefix('[')
+            .and_then(|r| r.find(']'))
+            .filter(|end| *end > 0)
+        {
+            if literal_start < i {
+                pieces.push(Piece::Literal(&template[literal_start..i]));
+            }
+            pieces.push(Piece::Escaped(&rest[1..end + 1]));
+            i += end + 2;
+            literal_start = i;
+            continue;
+        }
+        match TOKENS.iter().find(|token| rest.starts_with(**token)) {
+            Some(token) => {
+                if literal_start < i {
+                    pieces.push(Piece::Literal(&template[literal_start..i]));
+                }
+                pieces.push(Piece::Token(token));
+                i += token.len();
+                literal_start = i;
+            }
+            None => i += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if literal_start < template.len() {
+        pieces.push(Piece::Literal(&template[literal_start..]));
+    }
+    pieces
+}
+
+/// replace the localized format tokens `LT`, `LTS`, `L` to `LLLL` and the shorter `l` to `llll`
+/// with the locale's templates, like the dayjs localizedFormat plugin
+pub(crate) fn expand_localized<'a>(template: &'a str, locale: &Locale) -> Cow<'a, str> {
+    if !template.contains(['L', 'l']) {
+        return Cow::Borrowed(template);
+    }
+    let mut out = String::with_capacity(template.len() + 16);
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if let Some(end) = rest
+            .strip_prefix('[')
+            .and_then(|r| r.find(']'))
+            .filter(|end| *end > 0)
+        {
+            out.push_str(&rest[..end + 2]);
+            rest = &rest[end + 2..];
+            continue;
+        }
+        let token = [
+            "LTS", "LT", "LLLL", "LLL", "LL", "L", "llll", "lll", "ll", "l",
+        ]
+        .into_iter()
+        .find(|token| rest.starts_with(token));
+        let Some(token) = token else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        };
+        let index = ["LT", "LTS", "L", "LL", "LLL", "LLLL"]
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case(token))
+            .unwrap_or_default();
+        let expanded = locale.localized_formats[index];
+        if token.starts_with('l') {
+            // the short forms drop the padding and long names, "MMMM" to "MMM"
+            for piece in tokenize(expanded) {
+                match piece {
+                    Piece::Escaped(s) => write!(out, "[{}]", s).unwrap_or_default(),
+                    Piece::Literal(s) => out.push_str(s),
+                    Piece::Token(t @ ("MMMM" | "MM" | "DD" | "dddd")) => out.push_str(&t[1..]),
+                    Piece::Token(t) => out.push_str(t),
+                }
+            }
+        } else {
+            out.push_str(expanded);
+        }
+        rest = &rest[token.len()..];
+    }
+    Cow::Owned(out)
+}
+
+/// whether a template is read as strftime, a `%` outside `[...]`
+fn is_strftime(template: &str) -> bool {
+    tokenize(template)
+        .iter()
+        .any(|piece| matches!(piece, Piece::Literal(s) if s.contains('%')))
+}
+
+/// whether the month token at `index` follows a day number, or precedes one when the locale
+/// declines it there too, which selects the format form
+fn next_to_day(pieces: &[Piece], index: usize, locale: &Locale) -> bool {
+    fn scan<'p, 'a: 'p>(pieces: impl Iterator<Item = &'p Piece<'a>>) -> bool {
+        let mut separated = false;
+        for piece in pieces {
+            match piece {
+                // punctuation like "D.MMMM" separates as well as whitespace
+                Piece::Literal(s) if !s.chars().any(char::is_alphanumeric) => separated = true,
+                Piece::Escaped(_) => separated = true,
+                Piece::Token("D" | "DD" | "Do") => return separated,
+                _ => return false,
+            }
+        }
+        false
+    }
+    scan(pieces[..index].iter().rev())
+        || (locale.months_format_before_day && scan(pieces[index + 1..].iter()))
+}
+
+/// date part of [`Dayjs::format_style`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+    None,
+}
+
+/// time part of [`Dayjs::format_style`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+    None,
+}
+
+impl DateStyle {
+    /// index into the locale style tables
+    fn index(self) -> Option<usize> {
+        match self {
+            DateStyle::Full => Some(0),
+            DateStyle::Long => Some(1),
+            DateStyle::Medium => Some(2),
+            DateStyle::Short => Some(3),
+            DateStyle::None => None,
+        }
+    }
+}
+
+impl TimeStyle {
+    /// index into the locale style tables
+    fn index(self) -> Option<usize> {
+        match self {
+            TimeStyle::Full => Some(0),
+            TimeStyle::Long => Some(1),
+            TimeStyle::Medium => Some(2),
+            TimeStyle::Short => Some(3),
+            TimeStyle::None => None,
+        }
+    }
+}
+
+impl Dayjs {
+    /// format with dayjs style tokens, or with a chrono strftime template when it contains `%`
+    /// outside of `[...]`
+    ///
+    /// name tokens use the active locale, see [`crate::locale`], text inside `[...]` is kept as is,
+    /// and `LT`, `LTS`, `L` to `LLLL` and `l` to `llll` expand to the locale's templates. a literal
+    /// `%` is `[%]` in a dayjs template and `%%` in a strftime one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.format("YYYY-MM-DD HH:mm:ss"), "2024-01-05 08:30:15");
+    /// assert_eq!(date.format("[Today is] dddd"), "Today is Friday");
+    /// assert_eq!(date.format("[%d days] D"), "%d days 5");
+    /// assert_eq!(date.format("%d%% off"), "05% off");
+    /// assert_eq!(date.format("%Y-%m-%d %H:%M"), "2024-01-05 08:30");
+    /// assert_eq!(date.format("LLLL"), "Friday, January 5, 2024 8:30 AM");
+    /// ```
+    pub fn format(&self, template: &str) -> String {
+        let mut out = String::with_capacity(template.len() + 16);
+        self.write_format(&mut out, template);
+        out
+    }
+
+    /// [`Dayjs::format`] into a buffer reused by the calling thread, for hot logging paths
+    ///
+    /// the guard derefs to the text and hands the buffer back when it is dropped, so a thread
+    /// formatting one line after another allocates once; formatting again while a guard is
+    /// alive takes a fresh buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415250);
+    /// let line = date.format_buffered("YYYY-MM-DDTHH:mm:ss.SSS[Z]");
+    /// assert_eq!(&*line, "2024-01-05T08:30:15.250Z");
+    /// assert_eq!(format!("[{}] started", line), "[2024-01-05T08:30:15.250Z] started");
+    /// ```
+    pub fn format_buffered(&self, template: &str) -> FormatGuard {
+        let mut buf = take_buffer();
+        self.write_format(&mut buf, template);
+        FormatGuard { buf }
+    }
+
+    /// the UTC `toISOString()` text, e.g. `2024-01-05T08:30:15.250Z`, into the buffer of
+    /// [`Dayjs::format_buffered`]; it is written directly, with no template to read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415250).tz(dayjs::DayjsTimeZone::Offset(3600));
+    /// assert_eq!(&*date.to_iso_buffered(), "2024-01-05T08:30:15.250Z");
+    /// ```
+    pub fn to_iso_buffered(&self) -> FormatGuard {
+        let mut buf = take_buffer();
+        let time = self.time;
+        let _ = write!(
+            buf,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            time.year(),
+            time.month(),
+            time.day(),
+            time.hour(),
+            time.minute(),
+            time.second(),
+            time.timestamp_subsec_millis()
+        );
+        FormatGuard { buf }
+    }
+
+    /// local time with its offset followed by the same instant in UTC, for logs where the
+    /// timezone of a value matters, e.g. `2025-12-09T23:59:59+05:30 (18:29:59Z)`
+    ///
+    /// milliseconds are shown when there are any, and the UTC date when it is another day
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::DayjsTimeZone;
+    /// let date = dayjs::from_timestamp(1765304999).tz(DayjsTimeZone::Offset(19800));
+    /// assert_eq!(date.format_dual(), "2025-12-09T23:59:59+05:30 (18:29:59Z)");
+    /// let end = date.end_of("day").tz(DayjsTimeZone::Offset(14 * 3600));
+    /// assert_eq!(end.format_dual(), "2025-12-10T08:29:59.999+14:00 (2025-12-09T18:29:59.999Z)");
+    /// ```
+    pub fn format_dual(&self) -> String {
+        let local = self.datetime();
+        let utc = self.time;
+        let fraction = if utc.timestamp_subsec_millis() > 0 {
+            "%.3f"
+        } else {
+            ""
+        };
+        let utc_template = if utc.date_naive() == local.date_naive() {
+            format!("%H:%M:%S{}Z", fraction)
+        } else {
+            format!("%Y-%m-%dT%H:%M:%S{}Z", fraction)
+        };
+        format!(
+            "{} ({})",
+            local.format(&format!("%Y-%m-%dT%H:%M:%S{}%:z", fraction)),
+            utc.format(&utc_template)
+        )
+    }
+
+    fn write_format(&self, out: &mut String, template: &str) {
+        if is_strftime(template) {
+            // not a valid strftime template, e.g. "YYYY [100%]"
+            if let Some(text) = render_strftime(&self.datetime(), template) {
+                out.push_str(&text);
+                return;
+            }
+        }
+        self.write_locale(out, template, Locale::current())
+    }
+
+    /// format with a strftime template of another runtime, unknown specifiers are kept as text
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTimeZone, StrftimeMode};
+    /// let date = dayjs::from_timestamp(1704443415250).tz(DayjsTimeZone::Offset(19800));
+    /// let python = date.strftime("%Y-%m-%d %H:%M:%S.%f %Z", StrftimeMode::PyStrftime);
+    /// assert_eq!(python, "2024-01-05 14:00:15.250000 UTC+05:30");
+    /// assert_eq!(date.strftime("%#d/%-m %Q", StrftimeMode::PyStrftime), "5/1 %Q");
+    /// assert_eq!(date.strftime("%e %Q", StrftimeMode::Chrono), " 5 %Q");
+    /// ```
+    pub fn strftime(&self, template: &str, mode: StrftimeMode) -> String {
+        let mut chrono_template = String::with_capacity(template.len() + 8);
+        for (spec, text) in strftime_pieces(template) {
+            let python = mode == StrftimeMode::PyStrftime;
+            // python takes `#` as the windows no padding flag
+            let text = match text.strip_prefix("%#") {
+                Some(rest) if python => format!("%-{}", rest),
+                _ => text.to_string(),
+            };
+            match text.as_str() {
+                _ if !spec => chrono_template.push_str(&text.replace('%', "%%")),
+                "%f" if python => chrono_template.push_str("%6f"),
+                "%Z" if python => {
+                    chrono_template.push_str(&self.python_zone_name().replace('%', "%%"))
+                }
+                _ if render_strftime(&self.datetime(), &text).is_none() => {
+                    chrono_template.push_str(&text.replace('%', "%%"))
+                }
+                _ => chrono_template.push_str(&text),
+            }
+        }
+        render_strftime(&self.datetime(), &chrono_template).unwrap_or(chrono_template)
+    }
+
+    /// `%Z` of python, the zone abbreviation or `UTC+05:30` for a fixed offset
+    fn python_zone_name(&self) -> String {
+        #[cfg(feature = "tz")]
+        if let crate::DayjsTimeZone::City(tz) = self.tz {
+            use chrono::TimeZone;
+            use chrono_tz::OffsetName;
+            let offset = tz.offset_from_utc_datetime(&self.time.naive_utc());
+            if let Some(name) = offset.abbreviation() {
+                return name.to_string();
+            }
+        }
+        match self.utc_offset() {
+            0 => "UTC".to_string(),
+            minutes => format!("UTC{}", format_offset(minutes * 60, OffsetStyle::Colon)),
+        }
+    }
+
+    /// format with dayjs style tokens, name tokens use the given locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704412800);
+    /// assert_eq!(date.format_locale("D MMMM YYYY", &dayjs::locale::RU), "5 января 2024");
+    /// assert_eq!(date.format_locale("MMMM YYYY", &dayjs::locale::RU), "январь 2024");
+    /// ```
+    pub fn format_locale(&self, template: &str, locale: &Locale) -> String {
+        let mut out = String::with_capacity(template.len() + 16);
+        self.write_locale(&mut out, template, locale);
+        out
+    }
+
+    fn write_locale(&self, out: &mut String, template: &str, locale: &Locale) {
+        let time = self.datetime();
+        let template = expand_localized(template, locale);
+        let pieces = tokenize(&template);
+        let month0 = time.month0() as usize;
+        let weekday = time.weekday().num_days_from_sunday() as usize;
+        let offset = time.offset().local_minus_utc();
+        let meridiem = locale.meridiem[usize::from(time.hour() >= 12)];
+        for (index, piece) in pieces.iter().enumerate() {
+            let token = match piece {
+                Piece::Literal(s) | Piece::Escaped(s) => {
+                    out.push_str(s);
+                    continue;
+                }
+                Piece::Token(token) => *token,
+            };
+            let _ = match token {
+                "YYYY" => write!(out, "{:04}", time.year()),
+                "YY" => write!(out, "{:02}", time.year().rem_euclid(100)),
+                "Q" => write!(out, "{}", time.month0() / 3 + 1),
+                "MMMM" => write!(
+                    out,
+                    "{}",
+                    locale.month_name(month0, next_to_day(&pieces, index, locale))
+                ),
+                "MMM" => write!(out, "{}", locale.months_short[month0]),
+                "MM" => write!(out, "{:02}", time.month()),
+                "M" => write!(out, "{}", time.month()),
+                "DD" => write!(out, "{:02}", time.day()),
+                "Do" => write!(out, "{}", locale.ordinal(u64::from(time.day()))),
+                "D" => write!(out, "{}", time.day()),
+                "dddd" => write!(out, "{}", locale.weekdays[weekday]),
+                "ddd" => write!(out, "{}", locale.weekdays_short[weekday]),
+                "d" => write!(out, "{}", weekday),
+                "HH" => write!(out, "{:02}", time.hour()),
+                "H" => write!(out, "{}", time.hour()),
+                "hh" => write!(out, "{:02}", time.hour12().1),
+                "h" => write!(out, "{}", time.hour12().1),
+                "mm" => write!(out, "{:02}", time.minute()),
+                "m" => write!(out, "{}", time.minute()),
+                "ss" => write!(out, "{:02}", time.second()),
+                "s" => write!(out, "{}", time.second()),
+                "kk" => write!(out, "{:02}", (time.hour() + 23) % 24 + 1),
+                "k" => write!(out, "{}", (time.hour() + 23) % 24 + 1),
+                "SSS" => write!(out, "{:03}", time.timestamp_subsec_millis()),
+                "X" => write!(out, "{}", time.timestamp()),
+                "x" => write!(out, "{}", time.timestamp_millis()),
+                "A" => write!(out, "{}", meridiem),
+                "a" => write!(out, "{}", meridiem.to_lowercase()),
+                "ZZ" => write!(out, "{}", format_offset(offset, OffsetStyle::Basic)),
+                "Z" => write!(out, "{}", format_offset(offset, OffsetStyle::Colon)),
+                "zzz" => write!(out, "{}", self.tz.name_at(self, locale, NameStyle::Long)),
+                "z" => write!(out, "{}", self.tz.short_name_at(self, locale)),
+                _ => write!(out, "{}", token),
+            };
+        }
+    }
+
+    /// format with the locale's date and time style presets
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DateStyle, TimeStyle};
+    /// let date = dayjs::from_timestamp(1707145445);
+    /// let en = date.format_style(DateStyle::Medium, TimeStyle::Short, &dayjs::locale::EN);
+    /// assert_eq!(en, "Feb 5, 2024, 3:04 PM");
+    /// let ru = date.format_style(DateStyle::Long, TimeStyle::None, &dayjs::locale::RU);
+    /// assert_eq!(ru, "5 февраля 2024 г.");
+    /// ```
+    pub fn format_style(&self, date: DateStyle, time: TimeStyle, locale: &Locale) -> String {
+        let template = match (date.index(), time.index()) {
+            (Some(d), Some(t)) => locale.datetime_formats[d]
+                .replace("{1}", locale.date_formats[d])
+                .replace("{0}", locale.time_formats[t]),
+            (Some(d), None) => locale.date_formats[d].to_string(),
+            (None, Some(t)) => locale.time_formats[t].to_string(),
+            (None, None) => String::new(),
+        };
+        self.format_locale(&template, locale)
+    }
+}
+
+/// strftime dialect of [`Dayjs::strftime`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StrftimeMode {
+    /// chrono specifiers, `%f` is nanoseconds and `%Z` the offset
+    Chrono,
+    /// python `datetime.strftime`, `%f` is microseconds, `%Z` the zone name, and `%-d` or the
+    /// windows `%#d` drop the padding
+    PyStrftime,
+}
+
+/// one piece of a format template, see [`explain_format`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub token: String,
+    pub meaning: &'static str,
+    /// the piece for 2024-01-05T08:30:15.250Z in the active locale, empty for unknown
+    /// strftime specifiers
+    pub example: String,
+}
+
+fn dayjs_meaning(token: &str) -> &'static str {
+    match token {
+        "YYYY" => "four digit year",
+        "YY" => "two digit year",
+        "Q" => "quarter, 1 to 4",
+        "MMMM" => "month name",
+        "MMM" => "short month name",
+        "MM" => "month, 01 to 12",
+        "M" => "month, 1 to 12",
+        "DD" => "day of the month, 01 to 31",
+        "Do" => "day of the month as an ordinal",
+        "D" => "day of the month, 1 to 31",
+        "dddd" => "weekday name",
+        "ddd" => "short weekday name",
+        "d" => "day of the week, sunday is 0",
+        "HH" => "hour, 00 to 23",
+        "H" => "hour, 0 to 23",
+        "hh" => "hour, 01 to 12",
+        "h" => "hour, 1 to 12",
+        "mm" => "minute, 00 to 59",
+        "m" => "minute, 0 to 59",
+        "ss" => "second, 00 to 59",
+        "s" => "second, 0 to 59",
+        "SSS" => "millisecond, 000 to 999",
+        "A" => "AM or PM",
+        "a" => "am or pm",
+        "kk" => "hour, 01 to 24",
+        "k" => "hour, 1 to 24",
+        "X" => "unix seconds",
+        "x" => "unix milliseconds",
+        "ZZ" => "UTC offset, +hhmm",
+        "zzz" => "timezone name",
+        "z" => "short timezone name",
+        _ => "UTC offset, +hh:mm",
+    }
+}
+
+fn strftime_meaning(spec: &str) -> &'static str {
+    match spec {
+        "Y" => "year",
+        "C" => "century",
+        "y" => "two digit year",
+        "m" => "month, 01 to 12",
+        "b" | "h" => "short month name",
+        "B" => "month name",
+        "d" => "day of the month, 01 to 31",
+        "e" => "day of the month, space padded",
+        "a" => "short weekday name",
+        "A" => "weekday name",
+        "w" => "day of the week, sunday is 0",
+        "u" => "day of the week, monday is 1",
+        "U" => "week of the year, from sunday",
+        "W" => "week of the year, from monday",
+        "G" => "ISO week year",
+        "g" => "two digit ISO week year",
+        "V" => "ISO week number",
+        "j" => "day of the year",
+        "D" => "month/day/year",
+        "x" => "locale date",
+        "F" => "year-month-day",
+        "v" => "day-month name-year",
+        "H" => "hour, 00 to 23",
+        "k" => "hour, space padded",
+        "I" => "hour, 01 to 12",
+        "l" => "hour 1 to 12, space padded",
+        "P" => "am or pm",
+        "p" => "AM or PM",
+        "M" => "minute",
+        "S" => "second",
+        "f" => "nanoseconds since the last second",
+        ".f" | ".3f" | ".6f" | ".9f" => "fraction of a second with the dot",
+        "3f" | "6f" | "9f" => "fraction of a second",
+        "Z" => "timezone name or abbreviation",
+        "z" => "UTC offset, +hhmm",
+        ":z" => "UTC offset, +hh:mm",
+        "::z" => "UTC offset, +hh:mm:ss",
+        "c" => "locale date and time",
+        "+" => "ISO 8601 date and time",
+        "s" => "seconds since the unix epoch",
+        "T" => "hour:minute:second",
+        "X" => "locale time",
+        "r" => "12 hour time",
+        "R" => "hour:minute",
+        "n" => "newline",
+        "t" => "tab",
+        "%" => "percent sign",
+        _ => "unknown specifier",
+    }
+}
+
+/// render a chrono strftime template, none when it is invalid or, like the parse only `%#z`,
+/// cannot be formatted
+fn render_strftime(time: &DateTime<FixedOffset>, template: &str) -> Option<String> {
+    let items: Vec<Item> = StrftimeItems::new(template).collect();
+    if items.contains(&Item::Error) {
+        return None;
+    }
+    let mut out = String::new();
+    write!(out, "{}", time.format_with_items(items.iter())).ok()?;
+    Some(out)
+}
+
+/// split a strftime template into literal text and `%` specifiers
+fn strftime_pieces(template: &str) -> Vec<(bool, &str)> {
+    let mut pieces = Vec::new();
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < template.len() {
+        if !template[i..].starts_with('%') {
+            i += template[i..].chars().next().map_or(1, char::len_utf8);
+            continue;
+        }
+        if literal_start < i {
+            pieces.push((false, &template[literal_start..i]));
+        }
+        let rest = &template[i + 1..];
+        let flag = usize::from(rest.starts_with(['-', '_', '0', '#']));
+        let spec = &rest[flag..];
+        let len = ["::z", ":z", ".3f", ".6f", ".9f", ".f", "3f", "6f", "9f"]
+            .iter()
+            .find(|long| spec.starts_with(**long))
+            .map_or_else(
+                || spec.chars().next().map_or(0, char::len_utf8),
+                |s| s.len(),
+            );
+        let end = i + 1 + flag + len;
+        pieces.push((true, &template[i..end]));
+        i = end;
+        literal_start = end;
+    }
+    if literal_start < template.len() {
+        pieces.push((false, &template[literal_start..]));
+    }
+    pieces
+}
+
+/// explain each token of a template, dayjs tokens or strftime when it contains `%` like
+/// [`Dayjs::format`], so users can build formats safely
+///
+/// # Examples
+///
+/// ```
+/// let tokens = dayjs::explain_format("DD MMM [at] HH:mm");
+/// let pieces: Vec<_> = tokens.iter().map(|t| (t.token.as_str(), t.example.as_str())).collect();
+/// assert_eq!(
+///     pieces,
+///     [("DD", "05"), (" ", " "), ("MMM", "Jan"), (" ", " "), ("[at]", "at"), (" ", " "),
+///      ("HH", "08"), (":", ":"), ("mm", "30")]
+/// );
+/// assert_eq!(tokens[2].meaning, "short month name");
+/// assert_eq!(dayjs::explain_format("%-d %B")[2].meaning, "month name");
+/// ```
+pub fn explain_format(template: &str) -> Vec<TokenInfo> {
+    let sample = crate::from_timestamp(1704443415250);
+    let literal = |token: &str, meaning, example: &str| TokenInfo {
+        token: token.to_string(),
+        meaning,
+        example: example.to_string(),
+    };
+    if is_strftime(template) {
+        return strftime_pieces(template)
+            .into_iter()
+            .map(|(spec, text)| {
+                if !spec {
+                    return literal(text, "literal text", text);
+                }
+                let flag = usize::from(text[1..].starts_with(['-', '_', '0', '#']));
+                let example = render_strftime(&sample.datetime(), text).unwrap_or_default();
+                TokenInfo {
+                    token: text.to_string(),
+                    meaning: strftime_meaning(&text[1 + flag..]),
+                    example,
+                }
+            })
+            .collect();
+    }
+    let locale = Locale::current();
+    tokenize(template)
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Literal(s) => literal(s, "literal text", s),
+            Piece::Escaped(s) => literal(&format!("[{}]", s), "escaped text", s),
+            Piece::Token(token) => TokenInfo {
+                token: token.to_string(),
+                meaning: dayjs_meaning(token),
+                example: sample.format_locale(token, locale),
+            },
+        })
+        .collect()
+}
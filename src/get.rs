@@ -0,0 +1,347 @@
+use crate::{Dayjs, DayjsError, Locale, Unit};
+use chrono::{Datelike, Duration, NaiveDate, Timelike, Weekday};
+
+/// days of each month in a common year
+const MONTH_DAYS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// proleptic gregorian leap year, without branches
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0) & ((year % 100 != 0) | (year % 400 == 0))
+}
+
+/// days in `month`, 1 to 12
+pub(crate) const fn days_in_month(year: i32, month: u32) -> u32 {
+    MONTH_DAYS[month as usize - 1] + ((month == 2) & is_leap_year(year)) as u32
+}
+
+impl Dayjs {
+    /// year in the instance timezone
+    pub fn year(&self) -> i32 {
+        self.datetime().year()
+    }
+
+    /// month in the instance timezone, 1 based like chrono
+    pub fn month(&self) -> u32 {
+        self.datetime().month()
+    }
+
+    /// quarter of the year in the instance timezone, 1 to 4
+    pub fn quarter(&self) -> u32 {
+        self.datetime().month0() / 3 + 1
+    }
+
+    /// the same month of another quarter, the day is clamped to the end of a shorter month
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1709214615);
+    /// assert_eq!(date.quarter(), 1);
+    /// let moved = date.set_quarter(2).unwrap();
+    /// assert_eq!(moved.format("YYYY-MM-DD Q"), "2024-05-29 2");
+    /// assert!(date.set_quarter(5).is_err());
+    /// ```
+    pub fn set_quarter(&self, quarter: u32) -> Result<Dayjs, DayjsError> {
+        self.set(Unit::Quarter, quarter.into())
+    }
+
+    /// day of the month in the instance timezone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::DayjsTimeZone;
+    /// let date = dayjs::from_timestamp(1704497400);
+    /// assert_eq!(date.date(), 5);
+    /// assert_eq!(date.tz(DayjsTimeZone::Offset(3600)).date(), 6);
+    /// ```
+    pub fn date(&self) -> u32 {
+        self.datetime().day()
+    }
+
+    /// day of the week in the instance timezone
+    pub fn day(&self) -> Weekday {
+        self.datetime().weekday()
+    }
+
+    /// day of the week counted from the active locale's first day, 0 to 6, the dayjs weekday
+    /// plugin; [`Dayjs::day`] does not depend on the locale
+    pub fn weekday(&self) -> u32 {
+        let start = Locale::current().week_start.num_days_from_sunday();
+        (self.day().num_days_from_sunday() + 7 - start) % 7
+    }
+
+    /// move to day `n` of the locale week of [`Dayjs::weekday`], keeping the wall clock; values
+    /// outside 0 to 6 move into the weeks around it like dayjs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // friday, the locale is english with weeks from sunday
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.weekday(), 5);
+    /// assert_eq!(date.set_weekday(0).format("YYYY-MM-DD dddd"), "2023-12-31 Sunday");
+    /// assert_eq!(date.set_weekday(7).format("YYYY-MM-DD HH:mm"), "2024-01-07 08:30");
+    /// ```
+    pub fn set_weekday(&self, n: i64) -> Dayjs {
+        self.add(n.saturating_sub(self.weekday().into()), Unit::Day)
+    }
+
+    /// ISO day of the week, 1 for monday to 7 for sunday, whatever the locale
+    pub fn iso_weekday(&self) -> u32 {
+        self.day().number_from_monday()
+    }
+
+    /// move to ISO day `n` of the ISO week, keeping the wall clock; like [`Dayjs::set_weekday`]
+    /// other values move into the weeks around it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.iso_weekday(), 5);
+    /// assert_eq!(date.set_iso_weekday(7).format("YYYY-MM-DD dddd"), "2024-01-07 Sunday");
+    /// assert_eq!(date.set_iso_weekday(0).format("YYYY-MM-DD dddd"), "2023-12-31 Sunday");
+    /// ```
+    pub fn set_iso_weekday(&self, n: i64) -> Dayjs {
+        self.add(n.saturating_sub(self.iso_weekday().into()), Unit::Day)
+    }
+
+    /// day of the year in the instance timezone, 1 to 366, the dayjs dayOfYear plugin
+    pub fn day_of_year(&self) -> u32 {
+        self.datetime().ordinal()
+    }
+
+    /// days in the month of this instance, 28 to 31
+    pub fn days_in_month(&self) -> u32 {
+        let local = self.datetime();
+        days_in_month(local.year(), local.month())
+    }
+
+    /// days in the year of this instance, 365 or 366
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1709800215);
+    /// assert_eq!((date.days_in_month(), date.days_in_year()), (31, 366));
+    /// ```
+    pub fn days_in_year(&self) -> u32 {
+        365 + u32::from(is_leap_year(self.year()))
+    }
+
+    /// the same wall clock on day `n` of the year, an error for days the year does not have
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.day_of_year(), 5);
+    /// let last = date.set_day_of_year(366).unwrap();
+    /// assert_eq!(last.format("YYYY-MM-DD HH:mm:ss"), "2024-12-31 08:30:15");
+    /// assert!(date.set_day_of_year(0).is_err());
+    /// ```
+    pub fn set_day_of_year(&self, n: u32) -> Result<Dayjs, DayjsError> {
+        let local = self.datetime().naive_local();
+        let date = local.date().with_ordinal(n).ok_or_else(|| {
+            DayjsError::OutOfRange(format!(
+                "day of year {} is not between 1 and {}",
+                n,
+                self.days_in_year()
+            ))
+        })?;
+        Ok(Dayjs {
+            time: self.tz.local_to_utc(date.and_time(local.time())),
+            ..*self
+        })
+    }
+
+    /// ISO week number in the instance timezone, 1 to 53, the same as [`Unit::Isoweek`] in
+    /// [`Dayjs::get`]
+    pub fn week_of_year(&self) -> u32 {
+        self.datetime().iso_week().week()
+    }
+
+    /// year the ISO week of [`Dayjs::week_of_year`] belongs to, which is not [`Dayjs::year`] in
+    /// the first and last days of some years
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // sunday 2021-01-03
+    /// let date = dayjs::from_timestamp(1609673400);
+    /// let label = format!("{}-W{:02}", date.iso_week_year(), date.week_of_year());
+    /// assert_eq!((date.year(), label.as_str()), (2021, "2020-W53"));
+    /// ```
+    pub fn iso_week_year(&self) -> i32 {
+        self.datetime().iso_week().year()
+    }
+
+    /// year the locale week of [`Dayjs::weekday`] belongs to, the dayjs weekYear plugin; the week
+    /// holding january [`Locale::year_start`] is the first one of its year, so it depends on the
+    /// global locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // sunday 2024-12-29, in an english week that ends on 2025-01-04
+    /// let date = dayjs::from_timestamp(1735461000);
+    /// assert_eq!((date.week_year(), date.iso_week_year()), (2025, 2024));
+    /// ```
+    pub fn week_year(&self) -> i32 {
+        let locale = Locale::current();
+        let first = crate::manipulate::week_start(self.datetime().date_naive(), locale.week_start);
+        // the day of the week that falls on january `year_start` in the first week
+        let day = Duration::days(7 - i64::from(locale.year_start.clamp(1, 7)));
+        first.checked_add_signed(day).unwrap_or(first).year()
+    }
+
+    /// ISO weeks in the calendar year of this instance, 52 or 53, the dayjs isoWeeksInYear
+    /// plugin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.iso_weeks_in_year(), 52);
+    /// assert_eq!(date.add(2, dayjs::Unit::Year).iso_weeks_in_year(), 53);
+    /// ```
+    pub fn iso_weeks_in_year(&self) -> u32 {
+        iso_weeks(self.year())
+    }
+
+    /// the same weekday and wall clock in ISO week `n` of the ISO week year, like
+    /// `dayjs().isoWeek(n)`; weeks the year does not have are an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.week_of_year(), 1);
+    /// let later = date.set_week(25).unwrap();
+    /// assert_eq!(later.format("YYYY-MM-DD dddd HH:mm"), "2024-06-21 Friday 08:30");
+    /// assert!(date.set_week(53).is_err());
+    /// ```
+    pub fn set_week(&self, n: u32) -> Result<Dayjs, DayjsError> {
+        self.set(Unit::Isoweek, n.into())
+    }
+
+    /// hour in the instance timezone
+    pub fn hour(&self) -> u32 {
+        self.datetime().hour()
+    }
+
+    /// hour on a 12 hour clock, 1 to 12, the `h` token
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704465000);
+    /// assert_eq!((date.hour12(), date.meridiem()), (2, "PM"));
+    /// assert_eq!(date.format("h:mm A"), "2:30 PM");
+    /// ```
+    pub fn hour12(&self) -> u32 {
+        self.datetime().hour12().1
+    }
+
+    /// AM or PM in the active locale, the `A` token
+    pub fn meridiem(&self) -> &'static str {
+        Locale::current().meridiem[usize::from(self.hour() >= 12)]
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.datetime().minute()
+    }
+
+    pub fn second(&self) -> u32 {
+        self.datetime().second()
+    }
+
+    pub fn millisecond(&self) -> u32 {
+        self.datetime().timestamp_subsec_millis()
+    }
+
+    /// value of a unit in the instance timezone, [`Unit::Day`] is the day of the month and
+    /// [`Unit::Week`] or [`Unit::Isoweek`] the ISO week number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// let units = ["year", "M", "D", "hour"].map(|u| date.get(u.parse::<Unit>().unwrap()));
+    /// assert_eq!(units, [2024, 1, 5, 8]);
+    /// ```
+    pub fn get(&self, unit: Unit) -> i64 {
+        match unit {
+            Unit::Millisecond => self.millisecond().into(),
+            Unit::Second => self.second().into(),
+            Unit::Minute => self.minute().into(),
+            Unit::Hour => self.hour().into(),
+            Unit::Day => self.date().into(),
+            Unit::Week | Unit::Isoweek => self.datetime().iso_week().week().into(),
+            Unit::Month => self.month().into(),
+            Unit::Quarter => self.quarter().into(),
+            Unit::Year => self.year().into(),
+        }
+    }
+
+    /// the same instance with one unit set in the instance timezone, see [`Dayjs::get`]
+    ///
+    /// other units keep their wall clock value, a day is clamped to the end of a shorter month
+    /// when setting a month, quarter or year; values outside the unit's range are an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1706704215);
+    /// assert_eq!(date.set(Unit::Month, 4).unwrap().format("YYYY-MM-DD"), "2024-04-30");
+    /// assert_eq!(date.set(Unit::Hour, 0).unwrap().format("DD HH:mm"), "31 00:30");
+    /// assert!(date.set(Unit::Minute, 60).is_err());
+    /// ```
+    pub fn set(&self, unit: Unit, value: i64) -> Result<Dayjs, DayjsError> {
+        let local = self.datetime().naive_local();
+        let (min, max) = match unit {
+            Unit::Millisecond => (0, 999),
+            Unit::Second | Unit::Minute => (0, 59),
+            Unit::Hour => (0, 23),
+            Unit::Day => (1, self.days_in_month().into()),
+            Unit::Week | Unit::Isoweek => (1, iso_weeks(local.iso_week().year()).into()),
+            Unit::Month => (1, 12),
+            Unit::Quarter => (1, 4),
+            Unit::Year => (NaiveDate::MIN.year().into(), NaiveDate::MAX.year().into()),
+        };
+        if !(min..=max).contains(&value) {
+            return Err(DayjsError::OutOfRange(format!(
+                "{} {} is not between {} and {}",
+                format!("{:?}", unit).to_lowercase(),
+                value,
+                min,
+                max
+            )));
+        }
+        let diff = value - self.get(unit);
+        Ok(match unit {
+            Unit::Hour => Dayjs {
+                time: self.tz.local_to_utc(local + Duration::hours(diff)),
+                ..*self
+            },
+            Unit::Week | Unit::Isoweek => self.add(diff, Unit::Week),
+            _ => self.add(diff, unit),
+        })
+    }
+}
+
+/// weekday of january 1st, 0 for sunday, by Gauss's rule
+const fn new_year_weekday(year: i32) -> i32 {
+    let y = year - 1;
+    (1 + 5 * y.rem_euclid(4) + 4 * y.rem_euclid(100) + 6 * y.rem_euclid(400)).rem_euclid(7)
+}
+
+/// number of ISO weeks in an ISO week year, 52 or 53: years starting on a thursday have the
+/// leap week, and so do leap years starting on a wednesday
+const fn iso_weeks(year: i32) -> u32 {
+    let first = new_year_weekday(year);
+    52 + ((first == 4) | ((first == 3) & is_leap_year(year))) as u32
+}
@@ -0,0 +1,134 @@
+use crate::locale::EN;
+use crate::parse::{self, Cursor};
+use crate::rss::from_rss_date;
+use crate::{parse_rfc3339_strict, Dayjs, DayjsError, DayjsTimeZone};
+use chrono::{DateTime, FixedOffset};
+
+/// date formats printed by `git log --date=...`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GitDateFormat {
+    /// `1704443400 +0800`, as stored in commit objects
+    Raw,
+    /// `2024-01-05 16:30:00 +0800`
+    Iso,
+    /// `2024-01-05T16:30:00+08:00`
+    IsoStrict,
+    /// `Fri, 5 Jan 2024 16:30:00 +0800`
+    Rfc2822,
+}
+
+impl Dayjs {
+    /// format like git does, in the instance timezone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::git::{from_git_commit_time, GitDateFormat};
+    /// let date = from_git_commit_time(1704443400, 8 * 60).unwrap();
+    /// assert_eq!(date.to_git_date(GitDateFormat::Raw), "1704443400 +0800");
+    /// assert_eq!(date.to_git_date(GitDateFormat::Iso), "2024-01-05 16:30:00 +0800");
+    /// ```
+    pub fn to_git_date(&self, format: GitDateFormat) -> String {
+        let template = match format {
+            GitDateFormat::Raw => {
+                return format!("{} {}", self.time.timestamp(), self.format("ZZ"))
+            }
+            GitDateFormat::Iso => "YYYY-MM-DD HH:mm:ss ZZ",
+            GitDateFormat::IsoStrict => "YYYY-MM-DD[T]HH:mm:ssZ",
+            GitDateFormat::Rfc2822 => "ddd, D MMM YYYY HH:mm:ss ZZ",
+        };
+        self.format_locale(template, &EN)
+    }
+}
+
+/// get dayjs instance from a commit time, keeping the author's offset in the tz
+///
+/// `offset_minutes` is minutes east of UTC, as in git's `<epoch> <offset>` header; an offset of a
+/// day or more, or an epoch outside the supported dates, is an error
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::git::from_git_commit_time(1704443400, -5 * 60).unwrap();
+/// assert_eq!(date.tz, dayjs::DayjsTimeZone::Offset(-5 * 3600));
+/// assert_eq!(date.format("YYYY-MM-DD HH:mm Z"), "2024-01-05 03:30 -05:00");
+/// assert!(dayjs::git::from_git_commit_time(1704443400, i32::MAX).is_err());
+/// ```
+pub fn from_git_commit_time(epoch: i64, offset_minutes: i32) -> Result<Dayjs, DayjsError> {
+    let offset = offset_minutes
+        .checked_mul(60)
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| {
+            DayjsError::OutOfRange(format!(
+                "offset of {} minutes is out of range",
+                offset_minutes
+            ))
+        })?;
+    let time = DateTime::from_timestamp(epoch, 0)
+        .ok_or_else(|| DayjsError::OutOfRange(format!("commit time {} is out of range", epoch)))?;
+    Ok(Dayjs {
+        tz: DayjsTimeZone::Offset(offset.local_minus_utc()),
+        time,
+    })
+}
+
+/// `<epoch> <offset>`, git also accepts a leading `@`
+fn raw(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "git raw date");
+    c.eat(b'@');
+    let epoch = c.number(12, "epoch seconds")? as i64;
+    c.expect(b' ')?;
+    let negative = c.sign()?;
+    let offset = parse::offset(&mut c, negative, false)?;
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    from_git_commit_time(epoch, offset / 60)
+}
+
+/// `YYYY-MM-DD HH:MM:SS ±hhmm`
+fn iso(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "git ISO date");
+    let year = c.digits(4, "year")?;
+    c.expect(b'-')?;
+    let month = c.digits(2, "month")?;
+    c.expect(b'-')?;
+    let day = c.digits(2, "day")?;
+    c.expect(b' ')?;
+    let hour = c.digits(2, "hour")?;
+    c.expect(b':')?;
+    let minute = c.digits(2, "minute")?;
+    c.expect(b':')?;
+    let second = c.digits(2, "second")?;
+    c.expect(b' ')?;
+    let negative = c.sign()?;
+    let offset = parse::offset(&mut c, negative, false)?;
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    let date = parse::date(&c, year, month, day)?;
+    let time = parse::time(&c, hour, minute, second, 0)?;
+    Ok(parse::zoned(date.and_time(time), Some(offset)))
+}
+
+/// parse any date printed by git: raw, ISO, strict ISO or RFC 2822, keeping the offset in the tz
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::git::from_git_date;
+/// let raw = from_git_date("1704443400 +0800").unwrap();
+/// let iso = from_git_date("2024-01-05 16:30:00 +0800").unwrap();
+/// let rfc = from_git_date("Fri, 5 Jan 2024 16:30:00 +0800").unwrap();
+/// assert_eq!(raw, iso);
+/// assert_eq!(iso, rfc);
+/// ```
+pub fn from_git_date(s: &str) -> Result<Dayjs, DayjsError> {
+    let s = s.trim();
+    match s.as_bytes().get(4) {
+        Some(b'-') if s.as_bytes().get(10) == Some(&b'T') => parse_rfc3339_strict(s),
+        Some(b'-') => iso(s),
+        _ if s.starts_with(|c: char| c == '@' || c.is_ascii_digit()) && !s.contains(':') => raw(s),
+        _ => from_rss_date(s),
+    }
+}
@@ -0,0 +1,379 @@
+use crate::parse::{self, Cursor};
+use crate::timezone::{format_offset, OffsetStyle};
+use crate::{Dayjs, DayjsError, DayjsTimeZone};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use std::fmt::{Display, Formatter};
+
+/// value of a `<time datetime>` attribute, one of the WHATWG date and time microsyntaxes
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HtmlDatetime {
+    /// `2024`
+    Year(i32),
+    /// `2024-01`
+    Month { year: i32, month: u32 },
+    /// `2024-01-05`
+    Date(NaiveDate),
+    /// `01-05` or `--01-05`
+    YearlessDate { month: u32, day: u32 },
+    /// `08:30` or `08:30:15.250`
+    Time(NaiveTime),
+    /// `2024-01-05T08:30`
+    LocalDateTime(NaiveDateTime),
+    /// `Z` or `+08:00`, in seconds east of UTC
+    TimeZoneOffset(i32),
+    /// `2024-01-05T08:30+08:00`, the offset is kept in the tz
+    GlobalDateTime(Dayjs),
+    /// `2024-W01`
+    Week { year: i32, week: u32 },
+    /// `PT4H18M3S` or `4h 18m 3s`
+    Duration(Duration),
+}
+
+/// shortest time string, seconds and milliseconds are omitted when zero
+fn write_time(f: &mut Formatter<'_>, time: &NaiveTime) -> std::fmt::Result {
+    write!(f, "{:02}:{:02}", time.hour(), time.minute())?;
+    let millis = time.nanosecond() / 1_000_000;
+    match (time.second(), millis) {
+        (0, 0) => Ok(()),
+        (second, 0) => write!(f, ":{:02}", second),
+        (second, millis) => write!(f, ":{:02}.{:03}", second, millis),
+    }
+}
+
+fn write_date(f: &mut Formatter<'_>, date: &NaiveDate) -> std::fmt::Result {
+    write!(
+        f,
+        "{:04}-{:02}-{:02}",
+        date.year(),
+        date.month(),
+        date.day()
+    )
+}
+
+fn write_offset(f: &mut Formatter<'_>, offset: i32) -> std::fmt::Result {
+    match offset {
+        0 => write!(f, "Z"),
+        _ => write!(f, "{}", format_offset(offset, OffsetStyle::Colon)),
+    }
+}
+
+/// duration as `P1DT4H18M3.5S`
+fn write_duration(f: &mut Formatter<'_>, duration: &Duration) -> std::fmt::Result {
+    let millis = duration.num_milliseconds().abs();
+    let (days, rest) = (millis / 86_400_000, millis % 86_400_000);
+    let (hours, minutes) = (rest / 3_600_000, rest % 3_600_000 / 60_000);
+    let (seconds, millis) = (rest % 60_000 / 1000, rest % 1000);
+    write!(f, "P")?;
+    if days > 0 {
+        write!(f, "{}D", days)?;
+    }
+    if days > 0 && rest == 0 {
+        return Ok(());
+    }
+    write!(f, "T")?;
+    if hours > 0 {
+        write!(f, "{}H", hours)?;
+    }
+    if minutes > 0 {
+        write!(f, "{}M", minutes)?;
+    }
+    match (seconds, millis) {
+        (_, 0) if seconds > 0 || rest == 0 => write!(f, "{}S", seconds),
+        (_, 0) => Ok(()),
+        (seconds, millis) => {
+            let fraction = format!("{:03}", millis);
+            write!(f, "{}.{}S", seconds, fraction.trim_end_matches('0'))
+        }
+    }
+}
+
+impl Display for HtmlDatetime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HtmlDatetime::Year(year) => write!(f, "{:04}", year),
+            HtmlDatetime::Month { year, month } => write!(f, "{:04}-{:02}", year, month),
+            HtmlDatetime::Date(date) => write_date(f, date),
+            HtmlDatetime::YearlessDate { month, day } => write!(f, "{:02}-{:02}", month, day),
+            HtmlDatetime::Time(time) => write_time(f, time),
+            HtmlDatetime::LocalDateTime(datetime) => {
+                write_date(f, &datetime.date())?;
+                write!(f, "T")?;
+                write_time(f, &datetime.time())
+            }
+            HtmlDatetime::TimeZoneOffset(offset) => write_offset(f, *offset),
+            HtmlDatetime::GlobalDateTime(date) => write!(f, "{}", date.to_html_datetime()),
+            HtmlDatetime::Week { year, week } => write!(f, "{:04}-W{:02}", year, week),
+            HtmlDatetime::Duration(duration) => write_duration(f, duration),
+        }
+    }
+}
+
+impl Dayjs {
+    /// format as a valid global date and time string for `<time datetime>`
+    ///
+    /// the wall clock of the instance timezone with its offset, seconds and milliseconds are
+    /// omitted when zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut date = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(date.to_html_datetime(), "2024-01-05T08:30Z");
+    /// date.tz = dayjs::DayjsTimeZone::Offset(8 * 3600);
+    /// assert_eq!(date.to_html_datetime(), "2024-01-05T16:30+08:00");
+    /// ```
+    pub fn to_html_datetime(&self) -> String {
+        let time = self.datetime();
+        let offset = match self.tz {
+            DayjsTimeZone::DEFAULT => 0,
+            _ => time.offset().local_minus_utc(),
+        };
+        let local = HtmlDatetime::LocalDateTime(time.naive_local());
+        let mut out = local.to_string();
+        out.push_str(&HtmlDatetime::TimeZoneOffset(offset).to_string());
+        out
+    }
+}
+
+/// four or more digits, above zero
+fn year(c: &mut Cursor) -> Result<i32, DayjsError> {
+    let n = c.digit_run();
+    if n < 4 {
+        return Err(c.error("expected 4 digit year"));
+    }
+    if n > 6 {
+        return Err(c.error("year out of range"));
+    }
+    match c.digits(n, "year")? {
+        0 => Err(c.error("year must be above zero")),
+        year => Ok(year as i32),
+    }
+}
+
+/// `HH:MM`, optionally followed by `:SS` and 1 to 3 fraction digits
+fn time(c: &mut Cursor) -> Result<NaiveTime, DayjsError> {
+    let hour = c.digits(2, "hour")?;
+    c.expect(b':')?;
+    let minute = c.digits(2, "minute")?;
+    let (second, nanos) = if c.eat(b':') {
+        let second = c.digits(2, "second")?;
+        if second > 59 {
+            return Err(c.error("second out of range"));
+        }
+        let nanos = if c.eat(b'.') {
+            if c.digit_run() > 3 {
+                return Err(c.error("expected at most 3 fraction digits"));
+            }
+            c.fraction()?
+        } else {
+            0
+        };
+        (second, nanos)
+    } else {
+        (0, 0)
+    };
+    parse::time(c, hour, minute, second, nanos)
+}
+
+/// `P1DT4H18M3S`, days, hours, minutes and seconds only
+fn iso_duration(c: &mut Cursor) -> Result<Duration, DayjsError> {
+    c.expect(b'P')?;
+    let mut millis = 0i64;
+    let mut units: &[(u8, i64)] = &[(b'D', 86_400_000)];
+    let mut in_time = false;
+    let mut any = false;
+    loop {
+        if !in_time && c.eat(b'T') {
+            in_time = true;
+            any = false;
+            units = &[(b'H', 3_600_000), (b'M', 60_000), (b'S', 1000)];
+            continue;
+        }
+        if c.at_end() {
+            break;
+        }
+        let (value, fraction) = number(c)?;
+        let position = units.iter().position(|(unit, _)| c.peek() == Some(*unit));
+        let Some(position) = position else {
+            return Err(c.error("expected a duration unit in order"));
+        };
+        let (unit, scale) = units[position];
+        if fraction.is_some() && unit != b'S' {
+            return Err(c.error("only seconds may have a fraction"));
+        }
+        c.bump();
+        millis += value * scale + i64::from(fraction.unwrap_or(0)) / 1_000_000;
+        units = &units[position + 1..];
+        any = true;
+    }
+    if !any {
+        return Err(c.error("expected a duration component"));
+    }
+    Ok(Duration::milliseconds(millis))
+}
+
+/// `4h 18m 3s`, each unit at most once in any order
+fn component_duration(c: &mut Cursor) -> Result<Duration, DayjsError> {
+    let mut millis = 0i64;
+    let mut seen = Vec::new();
+    loop {
+        while c.eat(b' ') || c.eat(b'\t') || c.eat(b'\n') || c.eat(b'\r') {}
+        if c.at_end() {
+            break;
+        }
+        let (value, fraction) = number(c)?;
+        while c.eat(b' ') || c.eat(b'\t') || c.eat(b'\n') || c.eat(b'\r') {}
+        let unit = c.peek().map(|b| b.to_ascii_lowercase());
+        let scale = match unit {
+            Some(b'w') => 604_800_000,
+            Some(b'd') => 86_400_000,
+            Some(b'h') => 3_600_000,
+            Some(b'm') => 60_000,
+            Some(b's') => 1000,
+            _ => return Err(c.error("expected one of 'w', 'd', 'h', 'm' or 's'")),
+        };
+        if seen.contains(&unit) {
+            return Err(c.error("duplicate duration unit"));
+        }
+        if fraction.is_some() && unit != Some(b's') {
+            return Err(c.error("only seconds may have a fraction"));
+        }
+        seen.push(unit);
+        c.bump();
+        millis += value * scale + i64::from(fraction.unwrap_or(0)) / 1_000_000;
+    }
+    if seen.is_empty() {
+        return Err(c.error("expected a duration component"));
+    }
+    Ok(Duration::milliseconds(millis))
+}
+
+/// integer with an optional fraction of up to 3 digits, as nanoseconds
+fn number(c: &mut Cursor) -> Result<(i64, Option<u32>), DayjsError> {
+    let n = c.digit_run();
+    if n == 0 || n > 9 {
+        return Err(c.error("expected a number"));
+    }
+    let value = c.digits(n, "number")?;
+    let fraction = if c.eat(b'.') {
+        if c.digit_run() > 3 {
+            return Err(c.error("expected at most 3 fraction digits"));
+        }
+        Some(c.fraction()?)
+    } else {
+        None
+    };
+    Ok((i64::from(value), fraction))
+}
+
+fn html_datetime(c: &mut Cursor) -> Result<HtmlDatetime, DayjsError> {
+    match c.peek() {
+        Some(b'P') => return iso_duration(c).map(HtmlDatetime::Duration),
+        Some(b'-') if c.peek_at(1) == Some(b'-') => {
+            c.bump();
+            c.bump();
+            return yearless(c);
+        }
+        Some(b'Z') => {
+            c.bump();
+            return finish(c, HtmlDatetime::TimeZoneOffset(0));
+        }
+        Some(sign @ (b'+' | b'-')) => {
+            c.bump();
+            let offset = parse::offset(c, sign == b'-', false)?;
+            return finish(c, HtmlDatetime::TimeZoneOffset(offset));
+        }
+        _ => {}
+    }
+    let run = c.digit_run();
+    if run == 2 {
+        return match c.peek_at(2) {
+            Some(b':') => time(c).and_then(|t| finish(c, HtmlDatetime::Time(t))),
+            Some(b'-') => yearless(c),
+            _ => component_duration(c).map(HtmlDatetime::Duration),
+        };
+    }
+    if run < 4 {
+        return component_duration(c).map(HtmlDatetime::Duration);
+    }
+    let year = year(c)?;
+    if c.at_end() {
+        return Ok(HtmlDatetime::Year(year));
+    }
+    c.expect(b'-')?;
+    if c.eat(b'W') {
+        let week = c.digits(2, "week")?;
+        NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+            .ok_or_else(|| c.error("week out of range"))?;
+        return finish(c, HtmlDatetime::Week { year, week });
+    }
+    let month = c.digits(2, "month")?;
+    if c.at_end() {
+        if !(1..=12).contains(&month) {
+            return Err(c.error("month out of range"));
+        }
+        return Ok(HtmlDatetime::Month { year, month });
+    }
+    c.expect(b'-')?;
+    let day = c.digits(2, "day")?;
+    let date = parse::date(c, year as u32, month, day)?;
+    if c.at_end() {
+        return Ok(HtmlDatetime::Date(date));
+    }
+    if !(c.eat(b'T') || c.eat(b' ')) {
+        return Err(c.error("expected 'T' or a space"));
+    }
+    let local = date.and_time(time(c)?);
+    if c.at_end() {
+        return Ok(HtmlDatetime::LocalDateTime(local));
+    }
+    let offset = match c.peek() {
+        Some(b'Z') => {
+            c.bump();
+            None
+        }
+        Some(sign @ (b'+' | b'-')) => {
+            c.bump();
+            Some(parse::offset(c, sign == b'-', false)?)
+        }
+        _ => return Err(c.error("expected 'Z' or an offset")),
+    };
+    finish(c, HtmlDatetime::GlobalDateTime(parse::zoned(local, offset)))
+}
+
+/// `MM-DD` after an optional `--`, 02-29 is always valid
+fn yearless(c: &mut Cursor) -> Result<HtmlDatetime, DayjsError> {
+    let month = c.digits(2, "month")?;
+    c.expect(b'-')?;
+    let day = c.digits(2, "day")?;
+    parse::date(c, 2000, month, day)?;
+    finish(c, HtmlDatetime::YearlessDate { month, day })
+}
+
+fn finish(c: &Cursor, value: HtmlDatetime) -> Result<HtmlDatetime, DayjsError> {
+    if c.at_end() {
+        Ok(value)
+    } else {
+        Err(c.error("unexpected trailing characters"))
+    }
+}
+
+/// parse a `<time datetime>` attribute value
+///
+/// accepts every WHATWG form: year, month, date, yearless date, time, local and global date and
+/// time, time zone offset, week and duration
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::html::{from_html_datetime, HtmlDatetime};
+/// let date = from_html_datetime("2024-01-05 08:30+08:00").unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30+08:00");
+/// let week = from_html_datetime("2024-W01").unwrap();
+/// assert_eq!(week, HtmlDatetime::Week { year: 2024, week: 1 });
+/// let duration = from_html_datetime("4h 18m 3s").unwrap();
+/// assert_eq!(duration.to_string(), "PT4H18M3S");
+/// ```
+pub fn from_html_datetime(s: &str) -> Result<HtmlDatetime, DayjsError> {
+    html_datetime(&mut Cursor::new(s, "HTML datetime"))
+}
@@ -0,0 +1,214 @@
+use crate::{from_format_dayjs_strict, Dayjs, DayjsError};
+use std::sync::OnceLock;
+
+/// order of the date fields in a template
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    YearFirst,
+    DayFirst,
+    MonthFirst,
+}
+
+/// a template that reads some of the samples, see [`infer_format`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatGuess {
+    /// dayjs template for [`crate::from_format_dayjs_strict`]
+    pub template: String,
+    pub order: DateOrder,
+    /// samples the template reads exactly
+    pub matched: usize,
+    /// share of the samples it reads, 0 to 1
+    pub confidence: f64,
+    /// the template with day and month swapped reads the same samples, e.g. only days up to 12
+    pub ambiguous: bool,
+}
+
+const DATES: [&str; 18] = [
+    "YYYY-MM-DD",
+    "YYYY/MM/DD",
+    "YYYYMMDD",
+    "DD/MM/YYYY",
+    "MM/DD/YYYY",
+    "D/M/YYYY",
+    "M/D/YYYY",
+    "DD/MM/YY",
+    "MM/DD/YY",
+    "DD.MM.YYYY",
+    "D.M.YYYY",
+    "DD-MM-YYYY",
+    "MM-DD-YYYY",
+    "D MMM YYYY",
+    "MMM D, YYYY",
+    "D MMMM YYYY",
+    "MMMM D, YYYY",
+    "ddd, D MMM YYYY",
+];
+
+const TIMES: [&str; 10] = [
+    "",
+    " HH:mm",
+    " HH:mm:ss",
+    " HH:mm:ss.SSS",
+    " h:mm A",
+    " h:mm:ss A",
+    "THH:mm:ss",
+    "THH:mm:ss.SSS",
+    "THH:mm:ssZ",
+    "THH:mm:ss.SSSZ",
+];
+
+/// every date layout with every time layout, in table order
+fn layouts() -> &'static [String] {
+    static LAYOUTS: OnceLock<Vec<String>> = OnceLock::new();
+    LAYOUTS.get_or_init(|| {
+        DATES
+            .iter()
+            .flat_map(|date| TIMES.iter().map(move |time| format!("{}{}", date, time)))
+            .collect()
+    })
+}
+
+/// the order of the day and month tokens in a template
+fn order(date: &str) -> DateOrder {
+    match (date.find('D'), date.find('M'), date.find('Y')) {
+        (_, _, Some(0)) => DateOrder::YearFirst,
+        (Some(d), Some(m), _) if d < m => DateOrder::DayFirst,
+        _ => DateOrder::MonthFirst,
+    }
+}
+
+/// day and month tokens swapped, "DD/MM/YYYY" for "MM/DD/YYYY"
+fn swapped(template: &str) -> String {
+    template
+        .chars()
+        .map(|c| match c {
+            'D' => 'M',
+            'M' => 'D',
+            c => c,
+        })
+        .collect()
+}
+
+/// rank the templates that read sample timestamps exactly, the most samples first
+///
+/// templates are common numeric and named date layouts with an optional time, names are read in
+/// the active locale
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{infer_format, DateOrder};
+/// let guesses = infer_format(&["05/01/2024 08:30", "28/02/2024 17:05", "31/12/2023 23:59"]);
+/// assert_eq!(guesses[0].template, "DD/MM/YYYY HH:mm");
+/// assert_eq!(guesses[0].order, DateOrder::DayFirst);
+/// assert_eq!(guesses[0].confidence, 1.0);
+/// assert!(!guesses[0].ambiguous);
+/// ```
+pub fn infer_format(samples: &[&str]) -> Vec<FormatGuess> {
+    let mut guesses: Vec<FormatGuess> = layouts()
+        .iter()
+        .filter_map(|template| {
+            let matched = samples
+                .iter()
+                .filter(|s| from_format_dayjs_strict(s, template).is_ok())
+                .count();
+            (matched > 0).then(|| FormatGuess {
+                template: template.clone(),
+                order: order(template),
+                matched,
+                confidence: matched as f64 / samples.len() as f64,
+                ambiguous: false,
+            })
+        })
+        .collect();
+    let counts: Vec<(String, usize)> = guesses
+        .iter()
+        .map(|g| (g.template.clone(), g.matched))
+        .collect();
+    for guess in guesses.iter_mut() {
+        let other = swapped(&guess.template);
+        guess.ambiguous = guess.order != DateOrder::YearFirst
+            && counts
+                .iter()
+                .any(|(t, n)| *t == other && *n == guess.matched);
+    }
+    guesses.sort_by_key(|g| std::cmp::Reverse(g.matched));
+    guesses
+}
+
+/// read a timestamp with the first layout of [`infer_format`] that reads it exactly
+///
+/// a [`ParserProfile`] kept between calls tries the layout that matched last first
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::parse_date_time("05/01/2024 08:30").unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// assert!(dayjs::parse_date_time("yesterday").is_err());
+/// ```
+pub fn parse_date_time(s: &str) -> Result<Dayjs, DayjsError> {
+    ParserProfile::new().parse(s)
+}
+
+/// per caller memo for [`parse_date_time`], a feed in one layout pays for one template per value
+/// instead of scanning the table
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::ParserProfile;
+/// let mut profile = ParserProfile::new();
+/// profile.parse("Jan 5, 2024 8:30 PM").unwrap();
+/// assert_eq!(profile.last_template(), Some("MMM D, YYYY h:mm A"));
+/// let mut pinned = ParserProfile::pinned(["DD.MM.YYYY HH:mm", "DD.MM.YYYY"]);
+/// assert!(pinned.parse("05.01.2024").is_ok());
+/// assert!(pinned.parse("2024-01-05").is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParserProfile {
+    /// templates to try instead of the layout table
+    pinned: Option<Vec<String>>,
+    last: Option<String>,
+}
+
+impl ParserProfile {
+    /// learn from the whole layout table
+    pub fn new() -> ParserProfile {
+        ParserProfile::default()
+    }
+
+    /// only these dayjs templates, in this order, still trying the last match first
+    pub fn pinned<S: Into<String>>(templates: impl IntoIterator<Item = S>) -> ParserProfile {
+        ParserProfile {
+            pinned: Some(templates.into_iter().map(Into::into).collect()),
+            last: None,
+        }
+    }
+
+    /// the template of the last successful parse
+    pub fn last_template(&self) -> Option<&str> {
+        self.last.as_deref()
+    }
+
+    /// read `s` exactly, see [`parse_date_time`]
+    pub fn parse(&mut self, s: &str) -> Result<Dayjs, DayjsError> {
+        let s = s.trim();
+        if let Some(date) = self
+            .last
+            .as_deref()
+            .and_then(|last| from_format_dayjs_strict(s, last).ok())
+        {
+            return Ok(date);
+        }
+        let templates = self.pinned.as_deref().unwrap_or(layouts());
+        let found = templates
+            .iter()
+            .filter(|template| self.last.as_ref() != Some(*template))
+            .find_map(|template| Some((template, from_format_dayjs_strict(s, template).ok()?)));
+        let (template, date) =
+            found.ok_or_else(|| DayjsError::parse("date time", s, "no known format matches"))?;
+        self.last = Some(template.clone());
+        Ok(date)
+    }
+}
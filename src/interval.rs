@@ -0,0 +1,188 @@
+use crate::{Dayjs, DayjsTimeZone, Unit};
+use chrono::Duration;
+
+/// half-open span of time `[start, end)`, empty when `end` is not after `start`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Interval {
+    pub start: Dayjs,
+    pub end: Dayjs,
+}
+
+impl Interval {
+    pub fn new(start: Dayjs, end: Dayjs) -> Interval {
+        Interval { start, end }
+    }
+
+    /// length of the interval, zero when empty
+    pub fn duration(&self) -> Duration {
+        (self.end.time - self.start.time).max(Duration::zero())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end.time <= self.start.time
+    }
+
+    /// whether the instant is in `[start, end)`
+    pub fn contains(&self, date: &Dayjs) -> bool {
+        self.start.time <= date.time && date.time < self.end.time
+    }
+
+    /// the interval of the same length just before this one, for period over period deltas
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{Interval, Unit};
+    /// let start = dayjs::from_timestamp(1704443400);
+    /// let week = Interval::new(start, start.add(1, Unit::Week));
+    /// let previous = week.previous_comparable();
+    /// assert_eq!(previous.start.to_string(), "2023-12-29T08:30:00+00:00");
+    /// assert_eq!(previous.end, week.start);
+    /// ```
+    pub fn previous_comparable(&self) -> Interval {
+        Interval::new(self.start - self.duration(), self.start)
+    }
+
+    /// the same calendar period a year earlier in the bounds' timezones, february 29 becomes
+    /// february 28, so the length may differ
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{Interval, Unit};
+    /// let february = dayjs::from_timestamp(1706745600).start_of("month");
+    /// let interval = Interval::new(february, february.add(1, Unit::Month));
+    /// let last_year = interval.same_period_last_year();
+    /// assert_eq!(last_year.start.format("YYYY-MM-DD"), "2023-02-01");
+    /// assert_eq!(last_year.end.format("YYYY-MM-DD"), "2023-03-01");
+    /// assert_eq!(last_year.duration(), chrono::Duration::days(28));
+    /// ```
+    pub fn same_period_last_year(&self) -> Interval {
+        Interval::new(self.start.add_years(-1), self.end.add_years(-1))
+    }
+
+    /// the overlap of two intervals, `None` when they do not overlap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{Interval, Unit};
+    /// let start = dayjs::from_timestamp(1704443400);
+    /// let a = Interval::new(start, start.add(2, Unit::Hour));
+    /// let b = Interval::new(start.add(1, Unit::Hour), start.add(3, Unit::Hour));
+    /// let overlap = a.intersection(&b).unwrap();
+    /// assert_eq!(overlap.duration(), chrono::Duration::hours(1));
+    /// assert!(a.intersection(&Interval::new(a.end, b.end)).is_none());
+    /// ```
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let start = if self.start.time >= other.start.time {
+            self.start
+        } else {
+            other.start
+        };
+        let end = if self.end.time <= other.end.time {
+            self.end
+        } else {
+            other.end
+        };
+        let overlap = Interval { start, end };
+        (!overlap.is_empty()).then_some(overlap)
+    }
+}
+
+/// a `(start, end)` pair like [`Dayjs::bounds_of`] returns
+impl From<(Dayjs, Dayjs)> for Interval {
+    fn from((start, end): (Dayjs, Dayjs)) -> Self {
+        Interval { start, end }
+    }
+}
+
+impl Dayjs {
+    /// the `length` of time up to this instant, `[self - length, self)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let now = dayjs::from_timestamp(1704443415);
+    /// let window = now.trailing_window(chrono::Duration::days(7));
+    /// assert_eq!(window.start.to_string(), "2023-12-29T08:30:15+00:00");
+    /// assert_eq!(window.end, now);
+    /// ```
+    pub fn trailing_window(&self, length: Duration) -> Interval {
+        Interval::new(*self - length, *self)
+    }
+
+    /// the `length` of time from this instant on, `[self, self + length)`
+    pub fn leading_window(&self, length: Duration) -> Interval {
+        Interval::new(*self, *self + length)
+    }
+
+    /// the `n` full units in `tz` before the one containing this instant, e.g. the last 7 full
+    /// days without today
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTimeZone, Unit};
+    /// let now = dayjs::from_timestamp(1704443415);
+    /// let week = now.trailing_units(7, Unit::Day, &DayjsTimeZone::Offset(-8 * 3600));
+    /// assert_eq!(week.start.format("YYYY-MM-DD HH:mm Z"), "2023-12-29 00:00 -08:00");
+    /// assert_eq!(week.end.format("YYYY-MM-DD HH:mm Z"), "2024-01-05 00:00 -08:00");
+    /// ```
+    pub fn trailing_units(&self, n: i64, unit: Unit, tz: &DayjsTimeZone) -> Interval {
+        let end = self.start_of_in(unit, tz);
+        Interval::new(end.subtract(n, unit), end)
+    }
+
+    /// the `n` full units in `tz` after the one containing this instant, e.g. the next 4 full
+    /// weeks
+    pub fn leading_units(&self, n: i64, unit: Unit, tz: &DayjsTimeZone) -> Interval {
+        let start = self.start_of_in(unit, tz).add(1, unit);
+        Interval::new(start, start.add(n, unit))
+    }
+}
+
+/// sorted, disjoint intervals covering the same time as `intervals` within `window`
+pub(crate) fn merged(intervals: &[Interval], window: &Interval) -> Vec<Interval> {
+    let mut clipped: Vec<Interval> = intervals
+        .iter()
+        .filter_map(|i| i.intersection(window))
+        .collect();
+    clipped.sort_by_key(|i| i.start.time);
+    let mut out: Vec<Interval> = Vec::with_capacity(clipped.len());
+    for interval in clipped {
+        match out.last_mut() {
+            Some(last) if interval.start.time <= last.end.time => {
+                if interval.end.time > last.end.time {
+                    last.end = interval.end;
+                }
+            }
+            _ => out.push(interval),
+        }
+    }
+    out
+}
+
+/// total length of sorted, disjoint intervals
+pub(crate) fn total(intervals: &[Interval]) -> Duration {
+    intervals
+        .iter()
+        .fold(Duration::zero(), |sum, i| sum + i.duration())
+}
+
+/// total overlap of two lists of sorted, disjoint intervals
+pub(crate) fn overlap(a: &[Interval], b: &[Interval]) -> Duration {
+    let (mut i, mut j) = (0, 0);
+    let mut sum = Duration::zero();
+    while i < a.len() && j < b.len() {
+        if let Some(both) = a[i].intersection(&b[j]) {
+            sum += both.duration();
+        }
+        if a[i].end.time <= b[j].end.time {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    sum
+}
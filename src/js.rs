@@ -0,0 +1,106 @@
+use crate::{Dayjs, Epoch, Precision};
+
+/// Day.js method names for porting javascript code, each wraps the idiomatic method it names
+///
+/// units are Day.js unit strings like `"day"` or `"M"`, an unknown or missing unit compares
+/// milliseconds like Day.js; comparisons use the instant and the units of this instance's tz
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::js::DayjsJs;
+/// let date = dayjs::from_timestamp(1704443415250);
+/// let later = dayjs::from_timestamp(1704470400000);
+/// assert_eq!(date.startOf("day").valueOf(), 1704412800000);
+/// assert!(date.isSame(&later, Some("day")));
+/// assert!(date.isBefore(&later, None));
+/// assert_eq!(date.toISOString(), "2024-01-05T08:30:15.250Z");
+/// ```
+#[allow(non_snake_case)]
+pub trait DayjsJs {
+    /// [`Dayjs::to_epoch`] in unix milliseconds
+    fn valueOf(&self) -> i64;
+    /// [`Dayjs::to_epoch`] in unix seconds
+    fn unix(&self) -> i64;
+    /// [`Dayjs::start_of`]
+    fn startOf(&self, unit: &str) -> Dayjs;
+    /// [`Dayjs::end_of`]
+    fn endOf(&self, unit: &str) -> Dayjs;
+    /// whether `other` is in the same unit as this instance
+    fn isSame(&self, other: &Dayjs, unit: Option<&str>) -> bool;
+    /// whether the unit of this instance ends before `other`
+    fn isBefore(&self, other: &Dayjs, unit: Option<&str>) -> bool;
+    /// whether the unit of this instance starts after `other`
+    fn isAfter(&self, other: &Dayjs, unit: Option<&str>) -> bool;
+    fn isSameOrBefore(&self, other: &Dayjs, unit: Option<&str>) -> bool;
+    fn isSameOrAfter(&self, other: &Dayjs, unit: Option<&str>) -> bool;
+    /// [`Dayjs::from_now`]
+    fn fromNow(&self) -> String;
+    /// [`Dayjs::to_now`]
+    fn toNow(&self) -> String;
+    /// [`Dayjs::days_in_month`]
+    fn daysInMonth(&self) -> u32;
+    /// [`Dayjs::utc_offset`] in minutes
+    fn utcOffset(&self) -> i32;
+    /// UTC with milliseconds, `2024-01-05T08:30:15.250Z`
+    fn toISOString(&self) -> String;
+}
+
+impl DayjsJs for Dayjs {
+    fn valueOf(&self) -> i64 {
+        self.to_epoch(Epoch::Unix, Precision::Milliseconds)
+    }
+
+    fn unix(&self) -> i64 {
+        self.to_epoch(Epoch::Unix, Precision::Seconds)
+    }
+
+    fn startOf(&self, unit: &str) -> Dayjs {
+        self.start_of(unit)
+    }
+
+    fn endOf(&self, unit: &str) -> Dayjs {
+        self.end_of(unit)
+    }
+
+    fn isSame(&self, other: &Dayjs, unit: Option<&str>) -> bool {
+        let unit = unit.unwrap_or("millisecond");
+        self.start_of(unit).time <= other.time && other.time <= self.end_of(unit).time
+    }
+
+    fn isBefore(&self, other: &Dayjs, unit: Option<&str>) -> bool {
+        self.end_of(unit.unwrap_or("millisecond")).time < other.time
+    }
+
+    fn isAfter(&self, other: &Dayjs, unit: Option<&str>) -> bool {
+        other.time < self.start_of(unit.unwrap_or("millisecond")).time
+    }
+
+    fn isSameOrBefore(&self, other: &Dayjs, unit: Option<&str>) -> bool {
+        self.isSame(other, unit) || self.isBefore(other, unit)
+    }
+
+    fn isSameOrAfter(&self, other: &Dayjs, unit: Option<&str>) -> bool {
+        self.isSame(other, unit) || self.isAfter(other, unit)
+    }
+
+    fn fromNow(&self) -> String {
+        self.from_now()
+    }
+
+    fn toNow(&self) -> String {
+        self.to_now()
+    }
+
+    fn daysInMonth(&self) -> u32 {
+        self.days_in_month()
+    }
+
+    fn utcOffset(&self) -> i32 {
+        self.utc_offset()
+    }
+
+    fn toISOString(&self) -> String {
+        self.utc().format("YYYY-MM-DDTHH:mm:ss.SSS[Z]")
+    }
+}
@@ -0,0 +1,270 @@
+use crate::parse::Cursor;
+use crate::DayjsError;
+use std::fmt::{Display, Formatter, Write};
+
+/// nesting the reader accepts, deeper input is an error instead of a stack overflow
+const MAX_DEPTH: usize = 128;
+
+/// a JSON value, numbers keep their text and objects their order
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// JSON type name for messages
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Json::Null => "null",
+            Json::Bool(_) => "boolean",
+            Json::Number(_) => "number",
+            Json::String(_) => "string",
+            Json::Array(_) => "array",
+            Json::Object(_) => "object",
+        }
+    }
+}
+
+/// read a whole JSON document, duplicate object keys are an error
+pub(crate) fn parse(s: &str, grammar: &'static str) -> Result<Json, DayjsError> {
+    let mut c = Cursor::new(s, grammar);
+    let value = value(&mut c, 0)?;
+    skip_space(&mut c);
+    if !c.at_end() {
+        return Err(c.error("unexpected text after the value"));
+    }
+    Ok(value)
+}
+
+fn skip_space(c: &mut Cursor) {
+    while matches!(c.peek(), Some(b' ' | b'\t' | b'\r' | b'\n')) {
+        c.bump();
+    }
+}
+
+fn value(c: &mut Cursor, depth: usize) -> Result<Json, DayjsError> {
+    if depth > MAX_DEPTH {
+        return Err(c.error("nested too deeply"));
+    }
+    skip_space(c);
+    match c.peek() {
+        Some(b'{') => {
+            c.bump();
+            let mut members: Vec<(String, Json)> = Vec::new();
+            skip_space(c);
+            if c.eat(b'}') {
+                return Ok(Json::Object(members));
+            }
+            loop {
+                skip_space(c);
+                let key = string(c)?;
+                if members.iter().any(|(k, _)| *k == key) {
+                    return Err(c.error(&format!("duplicate key {:?}", key)));
+                }
+                skip_space(c);
+                c.expect(b':')?;
+                members.push((key, value(c, depth + 1)?));
+                skip_space(c);
+                if c.eat(b'}') {
+                    return Ok(Json::Object(members));
+                }
+                c.expect(b',')?;
+            }
+        }
+        Some(b'[') => {
+            c.bump();
+            let mut items = Vec::new();
+            skip_space(c);
+            if c.eat(b']') {
+                return Ok(Json::Array(items));
+            }
+            loop {
+                items.push(value(c, depth + 1)?);
+                skip_space(c);
+                if c.eat(b']') {
+                    return Ok(Json::Array(items));
+                }
+                c.expect(b',')?;
+            }
+        }
+        Some(b'"') => string(c).map(Json::String),
+        Some(b'-' | b'0'..=b'9') => number(c),
+        _ => {
+            for (word, json) in [
+                ("null", Json::Null),
+                ("true", Json::Bool(true)),
+                ("false", Json::Bool(false)),
+            ] {
+                if c.rest().starts_with(word) {
+                    c.advance(word.len());
+                    return Ok(json);
+                }
+            }
+            Err(c.error("expected a JSON value"))
+        }
+    }
+}
+
+/// `-?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?`
+fn number(c: &mut Cursor) -> Result<Json, DayjsError> {
+    let rest = c.rest();
+    let mut len = usize::from(rest.starts_with('-'));
+    let digits = |from: usize| {
+        rest.as_bytes()[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let int = digits(len);
+    if int == 0 || (int > 1 && rest.as_bytes()[len] == b'0') {
+        c.advance(len);
+        return Err(c.error("expected a number"));
+    }
+    len += int;
+    if rest[len..].starts_with('.') {
+        let fraction = digits(len + 1);
+        if fraction == 0 {
+            c.advance(len + 1);
+            return Err(c.error("expected fraction digits"));
+        }
+        len += 1 + fraction;
+    }
+    if rest[len..].starts_with(['e', 'E']) {
+        len += 1;
+        if rest[len..].starts_with(['+', '-']) {
+            len += 1;
+        }
+        let exponent = digits(len);
+        if exponent == 0 {
+            c.advance(len);
+            return Err(c.error("expected exponent digits"));
+        }
+        len += exponent;
+    }
+    c.advance(len);
+    Ok(Json::Number(rest[..len].to_string()))
+}
+
+fn string(c: &mut Cursor) -> Result<String, DayjsError> {
+    c.expect(b'"')?;
+    let mut out = String::new();
+    loop {
+        let Some(ch) = c.rest().chars().next() else {
+            return Err(c.error("unterminated string"));
+        };
+        match ch {
+            '"' => {
+                c.bump();
+                return Ok(out);
+            }
+            '\\' => {
+                c.bump();
+                let escaped = match c.peek() {
+                    Some(b'"') => '"',
+                    Some(b'\\') => '\\',
+                    Some(b'/') => '/',
+                    Some(b'b') => '\u{8}',
+                    Some(b'f') => '\u{c}',
+                    Some(b'n') => '\n',
+                    Some(b'r') => '\r',
+                    Some(b't') => '\t',
+                    Some(b'u') => {
+                        c.bump();
+                        let high = hex4(c)?;
+                        let code = if (0xd800..0xdc00).contains(&high) {
+                            if !c.rest().starts_with("\\u") {
+                                return Err(c.error("unpaired surrogate"));
+                            }
+                            c.advance(2);
+                            let low = hex4(c)?;
+                            if !(0xdc00..0xe000).contains(&low) {
+                                return Err(c.error("unpaired surrogate"));
+                            }
+                            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                        } else {
+                            high
+                        };
+                        out.push(
+                            char::from_u32(code).ok_or_else(|| c.error("unpaired surrogate"))?,
+                        );
+                        continue;
+                    }
+                    _ => return Err(c.error("invalid escape")),
+                };
+                c.bump();
+                out.push(escaped);
+            }
+            ch if u32::from(ch) < 0x20 => return Err(c.error("control character in string")),
+            ch => {
+                c.advance(ch.len_utf8());
+                out.push(ch);
+            }
+        }
+    }
+}
+
+fn hex4(c: &mut Cursor) -> Result<u32, DayjsError> {
+    let digits = c
+        .rest()
+        .get(..4)
+        .filter(|d| d.bytes().all(|b| b.is_ascii_hexdigit()));
+    let value = digits
+        .and_then(|d| u32::from_str_radix(d, 16).ok())
+        .ok_or_else(|| c.error("expected 4 hex digits"))?;
+    c.advance(4);
+    Ok(value)
+}
+
+pub(crate) fn write_string(f: &mut Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for ch in s.chars() {
+        match ch {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            ch if u32::from(ch) < 0x20 => write!(f, "\\u{:04x}", u32::from(ch))?,
+            ch => f.write_char(ch)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Json {
+    /// compact JSON text
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(n) => f.write_str(n),
+            Json::String(s) => write_string(f, s),
+            Json::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(members) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
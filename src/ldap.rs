@@ -0,0 +1,86 @@
+use crate::parse::{self, Cursor};
+use crate::{from_epoch, Dayjs, DayjsError, Epoch, Precision};
+use chrono::Duration;
+
+/// get dayjs instance from a Windows FILETIME or Active Directory timestamp, 100ns ticks since
+/// 1601-01-01 UTC
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::ldap::from_filetime(133_502_274_000_000_000);
+/// assert_eq!(date.to_string(), "2024-01-20T12:30:00+00:00");
+/// ```
+pub fn from_filetime(ticks: u64) -> Dayjs {
+    let ticks = ticks.min(i64::MAX as u64) as i64;
+    from_epoch(ticks, Epoch::WebKit, Precision::Ticks)
+}
+
+impl Dayjs {
+    /// Windows FILETIME, 100ns ticks since 1601-01-01 UTC, 0 before that
+    pub fn to_filetime(&self) -> u64 {
+        self.to_epoch(Epoch::WebKit, Precision::Ticks).max(0) as u64
+    }
+
+    /// LDAP GeneralizedTime in UTC, e.g. `20250325172147.0Z` as written by Active Directory
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1742923307);
+    /// assert_eq!(date.to_generalized_time(), "20250325172147.0Z");
+    /// ```
+    pub fn to_generalized_time(&self) -> String {
+        let fraction = self.time.timestamp_subsec_millis();
+        let fraction = format!("{:03}", fraction);
+        let fraction = match fraction.trim_end_matches('0') {
+            "" => "0",
+            trimmed => trimmed,
+        };
+        format!("{}.{}Z", self.time.format("%Y%m%d%H%M%S"), fraction)
+    }
+}
+
+/// parse an LDAP GeneralizedTime (RFC 4517), `YYYYMMDDHH[MM[SS]][.fraction](Z|±hh[mm])`
+///
+/// the fraction, with `.` or `,`, belongs to the last time component given
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::ldap::from_generalized_time("20250325172147.0Z").unwrap();
+/// assert_eq!(date.to_string(), "2025-03-25T17:21:47+00:00");
+/// let date = dayjs::ldap::from_generalized_time("202503251921.5+0200").unwrap();
+/// assert_eq!(date.to_string(), "2025-03-25T17:21:30+00:00");
+/// ```
+pub fn from_generalized_time(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "GeneralizedTime");
+    let year = c.digits(4, "year")?;
+    let month = c.digits(2, "month")?;
+    let day = c.digits(2, "day")?;
+    let date = parse::date(&c, year, month, day)?;
+    let mut parts = vec![c.digits(2, "hour")?];
+    while parts.len() < 3 && c.digit_run() >= 2 {
+        parts.push(c.digits(2, if parts.len() == 1 { "minute" } else { "second" })?);
+    }
+    let fraction = if c.eat(b'.') || c.eat(b',') {
+        c.fraction()?
+    } else {
+        0
+    };
+    // the fraction of the last component, in nanoseconds
+    let extra = [3_600, 60, 1][parts.len() - 1] * i64::from(fraction);
+    parts.resize(3, 0);
+    let time = parse::time(&c, parts[0], parts[1], parts[2], 0)?;
+    let offset = if c.eat(b'Z') {
+        None
+    } else {
+        let negative = c.sign()?;
+        Some(parse::offset(&mut c, negative, false)?)
+    };
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    let local = date.and_time(time) + Duration::nanoseconds(extra);
+    Ok(parse::zoned(local, offset))
+}
@@ -1,5 +1,72 @@
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub mod asn1;
+pub mod binary;
+mod business;
+pub mod calendar;
+mod capabilities;
+mod convert;
+mod custom_parse;
+mod daytime;
+pub mod deadline;
+mod diff;
+pub mod duration;
+mod epoch;
+mod error;
+mod format;
+mod get;
+pub mod git;
+pub mod html;
+mod infer;
+mod interval;
+pub mod js;
+mod json;
+pub mod ldap;
+pub mod locale;
+mod manipulate;
+pub mod media;
+pub mod normalize;
+mod object;
+pub mod oncall;
+pub mod parity;
+mod parse;
+mod period;
+pub mod quiet;
+pub mod recurrence;
+mod relative_time;
+pub mod rss;
+pub mod series;
+pub mod sla;
+pub mod sql;
+mod stamp;
+mod timezone;
+mod warning;
+
+pub use business::Weekend;
+pub use calendar::CalendarFormats;
+pub use capabilities::{capabilities, Capabilities};
+pub use convert::{convert_format, from_format_go, from_format_java, FormatSyntax};
+pub use custom_parse::{from_format_dayjs, from_format_dayjs_strict};
+pub use daytime::{fanout_local_time, DayjsTime};
+pub use epoch::{from_epoch, from_score, Epoch, Precision, MAX_EXACT_SCORE};
+pub use error::DayjsError;
+pub use format::{explain_format, DateStyle, FormatGuard, StrftimeMode, TimeStyle, TokenInfo};
+pub use infer::{infer_format, parse_date_time, DateOrder, FormatGuess, ParserProfile};
+pub use interval::Interval;
+pub use locale::Locale;
+pub use object::{from_object, DayjsObject, ZoneKind};
+pub use parse::{parse_iso8601, parse_rfc3339_strict, validate_iso, IsoKind};
+pub use period::Period;
+pub use stamp::{from_filename_stamp, StampStyle};
+pub use timezone::{
+    country_for_timezone, timezones_for_country, DayjsTimeZone, NameStyle, OffsetStyle,
+};
+#[cfg(feature = "tz")]
+pub use timezone::{tz_catalog, tz_catalog_at, CatalogRegion, CatalogZone};
+pub use warning::{clear_warning_handler, set_warning_handler, Warning};
 
 /// get dayjs instance
 ///
@@ -13,6 +80,21 @@ pub fn dayjs() -> Dayjs {
     Dayjs::default()
 }
 
+/// get dayjs instance of now in UTC mode, see [`Dayjs::local`] for the system timezone
+///
+/// # Examples
+///
+/// ```
+/// assert!(dayjs::utc().is_utc());
+/// assert_eq!(dayjs::utc().local().tz, dayjs::DayjsTimeZone::Local);
+/// ```
+pub fn utc() -> Dayjs {
+    Dayjs {
+        tz: DayjsTimeZone::DEFAULT,
+        time: Utc::now(),
+    }
+}
+
 /// get dayjs instance from str
 pub fn from_str(s: &str) -> Dayjs {
     let time: chrono::DateTime<Utc> = chrono::DateTime::parse_from_rfc3339(s)
@@ -24,6 +106,19 @@ pub fn from_str(s: &str) -> Dayjs {
     }
 }
 
+/// get dayjs instance from an RFC 3339 str, an error instead of a panic for anything else
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_str_strict("2024-01-05T08:30:00Z").unwrap();
+/// assert_eq!(date, dayjs::from_str("2024-01-05T08:30:00Z"));
+/// assert!(dayjs::from_str_strict("2024-01-05 08:30:00Z").is_err());
+/// ```
+pub fn from_str_strict(s: &str) -> Result<Dayjs, DayjsError> {
+    parse_rfc3339_strict(s)
+}
+
 /// get dayjs instance from number
 pub fn from_timestamp(n: i64) -> Dayjs {
     let len = format!("{}", n).len();
@@ -38,17 +133,129 @@ pub fn from_timestamp(n: i64) -> Dayjs {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum DayjsTimeZone {
-    DEFAULT,
+/// the earliest of `dates`, like the dayjs minMax plugin, `None` when there are none
+///
+/// instances are taken by value, they are `Copy`; equal instants are ordered by tz, see
+/// [`Dayjs`]
+///
+/// # Examples
+///
+/// ```
+/// let dates = [1704443400, 1704067200, 1706745600].map(dayjs::from_timestamp);
+/// assert_eq!(dayjs::min(dates), Some(dates[1]));
+/// assert_eq!(dayjs::max(dates.iter().copied()), Some(dates[2]));
+/// assert_eq!(dayjs::min([]), None);
+/// ```
+pub fn min(dates: impl IntoIterator<Item = Dayjs>) -> Option<Dayjs> {
+    dates.into_iter().min()
+}
+
+/// the latest of `dates`, see [`min`]
+pub fn max(dates: impl IntoIterator<Item = Dayjs>) -> Option<Dayjs> {
+    dates.into_iter().max()
+}
+
+/// set the active locale by name, used by `format` and relative time, returns `None` and keeps
+/// the active locale when the name is unknown
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_timestamp(1704443400);
+/// assert_eq!(dayjs::locale("zh-cn").map(|l| l.name), Some("zh-cn"));
+/// assert_eq!(date.format("YYYY年M月D日 dddd"), "2024年1月5日 星期五");
+/// dayjs::locale("en");
+/// assert_eq!(date.format("MMMM Do"), "January 5th");
+/// ```
+pub fn locale(name: &str) -> Option<&'static Locale> {
+    let locale = Locale::find(name)?;
+    Locale::activate(locale);
+    Some(locale)
+}
+
+/// ordinal number in the active locale, the text of the `Do` token, see [`Locale::ordinal`]
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(dayjs::ordinal(1), "1st");
+/// assert_eq!(dayjs::ordinal(21), "21st");
+/// assert_eq!(dayjs::ordinal(12), "12th");
+/// ```
+pub fn ordinal(n: u64) -> String {
+    Locale::current().ordinal(n)
+}
+
+/// time unit
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    /// week starting on monday, the same length as [`Unit::Week`] when adding
+    Isoweek,
+    Month,
+    /// three months, starting in january, april, july and october
+    Quarter,
+    Year,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+impl FromStr for Unit {
+    type Err = DayjsError;
+
+    /// parse a dayjs unit name: `"day"`, `"days"`, `"d"`, `"month"`, `"M"`, `"isoWeek"`, ...
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = match s {
+            "ms" => Unit::Millisecond,
+            "s" => Unit::Second,
+            "m" => Unit::Minute,
+            "h" => Unit::Hour,
+            "d" | "D" => Unit::Day,
+            "w" => Unit::Week,
+            "M" => Unit::Month,
+            "Q" => Unit::Quarter,
+            "y" => Unit::Year,
+            _ => match s.to_lowercase().trim_end_matches('s') {
+                "millisecond" => Unit::Millisecond,
+                "second" => Unit::Second,
+                "minute" => Unit::Minute,
+                "hour" => Unit::Hour,
+                "day" | "date" => Unit::Day,
+                "week" => Unit::Week,
+                "isoweek" => Unit::Isoweek,
+                "month" => Unit::Month,
+                "quarter" => Unit::Quarter,
+                "year" => Unit::Year,
+                _ => return Err(DayjsError::UnknownUnit(s.to_string())),
+            },
+        };
+        Ok(unit)
+    }
+}
+
+/// instances are equal and hash alike when both the instant and the tz match, they are
+/// ordered by instant and then by tz
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Dayjs {
     pub tz: DayjsTimeZone,
     time: chrono::DateTime<Utc>,
 }
 
+impl Dayjs {
+    /// wall clock time in the instance timezone
+    pub(crate) fn datetime(&self) -> DateTime<FixedOffset> {
+        let offset = self.tz.offset_at(&self.time);
+        // within a day of the supported range the wall clock may not exist, read it in UTC
+        match self.time.naive_utc().checked_add_offset(offset) {
+            Some(_) => self.time.with_timezone(&offset),
+            None => self.time.fixed_offset(),
+        }
+    }
+}
+
 impl Default for Dayjs {
     fn default() -> Self {
         Dayjs {
@@ -58,6 +265,20 @@ impl Default for Dayjs {
     }
 }
 
+impl PartialOrd for Dayjs {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Dayjs {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time
+            .cmp(&other.time)
+            .then_with(|| self.tz.sort_key().cmp(&other.tz.sort_key()))
+    }
+}
+
 impl Display for Dayjs {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.time.to_rfc3339())
@@ -0,0 +1,247 @@
+mod generated;
+
+use chrono::Weekday;
+pub use generated::*;
+use std::sync::RwLock;
+
+/// locales added with [`Locale::register`]
+static REGISTERED: RwLock<Vec<&'static Locale>> = RwLock::new(Vec::new());
+
+/// locale used by [`crate::Dayjs::format`] and relative time
+static ACTIVE: RwLock<&'static Locale> = RwLock::new(&EN);
+
+/// CLDR plural category
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// phrases keyed by plural category, `%d` is replaced by the number
+pub type PluralForms = &'static [(PluralCategory, &'static str)];
+
+/// unit phrases of relative time, `%d` is replaced by the number
+#[derive(Debug)]
+pub struct RelativeUnits {
+    pub second: &'static str,
+    pub minute: &'static str,
+    pub minutes: PluralForms,
+    pub hour: &'static str,
+    pub hours: PluralForms,
+    pub day: &'static str,
+    pub days: PluralForms,
+    pub month: &'static str,
+    pub months: PluralForms,
+    pub year: &'static str,
+    pub years: PluralForms,
+}
+
+/// relative time phrases, `%s` in `future`/`past` is replaced by the unit phrase
+#[derive(Debug)]
+pub struct RelativeTime {
+    pub future: &'static str,
+    pub past: &'static str,
+    /// units on their own, e.g. "минута"
+    pub units: RelativeUnits,
+    /// units inside `future`, declined where the language needs it, e.g. "через минуту"
+    pub future_units: RelativeUnits,
+    /// units inside `past`, e.g. "минуту назад"
+    pub past_units: RelativeUnits,
+}
+
+/// locale data used by name based format tokens
+#[derive(Debug)]
+pub struct Locale {
+    pub name: &'static str,
+    /// month names used next to a day number, e.g. "5 января"
+    pub months_format: [&'static str; 12],
+    /// month names used on their own, e.g. "январь 2024"
+    pub months_standalone: [&'static str; 12],
+    /// whether a month name before a day number takes the format form too, "sausio 5" in
+    /// Lithuanian
+    pub months_format_before_day: bool,
+    pub months_short: [&'static str; 12],
+    /// weekday names, starting from sunday
+    pub weekdays: [&'static str; 7],
+    pub weekdays_short: [&'static str; 7],
+    /// first day of the week, the dayjs `weekStart`
+    pub week_start: Weekday,
+    /// day of january that is always in the first week of a year, the dayjs `yearStart`; 1 when
+    /// the week holding january 1st is the first one, 4 for ISO like weeks
+    pub year_start: u32,
+    /// "last", "this" and "next" weekday phrases, starting from sunday
+    pub weekdays_relative: [[&'static str; 3]; 7],
+    /// AM and PM
+    pub meridiem: [&'static str; 2],
+    /// full, long, medium and short date templates
+    pub date_formats: [&'static str; 4],
+    /// full, long, medium and short time templates
+    pub time_formats: [&'static str; 4],
+    /// templates joining a date `{1}` and a time `{0}`, by date style
+    pub datetime_formats: [&'static str; 4],
+    /// localized GMT format, `{0}` is the offset, e.g. "GMT{0}"
+    pub gmt_format: &'static str,
+    pub gmt_zero_format: &'static str,
+    /// long and short name of UTC
+    pub utc_names: [&'static str; 2],
+    /// metazone names, sorted by metazone: long standard, long daylight, short standard and
+    /// short daylight, empty when the locale data has none
+    pub zone_names: &'static [(&'static str, [&'static str; 4])],
+    /// day, hour, minute and second phrases, e.g. "3 hours"
+    pub units_long: [PluralForms; 4],
+    /// e.g. "3 hr"
+    pub units_short: [PluralForms; 4],
+    /// e.g. "3h"
+    pub units_narrow: [PluralForms; 4],
+    /// CLDR cardinal plural rule for integers
+    pub plural: fn(u64) -> PluralCategory,
+    /// CLDR ordinal plural rule
+    pub ordinal_plural: fn(u64) -> PluralCategory,
+    /// ordinal by ordinal category, e.g. "%dnd" for two
+    pub ordinals: PluralForms,
+    /// dayjs templates of the `LT`, `LTS`, `L`, `LL`, `LLL` and `LLLL` tokens
+    pub localized_formats: [&'static str; 6],
+    /// [`crate::Dayjs::calendar`] templates for the same day, the next day, the next week, the
+    /// last day, the last week and other days
+    pub calendar: [&'static str; 6],
+    pub relative_time: RelativeTime,
+}
+
+impl PartialEq for Locale {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Locale {
+    /// get month name, `month0` is 0 based
+    pub fn month_name(&self, month0: usize, after_day: bool) -> &'static str {
+        if after_day {
+            self.months_format[month0]
+        } else {
+            self.months_standalone[month0]
+        }
+    }
+
+    /// find a registered or generated locale by tag, case insensitive, `_` is treated as `-`
+    pub fn find(tag: &str) -> Option<&'static Locale> {
+        let tag = tag.replace('_', "-");
+        let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+        registered
+            .iter()
+            .rev()
+            .chain(LOCALES.iter())
+            .copied()
+            .find(|locale| locale.name.eq_ignore_ascii_case(&tag))
+    }
+
+    /// first registered or generated locale of a primary language subtag, "zh" finds "zh-cn"
+    fn find_language(language: &str) -> Option<&'static Locale> {
+        let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+        registered
+            .iter()
+            .rev()
+            .chain(LOCALES.iter())
+            .copied()
+            .find(|locale| {
+                let primary = locale.name.split('-').next().unwrap_or(locale.name);
+                primary.eq_ignore_ascii_case(language)
+            })
+    }
+
+    /// make a locale available to [`Locale::find`] and [`crate::locale`], it takes precedence
+    /// over a generated locale with the same name
+    pub fn register(locale: &'static Locale) {
+        let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+        registered.retain(|l| l.name != locale.name);
+        registered.push(locale);
+    }
+
+    /// the active locale, [`EN`] until [`crate::locale`] changes it
+    pub fn current() -> &'static Locale {
+        *ACTIVE.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// make a locale the active one
+    pub(crate) fn activate(locale: &'static Locale) {
+        *ACTIVE.write().unwrap_or_else(|e| e.into_inner()) = locale;
+    }
+
+    /// pick a locale for an `Accept-Language` header, falls back to [`EN`]
+    ///
+    /// tags are tried by quality value, each one falling back to its parents (zh-TW -> zh), then
+    /// to another locale of the same language (zh -> zh-cn)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let locale = dayjs::Locale::negotiate("fr-CH, ru-RU;q=0.8, en;q=0.5");
+    /// assert_eq!(locale.name, "ru");
+    /// ```
+    pub fn negotiate(accept_language: &str) -> &'static Locale {
+        let mut ranges: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = parts.next()?.trim();
+                let q = parts
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (!tag.is_empty() && tag != "*" && q > 0.0).then_some((tag, q))
+            })
+            .collect();
+        // stable, so equal quality keeps header order
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+        for (tag, _) in ranges {
+            let tag = tag.replace('_', "-");
+            let mut tag = tag.as_str();
+            loop {
+                if let Some(locale) = Locale::find(tag) {
+                    return locale;
+                }
+                match tag.rfind('-') {
+                    Some(i) => tag = &tag[..i],
+                    None => break,
+                }
+            }
+            if let Some(locale) = Locale::find_language(tag) {
+                return locale;
+            }
+        }
+        &EN
+    }
+
+    /// get the plural form of a unit phrase for `n`, e.g. "5 часов"
+    pub fn pluralize(&self, forms: PluralForms, n: u64) -> String {
+        select(forms, (self.plural)(n), n)
+    }
+
+    /// ordinal number, e.g. "22nd"
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::locale::{EN, PL};
+    /// assert_eq!(EN.ordinal(22), "22nd");
+    /// assert_eq!(EN.ordinal(13), "13th");
+    /// assert_eq!(PL.ordinal(5), "5.");
+    /// ```
+    pub fn ordinal(&self, n: u64) -> String {
+        select(self.ordinals, (self.ordinal_plural)(n), n)
+    }
+}
+
+/// the form for `category`, falling back to other
+fn select(forms: PluralForms, category: PluralCategory, n: u64) -> String {
+    forms
+        .iter()
+        .find(|(c, _)| *c == category)
+        .or_else(|| forms.iter().find(|(c, _)| *c == PluralCategory::Other))
+        .map(|(_, form)| form.replace("%d", &n.to_string()))
+        .unwrap_or_else(|| n.to_string())
+}
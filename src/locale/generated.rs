@@ -0,0 +1,4309 @@
+// generated by tools/cldr-import.js from tools/locale-data, do not edit by hand
+use super::{Locale, PluralCategory, RelativeTime, RelativeUnits};
+use chrono::Weekday;
+use PluralCategory::{Few, Many, One, Other, Two};
+
+/// all generated locales
+pub static LOCALES: [&Locale; 6] = [&EN, &LT, &PL, &RU, &UK, &ZH_CN];
+
+/// zone to metazone of the locale data, sorted by zone
+#[cfg(feature = "tz")]
+pub(crate) static METAZONES: [(&str, &str); 418] = [
+    ("Africa/Abidjan", "Greenwich_Mean"),
+    ("Africa/Accra", "Greenwich_Mean"),
+    ("Africa/Addis_Ababa", "East_Africa"),
+    ("Africa/Algiers", "Central_European_Standard"),
+    ("Africa/Asmera", "East_Africa"),
+    ("Africa/Bamako", "Greenwich_Mean"),
+    ("Africa/Bangui", "West_Africa"),
+    ("Africa/Banjul", "Greenwich_Mean"),
+    ("Africa/Bissau", "Greenwich_Mean"),
+    ("Africa/Blantyre", "Central_Africa"),
+    ("Africa/Brazzaville", "West_Africa"),
+    ("Africa/Bujumbura", "Central_Africa"),
+    ("Africa/Cairo", "Eastern_European"),
+    ("Africa/Casablanca", "Morocco"),
+    ("Africa/Ceuta", "Central_European"),
+    ("Africa/Conakry", "Greenwich_Mean"),
+    ("Africa/Dakar", "Greenwich_Mean"),
+    ("Africa/Dar_es_Salaam", "East_Africa"),
+    ("Africa/Djibouti", "East_Africa"),
+    ("Africa/Douala", "West_Africa"),
+    ("Africa/El_Aaiun", "Western_Sahara"),
+    ("Africa/Freetown", "Greenwich_Mean"),
+    ("Africa/Gaborone", "Central_Africa"),
+    ("Africa/Harare", "Central_Africa"),
+    ("Africa/Johannesburg", "South_Africa_Standard"),
+    ("Africa/Juba", "Central_Africa"),
+    ("Africa/Kampala", "East_Africa"),
+    ("Africa/Khartoum", "Central_Africa"),
+    ("Africa/Kigali", "Central_Africa"),
+    ("Africa/Kinshasa", "West_Africa"),
+    ("Africa/Lagos", "West_Africa"),
+    ("Africa/Libreville", "West_Africa"),
+    ("Africa/Lome", "Greenwich_Mean"),
+    ("Africa/Luanda", "West_Africa"),
+    ("Africa/Lubumbashi", "Central_Africa"),
+    ("Africa/Lusaka", "Central_Africa"),
+    ("Africa/Malabo", "West_Africa"),
+    ("Africa/Maputo", "Central_Africa"),
+    ("Africa/Maseru", "South_Africa_Standard"),
+    ("Africa/Mbabane", "South_Africa_Standard"),
+    ("Africa/Mogadishu", "East_Africa"),
+    ("Africa/Monrovia", "Greenwich_Mean"),
+    ("Africa/Nairobi", "East_Africa"),
+    ("Africa/Ndjamena", "West_Africa"),
+    ("Africa/Niamey", "West_Africa"),
+    ("Africa/Nouakchott", "Greenwich_Mean"),
+    ("Africa/Ouagadougou", "Greenwich_Mean"),
+    ("Africa/Porto-Novo", "West_Africa"),
+    ("Africa/Sao_Tome", "Greenwich_Mean"),
+    ("Africa/Tripoli", "Eastern_European_Standard"),
+    ("Africa/Tunis", "Central_European_Standard"),
+    ("Africa/Windhoek", "Central_Africa"),
+    ("America/Adak", "Hawaii_Aleutian"),
+    ("America/Anchorage", "Alaska"),
+    ("America/Anguilla", "Atlantic_Standard"),
+    ("America/Antigua", "Atlantic_Standard"),
+    ("America/Araguaina", "Brasilia_Standard"),
+    ("America/Argentina/La_Rioja", "Argentina_Standard"),
+    ("America/Argentina/Rio_Gallegos", "Argentina_Standard"),
+    ("America/Argentina/Salta", "Argentina_Standard"),
+    ("America/Argentina/San_Juan", "Argentina_Standard"),
+    ("America/Argentina/San_Luis", "Argentina_Standard"),
+    ("America/Argentina/Tucuman", "Argentina_Standard"),
+    ("America/Argentina/Ushuaia", "Argentina_Standard"),
+    ("America/Aruba", "Atlantic_Standard"),
+    ("America/Asuncion", "Paraguay"),
+    ("America/Bahia", "Brasilia_Standard"),
+    ("America/Bahia_Banderas", "Central_Standard"),
+    ("America/Barbados", "Atlantic_Standard"),
+    ("America/Belem", "Brasilia_Standard"),
+    ("America/Belize", "Central_Standard"),
+    ("America/Blanc-Sablon", "Atlantic_Standard"),
+    ("America/Boa_Vista", "Amazon_Standard"),
+    ("America/Bogota", "Colombia_Standard"),
+    ("America/Boise", "Mountain"),
+    ("America/Buenos_Aires", "Argentina_Standard"),
+    ("America/Cambridge_Bay", "Mountain"),
+    ("America/Campo_Grande", "Amazon_Standard"),
+    ("America/Cancun", "Eastern_Standard"),
+    ("America/Caracas", "Venezuela"),
+    ("America/Catamarca", "Argentina_Standard"),
+    ("America/Cayenne", "French_Guiana"),
+    ("America/Cayman", "Eastern_Standard"),
+    ("America/Chicago", "Central"),
+    ("America/Chihuahua", "Central_Standard"),
+    ("America/Ciudad_Juarez", "Mountain"),
+    ("America/Coral_Harbour", "Eastern_Standard"),
+    ("America/Cordoba", "Argentina_Standard"),
+    ("America/Costa_Rica", "Central_Standard"),
+    ("America/Coyhaique", "Chile"),
+    ("America/Creston", "Mountain_Standard"),
+    ("America/Cuiaba", "Amazon_Standard"),
+    ("America/Curacao", "Atlantic_Standard"),
+    ("America/Danmarkshavn", "Greenwich_Mean"),
+    ("America/Dawson", "Yukon"),
+    ("America/Dawson_Creek", "Mountain_Standard"),
+    ("America/Denver", "Mountain"),
+    ("America/Detroit", "Eastern"),
+    ("America/Dominica", "Atlantic_Standard"),
+    ("America/Edmonton", "Mountain"),
+    ("America/Eirunepe", "Acre_Standard"),
+    ("America/El_Salvador", "Central_Standard"),
+    ("America/Fort_Nelson", "Mountain_Standard"),
+    ("America/Fortaleza", "Brasilia_Standard"),
+    ("America/Glace_Bay", "Atlantic"),
+    ("America/Godthab", "West_Greenland"),
+    ("America/Goose_Bay", "Atlantic"),
+    ("America/Grand_Turk", "Eastern"),
+    ("America/Grenada", "Atlantic_Standard"),
+    ("America/Guadeloupe", "Atlantic_Standard"),
+    ("America/Guatemala", "Central_Standard"),
+    ("America/Guayaquil", "Ecuador"),
+    ("America/Guyana", "Guyana"),
+    ("America/Halifax", "Atlantic"),
+    ("America/Havana", "Cuba"),
+    ("America/Hermosillo", "Mexican_Pacific_Standard"),
+    ("America/Indiana/Knox", "Central"),
+    ("America/Indiana/Marengo", "Eastern"),
+    ("America/Indiana/Petersburg", "Eastern"),
+    ("America/Indiana/Tell_City", "Central"),
+    ("America/Indiana/Vevay", "Eastern"),
+    ("America/Indiana/Vincennes", "Eastern"),
+    ("America/Indiana/Winamac", "Eastern"),
+    ("America/Indianapolis", "Eastern"),
+    ("America/Inuvik", "Mountain"),
+    ("America/Iqaluit", "Eastern"),
+    ("America/Jamaica", "Eastern_Standard"),
+    ("America/Jujuy", "Argentina_Standard"),
+    ("America/Juneau", "Alaska"),
+    ("America/Kentucky/Monticello", "Eastern"),
+    ("America/Kralendijk", "Atlantic_Standard"),
+    ("America/La_Paz", "Bolivia"),
+    ("America/Lima", "Peru_Standard"),
+    ("America/Los_Angeles", "Pacific"),
+    ("America/Louisville", "Eastern"),
+    ("America/Lower_Princes", "Atlantic_Standard"),
+    ("America/Maceio", "Brasilia_Standard"),
+    ("America/Managua", "Central_Standard"),
+    ("America/Manaus", "Amazon_Standard"),
+    ("America/Marigot", "Atlantic_Standard"),
+    ("America/Martinique", "Atlantic_Standard"),
+    ("America/Matamoros", "Central"),
+    ("America/Mazatlan", "Mexican_Pacific_Standard"),
+    ("America/Mendoza", "Argentina_Standard"),
+    ("America/Menominee", "Central"),
+    ("America/Merida", "Central_Standard"),
+    ("America/Metlakatla", "Alaska"),
+    ("America/Mexico_City", "Central_Standard"),
+    ("America/Miquelon", "St_Pierre_Miquelon"),
+    ("America/Moncton", "Atlantic"),
+    ("America/Monterrey", "Central_Standard"),
+    ("America/Montevideo", "Uruguay_Standard"),
+    ("America/Montserrat", "Atlantic_Standard"),
+    ("America/Nassau", "Eastern"),
+    ("America/New_York", "Eastern"),
+    ("America/Nome", "Alaska"),
+    ("America/Noronha", "Fernando_de_Noronha_Standard"),
+    ("America/North_Dakota/Beulah", "Central"),
+    ("America/North_Dakota/Center", "Central"),
+    ("America/North_Dakota/New_Salem", "Central"),
+    ("America/Ojinaga", "Central"),
+    ("America/Panama", "Eastern_Standard"),
+    ("America/Paramaribo", "Suriname"),
+    ("America/Phoenix", "Mountain_Standard"),
+    ("America/Port-au-Prince", "Eastern"),
+    ("America/Port_of_Spain", "Atlantic_Standard"),
+    ("America/Porto_Velho", "Amazon_Standard"),
+    ("America/Puerto_Rico", "Atlantic_Standard"),
+    ("America/Punta_Arenas", "Punta_Arenas"),
+    ("America/Rankin_Inlet", "Central"),
+    ("America/Recife", "Brasilia_Standard"),
+    ("America/Regina", "Central_Standard"),
+    ("America/Resolute", "Central"),
+    ("America/Rio_Branco", "Acre_Standard"),
+    ("America/Santarem", "Brasilia_Standard"),
+    ("America/Santiago", "Chile"),
+    ("America/Santo_Domingo", "Atlantic_Standard"),
+    ("America/Sao_Paulo", "Brasilia_Standard"),
+    ("America/Scoresbysund", "East_Greenland"),
+    ("America/Sitka", "Alaska"),
+    ("America/St_Barthelemy", "Atlantic_Standard"),
+    ("America/St_Johns", "Newfoundland"),
+    ("America/St_Kitts", "Atlantic_Standard"),
+    ("America/St_Lucia", "Atlantic_Standard"),
+    ("America/St_Thomas", "Atlantic_Standard"),
+    ("America/St_Vincent", "Atlantic_Standard"),
+    ("America/Swift_Current", "Central_Standard"),
+    ("America/Tegucigalpa", "Central_Standard"),
+    ("America/Thule", "Atlantic"),
+    ("America/Tijuana", "Pacific"),
+    ("America/Toronto", "Eastern"),
+    ("America/Tortola", "Atlantic_Standard"),
+    ("America/Vancouver", "Pacific"),
+    ("America/Whitehorse", "Yukon"),
+    ("America/Winnipeg", "Central"),
+    ("America/Yakutat", "Alaska"),
+    ("Antarctica/Casey", "Australian_Western_Standard"),
+    ("Antarctica/Davis", "Davis"),
+    ("Antarctica/DumontDUrville", "Dumont_d_Urville"),
+    ("Antarctica/Macquarie", "Australian_Eastern"),
+    ("Antarctica/Mawson", "Mawson"),
+    ("Antarctica/McMurdo", "New_Zealand"),
+    ("Antarctica/Palmer", "Palmer_Land"),
+    ("Antarctica/Rothera", "Rothera"),
+    ("Antarctica/Syowa", "Syowa"),
+    ("Antarctica/Troll", "Troll_Station"),
+    ("Antarctica/Vostok", "Vostok"),
+    ("Arctic/Longyearbyen", "Central_European"),
+    ("Asia/Aden", "Arabian_Standard"),
+    ("Asia/Almaty", "East_Kazakhstan"),
+    ("Asia/Amman", "Jordan"),
+    ("Asia/Anadyr", "Kamchatka_Standard"),
+    ("Asia/Aqtau", "West_Kazakhstan"),
+    ("Asia/Aqtobe", "West_Kazakhstan"),
+    ("Asia/Ashgabat", "Turkmenistan_Standard"),
+    ("Asia/Atyrau", "West_Kazakhstan"),
+    ("Asia/Baghdad", "Arabian_Standard"),
+    ("Asia/Bahrain", "Arabian_Standard"),
+    ("Asia/Baku", "Azerbaijan_Standard"),
+    ("Asia/Bangkok", "Indochina"),
+    ("Asia/Barnaul", "Krasnoyarsk_Standard"),
+    ("Asia/Beirut", "Eastern_European"),
+    ("Asia/Bishkek", "Kyrgyzstan"),
+    ("Asia/Brunei", "Brunei"),
+    ("Asia/Calcutta", "India_Standard"),
+    ("Asia/Chita", "Yakutsk_Standard"),
+    ("Asia/Colombo", "India_Standard"),
+    ("Asia/Damascus", "Syria"),
+    ("Asia/Dhaka", "Bangladesh_Standard"),
+    ("Asia/Dili", "Timor_Leste"),
+    ("Asia/Dubai", "Gulf_Standard"),
+    ("Asia/Dushanbe", "Tajikistan"),
+    ("Asia/Famagusta", "Eastern_European"),
+    ("Asia/Gaza", "Eastern_European"),
+    ("Asia/Hebron", "Eastern_European"),
+    ("Asia/Hong_Kong", "Hong_Kong_Standard"),
+    ("Asia/Hovd", "Khovd_Standard"),
+    ("Asia/Irkutsk", "Irkutsk_Standard"),
+    ("Asia/Jakarta", "Western_Indonesia"),
+    ("Asia/Jayapura", "Eastern_Indonesia"),
+    ("Asia/Jerusalem", "Israel"),
+    ("Asia/Kabul", "Afghanistan"),
+    ("Asia/Kamchatka", "Kamchatka_Standard"),
+    ("Asia/Karachi", "Pakistan_Standard"),
+    ("Asia/Katmandu", "Nepal"),
+    ("Asia/Khandyga", "Yakutsk_Standard"),
+    ("Asia/Krasnoyarsk", "Krasnoyarsk_Standard"),
+    ("Asia/Kuala_Lumpur", "Malaysia"),
+    ("Asia/Kuching", "Malaysia"),
+    ("Asia/Kuwait", "Arabian_Standard"),
+    ("Asia/Macau", "China_Standard"),
+    ("Asia/Magadan", "Magadan_Standard"),
+    ("Asia/Makassar", "Central_Indonesia"),
+    ("Asia/Manila", "Philippine_Standard"),
+    ("Asia/Muscat", "Gulf_Standard"),
+    ("Asia/Nicosia", "Eastern_European"),
+    ("Asia/Novokuznetsk", "Krasnoyarsk_Standard"),
+    ("Asia/Novosibirsk", "Krasnoyarsk_Standard"),
+    ("Asia/Omsk", "Omsk_Standard"),
+    ("Asia/Oral", "West_Kazakhstan"),
+    ("Asia/Phnom_Penh", "Indochina"),
+    ("Asia/Pontianak", "Western_Indonesia"),
+    ("Asia/Pyongyang", "Korean_Standard"),
+    ("Asia/Qatar", "Arabian_Standard"),
+    ("Asia/Qostanay", "East_Kazakhstan"),
+    ("Asia/Qyzylorda", "West_Kazakhstan"),
+    ("Asia/Rangoon", "Myanmar"),
+    ("Asia/Riyadh", "Arabian_Standard"),
+    ("Asia/Saigon", "Indochina"),
+    ("Asia/Sakhalin", "Magadan_Standard"),
+    ("Asia/Samarkand", "Uzbekistan_Standard"),
+    ("Asia/Seoul", "Korean_Standard"),
+    ("Asia/Shanghai", "China_Standard"),
+    ("Asia/Singapore", "Singapore_Standard"),
+    ("Asia/Srednekolymsk", "Magadan_Standard"),
+    ("Asia/Taipei", "Taiwan_Standard"),
+    ("Asia/Tashkent", "Uzbekistan_Standard"),
+    ("Asia/Tbilisi", "Georgia_Standard"),
+    ("Asia/Tehran", "Iran_Standard"),
+    ("Asia/Thimphu", "Bhutan"),
+    ("Asia/Tokyo", "Japan_Standard"),
+    ("Asia/Tomsk", "Krasnoyarsk_Standard"),
+    ("Asia/Ulaanbaatar", "Ulaanbaatar_Standard"),
+    ("Asia/Urumqi", "_r_mqi"),
+    ("Asia/Ust-Nera", "Vladivostok_Standard"),
+    ("Asia/Vientiane", "Indochina"),
+    ("Asia/Vladivostok", "Vladivostok_Standard"),
+    ("Asia/Yakutsk", "Yakutsk_Standard"),
+    ("Asia/Yekaterinburg", "Yekaterinburg_Standard"),
+    ("Asia/Yerevan", "Armenia_Standard"),
+    ("Atlantic/Azores", "Azores"),
+    ("Atlantic/Bermuda", "Atlantic"),
+    ("Atlantic/Canary", "Western_European"),
+    ("Atlantic/Cape_Verde", "Cape_Verde_Standard"),
+    ("Atlantic/Faeroe", "Western_European"),
+    ("Atlantic/Madeira", "Western_European"),
+    ("Atlantic/Reykjavik", "Greenwich_Mean"),
+    ("Atlantic/South_Georgia", "South_Georgia"),
+    ("Atlantic/St_Helena", "Greenwich_Mean"),
+    ("Atlantic/Stanley", "Falkland_Islands_Standard"),
+    ("Australia/Adelaide", "Australian_Central"),
+    ("Australia/Brisbane", "Australian_Eastern_Standard"),
+    ("Australia/Broken_Hill", "Australian_Central"),
+    ("Australia/Darwin", "Australian_Central_Standard"),
+    ("Australia/Eucla", "Australian_Central_Western_Standard"),
+    ("Australia/Hobart", "Australian_Eastern"),
+    ("Australia/Lindeman", "Australian_Eastern_Standard"),
+    ("Australia/Lord_Howe", "Lord_Howe"),
+    ("Australia/Melbourne", "Australian_Eastern"),
+    ("Australia/Perth", "Australian_Western_Standard"),
+    ("Australia/Sydney", "Australian_Eastern"),
+    ("Europe/Amsterdam", "Central_European"),
+    ("Europe/Andorra", "Central_European"),
+    ("Europe/Astrakhan", "Samara_Standard"),
+    ("Europe/Athens", "Eastern_European"),
+    ("Europe/Belgrade", "Central_European"),
+    ("Europe/Berlin", "Central_European"),
+    ("Europe/Bratislava", "Central_European"),
+    ("Europe/Brussels", "Central_European"),
+    ("Europe/Bucharest", "Eastern_European"),
+    ("Europe/Budapest", "Central_European"),
+    ("Europe/Busingen", "Central_European"),
+    ("Europe/Chisinau", "Eastern_European"),
+    ("Europe/Copenhagen", "Central_European"),
+    ("Europe/Dublin", "Ireland"),
+    ("Europe/Gibraltar", "Central_European"),
+    ("Europe/Guernsey", "Guernsey"),
+    ("Europe/Helsinki", "Eastern_European"),
+    ("Europe/Isle_of_Man", "Isle_of_Man"),
+    ("Europe/Istanbul", "T_rkiye_Standard"),
+    ("Europe/Jersey", "Jersey"),
+    ("Europe/Kaliningrad", "Eastern_European_Standard"),
+    ("Europe/Kiev", "Eastern_European"),
+    ("Europe/Kirov", "Moscow_Standard"),
+    ("Europe/Lisbon", "Western_European"),
+    ("Europe/Ljubljana", "Central_European"),
+    ("Europe/London", "United_Kingdom"),
+    ("Europe/Luxembourg", "Central_European"),
+    ("Europe/Madrid", "Central_European"),
+    ("Europe/Malta", "Central_European"),
+    ("Europe/Mariehamn", "Eastern_European"),
+    ("Europe/Minsk", "Moscow_Standard"),
+    ("Europe/Monaco", "Central_European"),
+    ("Europe/Moscow", "Moscow_Standard"),
+    ("Europe/Oslo", "Central_European"),
+    ("Europe/Paris", "Central_European"),
+    ("Europe/Podgorica", "Central_European"),
+    ("Europe/Prague", "Central_European"),
+    ("Europe/Riga", "Eastern_European"),
+    ("Europe/Rome", "Central_European"),
+    ("Europe/Samara", "Samara_Standard"),
+    ("Europe/San_Marino", "Central_European"),
+    ("Europe/Sarajevo", "Central_European"),
+    ("Europe/Saratov", "Samara_Standard"),
+    ("Europe/Simferopol", "Moscow_Standard"),
+    ("Europe/Skopje", "Central_European"),
+    ("Europe/Sofia", "Eastern_European"),
+    ("Europe/Stockholm", "Central_European"),
+    ("Europe/Tallinn", "Eastern_European"),
+    ("Europe/Tirane", "Central_European"),
+    ("Europe/Ulyanovsk", "Samara_Standard"),
+    ("Europe/Vaduz", "Central_European"),
+    ("Europe/Vatican", "Central_European"),
+    ("Europe/Vienna", "Central_European"),
+    ("Europe/Vilnius", "Eastern_European"),
+    ("Europe/Volgograd", "Moscow_Standard"),
+    ("Europe/Warsaw", "Central_European"),
+    ("Europe/Zagreb", "Central_European"),
+    ("Europe/Zurich", "Central_European"),
+    ("Indian/Antananarivo", "East_Africa"),
+    ("Indian/Chagos", "Indian_Ocean"),
+    ("Indian/Christmas", "Christmas_Island"),
+    ("Indian/Cocos", "Cocos_Islands"),
+    ("Indian/Comoro", "East_Africa"),
+    ("Indian/Kerguelen", "French_Southern_Antarctic"),
+    ("Indian/Mahe", "Seychelles"),
+    ("Indian/Maldives", "Maldives"),
+    ("Indian/Mauritius", "Mauritius_Standard"),
+    ("Indian/Mayotte", "East_Africa"),
+    ("Indian/Reunion", "R_union"),
+    ("Pacific/Apia", "Samoa_Standard"),
+    ("Pacific/Auckland", "New_Zealand"),
+    ("Pacific/Bougainville", "Bougainville"),
+    ("Pacific/Chatham", "Chatham"),
+    ("Pacific/Easter", "Easter_Island"),
+    ("Pacific/Efate", "Vanuatu_Standard"),
+    ("Pacific/Enderbury", "Phoenix_Islands"),
+    ("Pacific/Fakaofo", "Tokelau"),
+    ("Pacific/Fiji", "Fiji_Standard"),
+    ("Pacific/Funafuti", "Tuvalu"),
+    ("Pacific/Galapagos", "Galapagos"),
+    ("Pacific/Gambier", "Gambier"),
+    ("Pacific/Guadalcanal", "Solomon_Islands"),
+    ("Pacific/Guam", "Chamorro_Standard"),
+    ("Pacific/Honolulu", "Hawaii_Aleutian_Standard"),
+    ("Pacific/Kiritimati", "Line_Islands"),
+    ("Pacific/Kosrae", "Kosrae"),
+    ("Pacific/Kwajalein", "Marshall_Islands"),
+    ("Pacific/Majuro", "Marshall_Islands"),
+    ("Pacific/Marquesas", "Marquesas"),
+    ("Pacific/Midway", "American_Samoa_Standard"),
+    ("Pacific/Nauru", "Nauru"),
+    ("Pacific/Niue", "Niue"),
+    ("Pacific/Norfolk", "Norfolk_Island"),
+    ("Pacific/Noumea", "New_Caledonia_Standard"),
+    ("Pacific/Pago_Pago", "American_Samoa_Standard"),
+    ("Pacific/Palau", "Palau"),
+    ("Pacific/Pitcairn", "Pitcairn"),
+    ("Pacific/Ponape", "Pohnpei"),
+    ("Pacific/Port_Moresby", "Papua_New_Guinea"),
+    ("Pacific/Rarotonga", "Cook_Islands_Standard"),
+    ("Pacific/Saipan", "Chamorro_Standard"),
+    ("Pacific/Tahiti", "Tahiti"),
+    ("Pacific/Tarawa", "Gilbert_Islands"),
+    ("Pacific/Tongatapu", "Tonga_Standard"),
+    ("Pacific/Truk", "Chuuk"),
+    ("Pacific/Wake", "Wake_Island"),
+    ("Pacific/Wallis", "Wallis_Futuna"),
+];
+
+/// other names of a zone to its id in the locale data, sorted by name
+pub(crate) static ZONE_ALIASES: [(&str, &str); 31] = [
+    ("Africa/Asmara", "Africa/Asmera"),
+    ("America/Argentina/Buenos_Aires", "America/Buenos_Aires"),
+    ("America/Argentina/Catamarca", "America/Catamarca"),
+    ("America/Argentina/ComodRivadavia", "America/Catamarca"),
+    ("America/Argentina/Cordoba", "America/Cordoba"),
+    ("America/Argentina/Jujuy", "America/Jujuy"),
+    ("America/Argentina/Mendoza", "America/Mendoza"),
+    ("America/Atikokan", "America/Coral_Harbour"),
+    ("America/Fort_Wayne", "America/Indianapolis"),
+    ("America/Indiana/Indianapolis", "America/Indianapolis"),
+    ("America/Kentucky/Louisville", "America/Louisville"),
+    ("America/Nuuk", "America/Godthab"),
+    ("America/Rosario", "America/Cordoba"),
+    ("Asia/Chongqing", "Asia/Shanghai"),
+    ("Asia/Chungking", "Asia/Shanghai"),
+    ("Asia/Harbin", "Asia/Shanghai"),
+    ("Asia/Ho_Chi_Minh", "Asia/Saigon"),
+    ("Asia/Kathmandu", "Asia/Katmandu"),
+    ("Asia/Kolkata", "Asia/Calcutta"),
+    ("Asia/Yangon", "Asia/Rangoon"),
+    ("Atlantic/Faroe", "Atlantic/Faeroe"),
+    ("Europe/Kyiv", "Europe/Kiev"),
+    ("Europe/Uzhgorod", "Europe/Kiev"),
+    ("Europe/Zaporozhye", "Europe/Kiev"),
+    ("PRC", "Asia/Shanghai"),
+    ("Pacific/Chuuk", "Pacific/Truk"),
+    ("Pacific/Kanton", "Pacific/Enderbury"),
+    ("Pacific/Pohnpei", "Pacific/Ponape"),
+    ("Pacific/Yap", "Pacific/Truk"),
+    ("US/East-Indiana", "America/Indianapolis"),
+    ("US/Eastern", "America/New_York"),
+];
+
+pub static EN: Locale = Locale {
+    name: "en",
+    months_format: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_standalone: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_format_before_day: false,
+    months_short: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekdays: [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+    weekdays_short: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    week_start: Weekday::Sun,
+    year_start: 1,
+    weekdays_relative: [
+        ["last Sunday", "this Sunday", "next Sunday"],
+        ["last Monday", "this Monday", "next Monday"],
+        ["last Tuesday", "this Tuesday", "next Tuesday"],
+        ["last Wednesday", "this Wednesday", "next Wednesday"],
+        ["last Thursday", "this Thursday", "next Thursday"],
+        ["last Friday", "this Friday", "next Friday"],
+        ["last Saturday", "this Saturday", "next Saturday"],
+    ],
+    meridiem: ["AM", "PM"],
+    date_formats: [
+        "dddd, MMMM D, YYYY",
+        "MMMM D, YYYY",
+        "MMM D, YYYY",
+        "M/D/YY",
+    ],
+    time_formats: ["h:mm:ss A zzz", "h:mm:ss A z", "h:mm:ss A", "h:mm A"],
+    datetime_formats: ["{1} [at] {0}", "{1} [at] {0}", "{1}, {0}", "{1}, {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    utc_names: ["Coordinated Universal Time", "UTC"],
+    zone_names: &[
+        ("Acre_Standard", ["Acre Standard Time", "", "", ""]),
+        ("Afghanistan", ["Afghanistan Time", "", "", ""]),
+        (
+            "Alaska",
+            [
+                "Alaska Standard Time",
+                "Alaska Daylight Time",
+                "AKST",
+                "AKDT",
+            ],
+        ),
+        ("Amazon_Standard", ["Amazon Standard Time", "", "", ""]),
+        (
+            "American_Samoa_Standard",
+            ["American Samoa Standard Time", "", "", ""],
+        ),
+        ("Arabian_Standard", ["Arabian Standard Time", "", "", ""]),
+        (
+            "Argentina_Standard",
+            ["Argentina Standard Time", "", "", ""],
+        ),
+        ("Armenia_Standard", ["Armenia Standard Time", "", "", ""]),
+        (
+            "Atlantic",
+            [
+                "Atlantic Standard Time",
+                "Atlantic Daylight Time",
+                "AST",
+                "ADT",
+            ],
+        ),
+        (
+            "Atlantic_Standard",
+            ["Atlantic Standard Time", "", "AST", ""],
+        ),
+        (
+            "Australian_Central",
+            [
+                "Australian Central Standard Time",
+                "Australian Central Daylight Time",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Central_Standard",
+            ["Australian Central Standard Time", "", "", ""],
+        ),
+        (
+            "Australian_Central_Western_Standard",
+            ["Australian Central Western Standard Time", "", "", ""],
+        ),
+        (
+            "Australian_Eastern",
+            [
+                "Australian Eastern Standard Time",
+                "Australian Eastern Daylight Time",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern_Standard",
+            ["Australian Eastern Standard Time", "", "", ""],
+        ),
+        (
+            "Australian_Western_Standard",
+            ["Australian Western Standard Time", "", "", ""],
+        ),
+        (
+            "Azerbaijan_Standard",
+            ["Azerbaijan Standard Time", "", "", ""],
+        ),
+        (
+            "Azores",
+            ["Azores Standard Time", "Azores Summer Time", "", ""],
+        ),
+        (
+            "Bangladesh_Standard",
+            ["Bangladesh Standard Time", "", "", ""],
+        ),
+        ("Bhutan", ["Bhutan Time", "", "", ""]),
+        ("Bolivia", ["Bolivia Time", "", "", ""]),
+        ("Brasilia_Standard", ["Brasilia Standard Time", "", "", ""]),
+        ("Brunei", ["Brunei Time", "", "", ""]),
+        (
+            "Cape_Verde_Standard",
+            ["Cape Verde Standard Time", "", "", ""],
+        ),
+        (
+            "Central",
+            [
+                "Central Standard Time",
+                "Central Daylight Time",
+                "CST",
+                "CDT",
+            ],
+        ),
+        ("Central_Africa", ["Central Africa Time", "", "", ""]),
+        (
+            "Central_European",
+            [
+                "Central European Standard Time",
+                "Central European Summer Time",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Central_European_Standard",
+            ["Central European Standard Time", "", "", ""],
+        ),
+        ("Central_Indonesia", ["Central Indonesia Time", "", "", ""]),
+        ("Central_Standard", ["Central Standard Time", "", "CST", ""]),
+        ("Chamorro_Standard", ["Chamorro Standard Time", "", "", ""]),
+        (
+            "Chatham",
+            ["Chatham Standard Time", "Chatham Daylight Time", "", ""],
+        ),
+        (
+            "Chile",
+            ["Chile Standard Time", "Chile Summer Time", "", ""],
+        ),
+        ("China_Standard", ["China Standard Time", "", "", ""]),
+        ("Christmas_Island", ["Christmas Island Time", "", "", ""]),
+        ("Chuuk", ["Chuuk Time", "", "", ""]),
+        ("Cocos_Islands", ["Cocos Islands Time", "", "", ""]),
+        ("Colombia_Standard", ["Colombia Standard Time", "", "", ""]),
+        (
+            "Cook_Islands_Standard",
+            ["Cook Islands Standard Time", "", "", ""],
+        ),
+        ("Cuba", ["Cuba Standard Time", "Cuba Daylight Time", "", ""]),
+        ("Davis", ["Davis Time", "", "", ""]),
+        ("Dumont_d_Urville", ["Dumont d’Urville Time", "", "", ""]),
+        ("East_Africa", ["East Africa Time", "", "", ""]),
+        (
+            "East_Greenland",
+            ["East Greenland Standard Time", "", "", ""],
+        ),
+        (
+            "East_Kazakhstan",
+            ["Kazakhstan Time", "East Kazakhstan Time", "", ""],
+        ),
+        (
+            "Easter_Island",
+            [
+                "Easter Island Standard Time",
+                "Easter Island Summer Time",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern",
+            [
+                "Eastern Standard Time",
+                "Eastern Daylight Time",
+                "EST",
+                "EDT",
+            ],
+        ),
+        (
+            "Eastern_European",
+            [
+                "Eastern European Standard Time",
+                "Eastern European Summer Time",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European_Standard",
+            ["Eastern European Standard Time", "", "", ""],
+        ),
+        ("Eastern_Indonesia", ["Eastern Indonesia Time", "", "", ""]),
+        ("Eastern_Standard", ["Eastern Standard Time", "", "EST", ""]),
+        ("Ecuador", ["Ecuador Time", "", "", ""]),
+        (
+            "Falkland_Islands_Standard",
+            ["Falkland Islands Standard Time", "", "", ""],
+        ),
+        (
+            "Fernando_de_Noronha_Standard",
+            ["Fernando de Noronha Standard Time", "", "", ""],
+        ),
+        ("Fiji_Standard", ["Fiji Standard Time", "", "", ""]),
+        ("French_Guiana", ["French Guiana Time", "", "", ""]),
+        (
+            "French_Southern_Antarctic",
+            ["French Southern & Antarctic Time", "", "", ""],
+        ),
+        ("Galapagos", ["Galapagos Time", "", "", ""]),
+        ("Gambier", ["Gambier Time", "", "", ""]),
+        ("Georgia_Standard", ["Georgia Standard Time", "", "", ""]),
+        ("Gilbert_Islands", ["Gilbert Islands Time", "", "", ""]),
+        ("Greenwich_Mean", ["Greenwich Mean Time", "", "", ""]),
+        ("Guernsey", ["Greenwich Mean Time", "", "", ""]),
+        ("Gulf_Standard", ["Gulf Standard Time", "", "", ""]),
+        ("Guyana", ["Guyana Time", "", "", ""]),
+        (
+            "Hawaii_Aleutian",
+            [
+                "Hawaii-Aleutian Standard Time",
+                "Hawaii-Aleutian Daylight Time",
+                "HAST",
+                "HADT",
+            ],
+        ),
+        (
+            "Hawaii_Aleutian_Standard",
+            ["Hawaii-Aleutian Standard Time", "", "HST", ""],
+        ),
+        (
+            "Hong_Kong_Standard",
+            ["Hong Kong Standard Time", "", "", ""],
+        ),
+        ("India_Standard", ["India Standard Time", "", "", ""]),
+        ("Indian_Ocean", ["Indian Ocean Time", "", "", ""]),
+        ("Indochina", ["Indochina Time", "", "", ""]),
+        ("Iran_Standard", ["Iran Standard Time", "", "", ""]),
+        (
+            "Ireland",
+            ["Greenwich Mean Time", "Irish Standard Time", "", ""],
+        ),
+        ("Irkutsk_Standard", ["Irkutsk Standard Time", "", "", ""]),
+        ("Isle_of_Man", ["Greenwich Mean Time", "", "", ""]),
+        (
+            "Israel",
+            ["Israel Standard Time", "Israel Daylight Time", "", ""],
+        ),
+        ("Japan_Standard", ["Japan Standard Time", "", "", ""]),
+        ("Jersey", ["Greenwich Mean Time", "", "", ""]),
+        (
+            "Kamchatka_Standard",
+            ["Kamchatka Standard Time", "", "", ""],
+        ),
+        ("Khovd_Standard", ["Khovd Standard Time", "", "", ""]),
+        ("Korean_Standard", ["Korean Standard Time", "", "", ""]),
+        ("Kosrae", ["Kosrae Time", "", "", ""]),
+        (
+            "Krasnoyarsk_Standard",
+            ["Krasnoyarsk Standard Time", "", "", ""],
+        ),
+        ("Kyrgyzstan", ["Kyrgyzstan Time", "", "", ""]),
+        ("Line_Islands", ["Line Islands Time", "", "", ""]),
+        (
+            "Lord_Howe",
+            ["Lord Howe Standard Time", "Lord Howe Daylight Time", "", ""],
+        ),
+        ("Magadan_Standard", ["Magadan Standard Time", "", "", ""]),
+        ("Malaysia", ["Malaysia Time", "", "", ""]),
+        ("Maldives", ["Maldives Time", "", "", ""]),
+        ("Marquesas", ["Marquesas Time", "", "", ""]),
+        ("Marshall_Islands", ["Marshall Islands Time", "", "", ""]),
+        (
+            "Mauritius_Standard",
+            ["Mauritius Standard Time", "", "", ""],
+        ),
+        ("Mawson", ["Mawson Time", "", "", ""]),
+        (
+            "Mexican_Pacific_Standard",
+            ["Mexican Pacific Standard Time", "", "", ""],
+        ),
+        ("Moscow_Standard", ["Moscow Standard Time", "", "", ""]),
+        (
+            "Mountain",
+            [
+                "Mountain Standard Time",
+                "Mountain Daylight Time",
+                "MST",
+                "MDT",
+            ],
+        ),
+        (
+            "Mountain_Standard",
+            ["Mountain Standard Time", "", "MST", ""],
+        ),
+        ("Myanmar", ["Myanmar Time", "", "", ""]),
+        ("Nauru", ["Nauru Time", "", "", ""]),
+        ("Nepal", ["Nepal Time", "", "", ""]),
+        (
+            "New_Caledonia_Standard",
+            ["New Caledonia Standard Time", "", "", ""],
+        ),
+        (
+            "New_Zealand",
+            [
+                "New Zealand Standard Time",
+                "New Zealand Daylight Time",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Newfoundland",
+            [
+                "Newfoundland Standard Time",
+                "Newfoundland Daylight Time",
+                "",
+                "",
+            ],
+        ),
+        ("Niue", ["Niue Time", "", "", ""]),
+        (
+            "Norfolk_Island",
+            [
+                "Norfolk Island Standard Time",
+                "Norfolk Island Daylight Time",
+                "",
+                "",
+            ],
+        ),
+        ("Omsk_Standard", ["Omsk Standard Time", "", "", ""]),
+        (
+            "Pacific",
+            [
+                "Pacific Standard Time",
+                "Pacific Daylight Time",
+                "PST",
+                "PDT",
+            ],
+        ),
+        ("Pakistan_Standard", ["Pakistan Standard Time", "", "", ""]),
+        ("Palau", ["Palau Time", "", "", ""]),
+        ("Papua_New_Guinea", ["Papua New Guinea Time", "", "", ""]),
+        (
+            "Paraguay",
+            ["Paraguay Standard Time", "Paraguay Summer Time", "", ""],
+        ),
+        ("Peru_Standard", ["Peru Standard Time", "", "", ""]),
+        (
+            "Philippine_Standard",
+            ["Philippine Standard Time", "", "", ""],
+        ),
+        ("Phoenix_Islands", ["Phoenix Islands Time", "", "", ""]),
+        ("Pitcairn", ["Pitcairn Time", "", "", ""]),
+        ("Pohnpei", ["Pohnpei Time", "", "", ""]),
+        ("R_union", ["Réunion Time", "", "", ""]),
+        ("Rothera", ["Rothera Time", "", "", ""]),
+        ("Samara_Standard", ["Samara Standard Time", "", "", ""]),
+        ("Samoa_Standard", ["Samoa Standard Time", "", "", ""]),
+        ("Seychelles", ["Seychelles Time", "", "", ""]),
+        (
+            "Singapore_Standard",
+            ["Singapore Standard Time", "", "", ""],
+        ),
+        ("Solomon_Islands", ["Solomon Islands Time", "", "", ""]),
+        (
+            "South_Africa_Standard",
+            ["South Africa Standard Time", "", "", ""],
+        ),
+        ("South_Georgia", ["South Georgia Time", "", "", ""]),
+        (
+            "St_Pierre_Miquelon",
+            [
+                "St. Pierre & Miquelon Standard Time",
+                "St. Pierre & Miquelon Daylight Time",
+                "",
+                "",
+            ],
+        ),
+        ("Suriname", ["Suriname Time", "", "", ""]),
+        ("Syowa", ["Syowa Time", "", "", ""]),
+        ("T_rkiye_Standard", ["Türkiye Standard Time", "", "", ""]),
+        ("Tahiti", ["Tahiti Time", "", "", ""]),
+        ("Taiwan_Standard", ["Taiwan Standard Time", "", "", ""]),
+        ("Tajikistan", ["Tajikistan Time", "", "", ""]),
+        ("Timor_Leste", ["Timor-Leste Time", "", "", ""]),
+        ("Tokelau", ["Tokelau Time", "", "", ""]),
+        ("Tonga_Standard", ["Tonga Standard Time", "", "", ""]),
+        ("Troll_Station", ["Greenwich Mean Time", "", "", ""]),
+        (
+            "Turkmenistan_Standard",
+            ["Turkmenistan Standard Time", "", "", ""],
+        ),
+        ("Tuvalu", ["Tuvalu Time", "", "", ""]),
+        (
+            "Ulaanbaatar_Standard",
+            ["Ulaanbaatar Standard Time", "", "", ""],
+        ),
+        (
+            "United_Kingdom",
+            ["Greenwich Mean Time", "British Summer Time", "", ""],
+        ),
+        ("Uruguay_Standard", ["Uruguay Standard Time", "", "", ""]),
+        (
+            "Uzbekistan_Standard",
+            ["Uzbekistan Standard Time", "", "", ""],
+        ),
+        ("Vanuatu_Standard", ["Vanuatu Standard Time", "", "", ""]),
+        ("Venezuela", ["Venezuela Time", "", "", ""]),
+        (
+            "Vladivostok_Standard",
+            ["Vladivostok Standard Time", "", "", ""],
+        ),
+        ("Vostok", ["Vostok Time", "", "", ""]),
+        ("Wake_Island", ["Wake Island Time", "", "", ""]),
+        ("Wallis_Futuna", ["Wallis & Futuna Time", "", "", ""]),
+        ("West_Africa", ["West Africa Time", "", "", ""]),
+        (
+            "West_Greenland",
+            [
+                "West Greenland Standard Time",
+                "Greenland Summer Time",
+                "",
+                "",
+            ],
+        ),
+        ("West_Kazakhstan", ["West Kazakhstan Time", "", "", ""]),
+        (
+            "Western_European",
+            [
+                "Western European Standard Time",
+                "Western European Summer Time",
+                "",
+                "",
+            ],
+        ),
+        ("Western_Indonesia", ["Western Indonesia Time", "", "", ""]),
+        ("Yakutsk_Standard", ["Yakutsk Standard Time", "", "", ""]),
+        (
+            "Yekaterinburg_Standard",
+            ["Yekaterinburg Standard Time", "", "", ""],
+        ),
+        ("Yukon", ["Yukon Time", "", "", ""]),
+    ],
+    units_long: [
+        &[(One, "%d day"), (Other, "%d days")],
+        &[(One, "%d hour"), (Other, "%d hours")],
+        &[(One, "%d minute"), (Other, "%d minutes")],
+        &[(One, "%d second"), (Other, "%d seconds")],
+    ],
+    units_short: [
+        &[(One, "%d day"), (Other, "%d days")],
+        &[(One, "%d hr"), (Other, "%d hr")],
+        &[(One, "%d min"), (Other, "%d min")],
+        &[(One, "%d sec"), (Other, "%d sec")],
+    ],
+    units_narrow: [
+        &[(One, "%dd"), (Other, "%dd")],
+        &[(One, "%dh"), (Other, "%dh")],
+        &[(One, "%dm"), (Other, "%dm")],
+        &[(One, "%ds"), (Other, "%ds")],
+    ],
+    plural: plural_en,
+    ordinal_plural: ordinal_en,
+    ordinals: &[(One, "%dst"), (Two, "%dnd"), (Few, "%drd"), (Other, "%dth")],
+    localized_formats: [
+        "h:mm A",
+        "h:mm:ss A",
+        "MM/DD/YYYY",
+        "MMMM D, YYYY",
+        "MMMM D, YYYY h:mm A",
+        "dddd, MMMM D, YYYY h:mm A",
+    ],
+    calendar: [
+        "[Today at] h:mm A",
+        "[Tomorrow at] h:mm A",
+        "dddd [at] h:mm A",
+        "[Yesterday at] h:mm A",
+        "[Last] dddd [at] h:mm A",
+        "MM/DD/YYYY",
+    ],
+    relative_time: RelativeTime {
+        future: "in %s",
+        past: "%s ago",
+        units: RelativeUnits {
+            second: "a few seconds",
+            minute: "a minute",
+            minutes: &[(One, "%d minute"), (Other, "%d minutes")],
+            hour: "an hour",
+            hours: &[(One, "%d hour"), (Other, "%d hours")],
+            day: "a day",
+            days: &[(One, "%d day"), (Other, "%d days")],
+            month: "a month",
+            months: &[(One, "%d month"), (Other, "%d months")],
+            year: "a year",
+            years: &[(One, "%d year"), (Other, "%d years")],
+        },
+        future_units: RelativeUnits {
+            second: "a few seconds",
+            minute: "a minute",
+            minutes: &[(One, "%d minute"), (Other, "%d minutes")],
+            hour: "an hour",
+            hours: &[(One, "%d hour"), (Other, "%d hours")],
+            day: "a day",
+            days: &[(One, "%d day"), (Other, "%d days")],
+            month: "a month",
+            months: &[(One, "%d month"), (Other, "%d months")],
+            year: "a year",
+            years: &[(One, "%d year"), (Other, "%d years")],
+        },
+        past_units: RelativeUnits {
+            second: "a few seconds",
+            minute: "a minute",
+            minutes: &[(One, "%d minute"), (Other, "%d minutes")],
+            hour: "an hour",
+            hours: &[(One, "%d hour"), (Other, "%d hours")],
+            day: "a day",
+            days: &[(One, "%d day"), (Other, "%d days")],
+            month: "a month",
+            months: &[(One, "%d month"), (Other, "%d months")],
+            year: "a year",
+            years: &[(One, "%d year"), (Other, "%d years")],
+        },
+    },
+};
+
+pub static LT: Locale = Locale {
+    name: "lt",
+    months_format: [
+        "sausio",
+        "vasario",
+        "kovo",
+        "balandžio",
+        "gegužės",
+        "birželio",
+        "liepos",
+        "rugpjūčio",
+        "rugsėjo",
+        "spalio",
+        "lapkričio",
+        "gruodžio",
+    ],
+    months_standalone: [
+        "sausis",
+        "vasaris",
+        "kovas",
+        "balandis",
+        "gegužė",
+        "birželis",
+        "liepa",
+        "rugpjūtis",
+        "rugsėjis",
+        "spalis",
+        "lapkritis",
+        "gruodis",
+    ],
+    months_format_before_day: true,
+    months_short: [
+        "sau", "vas", "kov", "bal", "geg", "bir", "lie", "rgp", "rgs", "spa", "lap", "grd",
+    ],
+    weekdays: [
+        "sekmadienis",
+        "pirmadienis",
+        "antradienis",
+        "trečiadienis",
+        "ketvirtadienis",
+        "penktadienis",
+        "šeštadienis",
+    ],
+    weekdays_short: ["sek", "pir", "ant", "tre", "ket", "pen", "šeš"],
+    week_start: Weekday::Mon,
+    year_start: 4,
+    weekdays_relative: [
+        ["praėjusį sekmadienį", "šį sekmadienį", "kitą sekmadienį"],
+        ["praėjusį pirmadienį", "šį pirmadienį", "kitą pirmadienį"],
+        ["praėjusį antradienį", "šį antradienį", "kitą antradienį"],
+        ["praėjusį trečiadienį", "šį trečiadienį", "kitą trečiadienį"],
+        [
+            "praėjusį ketvirtadienį",
+            "šį ketvirtadienį",
+            "kitą ketvirtadienį",
+        ],
+        ["praėjusį penktadienį", "šį penktadienį", "kitą penktadienį"],
+        ["praėjusį šeštadienį", "šį šeštadienį", "kitą šeštadienį"],
+    ],
+    meridiem: ["priešpiet", "popiet"],
+    date_formats: [
+        "YYYY [m]. MMMM D [d]., dddd",
+        "YYYY [m]. MMMM D [d].",
+        "YYYY-MM-DD",
+        "YYYY-MM-DD",
+    ],
+    time_formats: ["HH:mm:ss zzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    datetime_formats: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    utc_names: ["pasaulio suderintasis laikas", "UTC"],
+    zone_names: &[
+        ("Acre_Standard", ["Ako standartinis laikas", "", "", ""]),
+        ("Afghanistan", ["Afganistano laikas", "", "", ""]),
+        (
+            "Alaska",
+            ["Aliaskos žiemos laikas", "Aliaskos vasaros laikas", "", ""],
+        ),
+        ("Amazon_Standard", ["Amazonės žiemos laikas", "", "", ""]),
+        (
+            "American_Samoa_Standard",
+            ["Samoa žiemos laikas", "", "", ""],
+        ),
+        ("Arabian_Standard", ["Arabijos žiemos laikas", "", "", ""]),
+        (
+            "Argentina_Standard",
+            ["Argentinos žiemos laikas", "", "", ""],
+        ),
+        ("Armenia_Standard", ["Armėnijos žiemos laikas", "", "", ""]),
+        (
+            "Atlantic",
+            ["Atlanto žiemos laikas", "Atlanto vasaros laikas", "", ""],
+        ),
+        ("Atlantic_Standard", ["Atlanto žiemos laikas", "", "", ""]),
+        (
+            "Australian_Central",
+            [
+                "Centrinės Australijos žiemos laikas",
+                "Centrinės Australijos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Central_Standard",
+            ["Centrinės Australijos žiemos laikas", "", "", ""],
+        ),
+        (
+            "Australian_Central_Western_Standard",
+            ["Centrinės vakarų Australijos žiemos laikas", "", "", ""],
+        ),
+        (
+            "Australian_Eastern",
+            [
+                "Rytų Australijos žiemos laikas",
+                "Rytų Australijos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern_Standard",
+            ["Rytų Australijos žiemos laikas", "", "", ""],
+        ),
+        (
+            "Australian_Western_Standard",
+            ["Vakarų Australijos žiemos laikas", "", "", ""],
+        ),
+        (
+            "Azerbaijan_Standard",
+            ["Azerbaidžano žiemos laikas", "", "", ""],
+        ),
+        (
+            "Azores",
+            [
+                "Azorų Salų žiemos laikas",
+                "Azorų Salų vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Bangladesh_Standard",
+            ["Bangladešo žiemos laikas", "", "", ""],
+        ),
+        ("Bhutan", ["Butano laikas", "", "", ""]),
+        ("Bolivia", ["Bolivijos laikas", "", "", ""]),
+        (
+            "Brasilia_Standard",
+            ["Brazilijos žiemos laikas", "", "", ""],
+        ),
+        ("Brunei", ["Brunėjaus Darusalamo laikas", "", "", ""]),
+        (
+            "Cape_Verde_Standard",
+            ["Žaliojo Kyšulio žiemos laikas", "", "", ""],
+        ),
+        (
+            "Central",
+            [
+                "Šiaurės Amerikos centro žiemos laikas",
+                "Šiaurės Amerikos centro vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Central_Africa", ["Centrinės Afrikos laikas", "", "", ""]),
+        (
+            "Central_European",
+            [
+                "Vidurio Europos žiemos laikas",
+                "Vidurio Europos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Central_European_Standard",
+            ["Vidurio Europos žiemos laikas", "", "", ""],
+        ),
+        (
+            "Central_Indonesia",
+            ["Centrinės Indonezijos laikas", "", "", ""],
+        ),
+        (
+            "Central_Standard",
+            ["Šiaurės Amerikos centro žiemos laikas", "", "", ""],
+        ),
+        ("Chamorro_Standard", ["Čamoro laikas", "", "", ""]),
+        (
+            "Chatham",
+            ["Čatamo žiemos laikas", "Čatamo vasaros laikas", "", ""],
+        ),
+        (
+            "Chile",
+            ["Čilės žiemos laikas", "Čilės vasaros laikas", "", ""],
+        ),
+        ("China_Standard", ["Kinijos žiemos laikas", "", "", ""]),
+        ("Christmas_Island", ["Kalėdų Salos laikas", "", "", ""]),
+        ("Chuuk", ["Čuko laikas", "", "", ""]),
+        ("Cocos_Islands", ["Kokosų Salų laikas", "", "", ""]),
+        (
+            "Colombia_Standard",
+            ["Kolumbijos žiemos laikas", "", "", ""],
+        ),
+        (
+            "Cook_Islands_Standard",
+            ["Kuko Salų žiemos laikas", "", "", ""],
+        ),
+        (
+            "Cuba",
+            ["Kubos žiemos laikas", "Kubos vasaros laikas", "", ""],
+        ),
+        ("Davis", ["Deiviso laikas", "", "", ""]),
+        ("Dumont_d_Urville", ["Diumono d’Urvilio laikas", "", "", ""]),
+        ("East_Africa", ["Rytų Afrikos laikas", "", "", ""]),
+        (
+            "East_Greenland",
+            ["Grenlandijos rytų žiemos laikas", "", "", ""],
+        ),
+        (
+            "East_Kazakhstan",
+            ["Kazachstano laikas", "Rytų Kazachstano laikas", "", ""],
+        ),
+        (
+            "Easter_Island",
+            [
+                "Velykų salos žiemos laikas",
+                "Velykų Salos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern",
+            [
+                "Šiaurės Amerikos rytų žiemos laikas",
+                "Šiaurės Amerikos rytų vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European",
+            [
+                "Rytų Europos žiemos laikas",
+                "Rytų Europos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European_Standard",
+            ["Rytų Europos žiemos laikas", "", "", ""],
+        ),
+        ("Eastern_Indonesia", ["Rytų Indonezijos laikas", "", "", ""]),
+        (
+            "Eastern_Standard",
+            ["Šiaurės Amerikos rytų žiemos laikas", "", "", ""],
+        ),
+        ("Ecuador", ["Ekvadoro laikas", "", "", ""]),
+        (
+            "Falkland_Islands_Standard",
+            ["Folklandų Salų žiemos laikas", "", "", ""],
+        ),
+        (
+            "Fernando_de_Noronha_Standard",
+            ["Fernando de Noronjos žiemos laikas", "", "", ""],
+        ),
+        ("Fiji_Standard", ["Fidžio žiemos laikas", "", "", ""]),
+        ("French_Guiana", ["Prancūzijos Gvianos laikas", "", "", ""]),
+        (
+            "French_Southern_Antarctic",
+            ["Pietų Prancūzijos ir antarktinis laikas", "", "", ""],
+        ),
+        ("Galapagos", ["Galapagų laikas", "", "", ""]),
+        ("Gambier", ["Gambyro laikas", "", "", ""]),
+        ("Georgia_Standard", ["Gruzijos žiemos laikas", "", "", ""]),
+        ("Gilbert_Islands", ["Gilberto Salų laikas", "", "", ""]),
+        ("Greenwich_Mean", ["Grinvičo laikas", "", "", ""]),
+        ("Guernsey", ["Grinvičo laikas", "", "", ""]),
+        ("Gulf_Standard", ["Persijos įlankos laikas", "", "", ""]),
+        ("Guyana", ["Gajanos laikas", "", "", ""]),
+        (
+            "Hawaii_Aleutian",
+            [
+                "Havajų–Aleutų žiemos laikas",
+                "Havajų–Aleutų vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Hawaii_Aleutian_Standard",
+            ["Havajų–Aleutų žiemos laikas", "", "", ""],
+        ),
+        ("Hong_Kong_Standard", ["Honkongo žiemos laikas", "", "", ""]),
+        ("India_Standard", ["Indijos laikas", "", "", ""]),
+        ("Indian_Ocean", ["Indijos vandenyno laikas", "", "", ""]),
+        ("Indochina", ["Indokinijos laikas", "", "", ""]),
+        ("Iran_Standard", ["Irano žiemos laikas", "", "", ""]),
+        (
+            "Ireland",
+            ["Grinvičo laikas", "Airijos vasaros laikas", "", ""],
+        ),
+        ("Irkutsk_Standard", ["Irkutsko žiemos laikas", "", "", ""]),
+        ("Isle_of_Man", ["Grinvičo laikas", "", "", ""]),
+        (
+            "Israel",
+            ["Izraelio žiemos laikas", "Izraelio vasaros laikas", "", ""],
+        ),
+        ("Japan_Standard", ["Japonijos žiemos laikas", "", "", ""]),
+        ("Jersey", ["Grinvičo laikas", "", "", ""]),
+        (
+            "Kamchatka_Standard",
+            ["Kamčiatkos Petropavlovsko žiemos laikas", "", "", ""],
+        ),
+        ("Khovd_Standard", ["Hovdo žiemos laikas", "", "", ""]),
+        ("Korean_Standard", ["Korėjos žiemos laikas", "", "", ""]),
+        ("Kosrae", ["Kosrajė laikas", "", "", ""]),
+        (
+            "Krasnoyarsk_Standard",
+            ["Krasnojarsko žiemos laikas", "", "", ""],
+        ),
+        ("Kyrgyzstan", ["Kirgistano laikas", "", "", ""]),
+        ("Line_Islands", ["Laino Salų laikas", "", "", ""]),
+        (
+            "Lord_Howe",
+            [
+                "Lordo Hau žiemos laikas",
+                "Lordo Hau vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Magadan_Standard", ["Magadano žiemos laikas", "", "", ""]),
+        ("Malaysia", ["Malaizijos laikas", "", "", ""]),
+        ("Maldives", ["Maldyvų laikas", "", "", ""]),
+        ("Marquesas", ["Markizo Salų laikas", "", "", ""]),
+        ("Marshall_Islands", ["Maršalo Salų laikas", "", "", ""]),
+        (
+            "Mauritius_Standard",
+            ["Mauricijaus žiemos laikas", "", "", ""],
+        ),
+        ("Mawson", ["Mosono laikas", "", "", ""]),
+        (
+            "Mexican_Pacific_Standard",
+            ["Meksikos Ramiojo vandenyno žiemos laikas", "", "", ""],
+        ),
+        ("Moscow_Standard", ["Maskvos žiemos laikas", "", "", ""]),
+        (
+            "Mountain",
+            [
+                "Šiaurės Amerikos kalnų žiemos laikas",
+                "Šiaurės Amerikos kalnų vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Mountain_Standard",
+            ["Šiaurės Amerikos kalnų žiemos laikas", "", "", ""],
+        ),
+        ("Myanmar", ["Mianmaro laikas", "", "", ""]),
+        ("Nauru", ["Nauru laikas", "", "", ""]),
+        ("Nepal", ["Nepalo laikas", "", "", ""]),
+        (
+            "New_Caledonia_Standard",
+            ["Naujosios Kaledonijos žiemos laikas", "", "", ""],
+        ),
+        (
+            "New_Zealand",
+            [
+                "Naujosios Zelandijos žiemos laikas",
+                "Naujosios Zelandijos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Newfoundland",
+            [
+                "Niufaundlendo žiemos laikas",
+                "Niufaundlendo vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Niue", ["Niujė laikas", "", "", ""]),
+        (
+            "Norfolk_Island",
+            [
+                "Norfolko Salų žiemos laikas",
+                "Norfolko Salų vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Omsk_Standard", ["Omsko žiemos laikas", "", "", ""]),
+        (
+            "Pacific",
+            [
+                "Šiaurės Amerikos Ramiojo vandenyno žiemos laikas",
+                "Šiaurės Amerikos Ramiojo vandenyno vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Pakistan_Standard", ["Pakistano žiemos laikas", "", "", ""]),
+        ("Palau", ["Palau laikas", "", "", ""]),
+        (
+            "Papua_New_Guinea",
+            ["Papua Naujosios Gvinėjos laikas", "", "", ""],
+        ),
+        (
+            "Paraguay",
+            [
+                "Paragvajaus žiemos laikas",
+                "Paragvajaus vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Peru_Standard", ["Peru žiemos laikas", "", "", ""]),
+        (
+            "Philippine_Standard",
+            ["Filipinų žiemos laikas", "", "", ""],
+        ),
+        ("Phoenix_Islands", ["Fenikso Salų laikas", "", "", ""]),
+        ("Pitcairn", ["Pitkerno laikas", "", "", ""]),
+        ("Pohnpei", ["Ponapės laikas", "", "", ""]),
+        ("R_union", ["Reunjono laikas", "", "", ""]),
+        ("Rothera", ["Roteros laikas", "", "", ""]),
+        ("Samara_Standard", ["Samaros žiemos laikas", "", "", ""]),
+        ("Samoa_Standard", ["Apijos žiemos laikas", "", "", ""]),
+        ("Seychelles", ["Seišelių laikas", "", "", ""]),
+        ("Singapore_Standard", ["Singapūro laikas", "", "", ""]),
+        ("Solomon_Islands", ["Saliamono Salų laikas", "", "", ""]),
+        (
+            "South_Africa_Standard",
+            ["Pietų Afrikos laikas", "", "", ""],
+        ),
+        ("South_Georgia", ["Pietų Džordžijos laikas", "", "", ""]),
+        (
+            "St_Pierre_Miquelon",
+            [
+                "Sen Pjero ir Mikelono žiemos laikas",
+                "Sen Pjero ir Mikelono vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        ("Suriname", ["Surinamo laikas", "", "", ""]),
+        ("Syowa", ["Siovos laikas", "", "", ""]),
+        ("T_rkiye_Standard", ["Turkijos žiemos laikas", "", "", ""]),
+        ("Tahiti", ["Tahičio laikas", "", "", ""]),
+        ("Taiwan_Standard", ["Taipėjaus žiemos laikas", "", "", ""]),
+        ("Tajikistan", ["Tadžikistano laikas", "", "", ""]),
+        ("Timor_Leste", ["Rytų Timoro laikas", "", "", ""]),
+        ("Tokelau", ["Tokelau laikas", "", "", ""]),
+        ("Tonga_Standard", ["Tongos žiemos laikas", "", "", ""]),
+        ("Troll_Station", ["Grinvičo laikas", "", "", ""]),
+        (
+            "Turkmenistan_Standard",
+            ["Turkmėnistano žiemos laikas", "", "", ""],
+        ),
+        ("Tuvalu", ["Tuvalu laikas", "", "", ""]),
+        (
+            "Ulaanbaatar_Standard",
+            ["Ulan Batoro žiemos laikas", "", "", ""],
+        ),
+        (
+            "United_Kingdom",
+            ["Grinvičo laikas", "Britanijos vasaros laikas", "", ""],
+        ),
+        ("Uruguay_Standard", ["Urugvajaus žiemos laikas", "", "", ""]),
+        (
+            "Uzbekistan_Standard",
+            ["Uzbekistano žiemos laikas", "", "", ""],
+        ),
+        ("Vanuatu_Standard", ["Vanuatu žiemos laikas", "", "", ""]),
+        ("Venezuela", ["Venesuelos laikas", "", "", ""]),
+        (
+            "Vladivostok_Standard",
+            ["Vladivostoko žiemos laikas", "", "", ""],
+        ),
+        ("Vostok", ["Vostoko laikas", "", "", ""]),
+        ("Wake_Island", ["Veiko Salos laikas", "", "", ""]),
+        ("Wallis_Futuna", ["Voliso ir Futūnos laikas", "", "", ""]),
+        ("West_Africa", ["Vakarų Afrikos laikas", "", "", ""]),
+        (
+            "West_Greenland",
+            ["Grenlandijos vakarų žiemos laikas", "", "", ""],
+        ),
+        ("West_Kazakhstan", ["Vakarų Kazachstano laikas", "", "", ""]),
+        (
+            "Western_European",
+            [
+                "Vakarų Europos žiemos laikas",
+                "Vakarų Europos vasaros laikas",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Western_Indonesia",
+            ["Vakarų Indonezijos laikas", "", "", ""],
+        ),
+        ("Yakutsk_Standard", ["Jakutsko žiemos laikas", "", "", ""]),
+        (
+            "Yekaterinburg_Standard",
+            ["Jekaterinburgo žiemos laikas", "", "", ""],
+        ),
+        ("Yukon", ["Jukono laikas", "", "", ""]),
+    ],
+    units_long: [
+        &[(One, "%d diena"), (Few, "%d dienos"), (Other, "%d dienų")],
+        &[
+            (One, "%d valanda"),
+            (Few, "%d valandos"),
+            (Other, "%d valandų"),
+        ],
+        &[
+            (One, "%d minutė"),
+            (Few, "%d minutės"),
+            (Other, "%d minučių"),
+        ],
+        &[
+            (One, "%d sekundė"),
+            (Few, "%d sekundės"),
+            (Other, "%d sekundžių"),
+        ],
+    ],
+    units_short: [
+        &[(One, "%d d."), (Few, "%d d."), (Other, "%d d.")],
+        &[(One, "%d val."), (Few, "%d val."), (Other, "%d val.")],
+        &[(One, "%d min."), (Few, "%d min."), (Other, "%d min.")],
+        &[(One, "%d sek."), (Few, "%d sek."), (Other, "%d sek.")],
+    ],
+    units_narrow: [
+        &[(One, "%d d."), (Few, "%d d."), (Other, "%d d.")],
+        &[(One, "%d h"), (Few, "%d h"), (Other, "%d h")],
+        &[(One, "%d min."), (Few, "%d min."), (Other, "%d min.")],
+        &[(One, "%d s"), (Few, "%d s"), (Other, "%d s")],
+    ],
+    plural: plural_lt,
+    ordinal_plural: ordinal_lt,
+    ordinals: &[(Other, "%d.")],
+    localized_formats: [
+        "HH:mm",
+        "HH:mm:ss",
+        "YYYY-MM-DD",
+        "YYYY [m.] MMMM D [d.]",
+        "YYYY [m.] MMMM D [d.], HH:mm [val.]",
+        "YYYY [m.] MMMM D [d.], dddd, HH:mm [val.]",
+    ],
+    calendar: [
+        "[Šiandien] HH:mm",
+        "[Rytoj] HH:mm",
+        "dddd HH:mm",
+        "[Vakar] HH:mm",
+        "[Praėjusį] dddd HH:mm",
+        "YYYY-MM-DD",
+    ],
+    relative_time: RelativeTime {
+        future: "už %s",
+        past: "prieš %s",
+        units: RelativeUnits {
+            second: "kelias sekundes",
+            minute: "minutę",
+            minutes: &[
+                (One, "%d minutę"),
+                (Few, "%d minutes"),
+                (Other, "%d minučių"),
+            ],
+            hour: "valandą",
+            hours: &[
+                (One, "%d valandą"),
+                (Few, "%d valandas"),
+                (Other, "%d valandų"),
+            ],
+            day: "dieną",
+            days: &[(One, "%d dieną"), (Few, "%d dienas"), (Other, "%d dienų")],
+            month: "mėnesį",
+            months: &[
+                (One, "%d mėnesį"),
+                (Few, "%d mėnesius"),
+                (Other, "%d mėnesių"),
+            ],
+            year: "metus",
+            years: &[(One, "%d metus"), (Few, "%d metus"), (Other, "%d metų")],
+        },
+        future_units: RelativeUnits {
+            second: "kelias sekundes",
+            minute: "minutę",
+            minutes: &[
+                (One, "%d minutę"),
+                (Few, "%d minutes"),
+                (Other, "%d minučių"),
+            ],
+            hour: "valandą",
+            hours: &[
+                (One, "%d valandą"),
+                (Few, "%d valandas"),
+                (Other, "%d valandų"),
+            ],
+            day: "dieną",
+            days: &[(One, "%d dieną"), (Few, "%d dienas"), (Other, "%d dienų")],
+            month: "mėnesį",
+            months: &[
+                (One, "%d mėnesį"),
+                (Few, "%d mėnesius"),
+                (Other, "%d mėnesių"),
+            ],
+            year: "metus",
+            years: &[(One, "%d metus"), (Few, "%d metus"), (Other, "%d metų")],
+        },
+        past_units: RelativeUnits {
+            second: "kelias sekundes",
+            minute: "minutę",
+            minutes: &[
+                (One, "%d minutę"),
+                (Few, "%d minutes"),
+                (Other, "%d minučių"),
+            ],
+            hour: "valandą",
+            hours: &[
+                (One, "%d valandą"),
+                (Few, "%d valandas"),
+                (Other, "%d valandų"),
+            ],
+            day: "dieną",
+            days: &[(One, "%d dieną"), (Few, "%d dienas"), (Other, "%d dienų")],
+            month: "mėnesį",
+            months: &[
+                (One, "%d mėnesį"),
+                (Few, "%d mėnesius"),
+                (Other, "%d mėnesių"),
+            ],
+            year: "metus",
+            years: &[(One, "%d metus"), (Few, "%d metus"), (Other, "%d metų")],
+        },
+    },
+};
+
+pub static PL: Locale = Locale {
+    name: "pl",
+    months_format: [
+        "stycznia",
+        "lutego",
+        "marca",
+        "kwietnia",
+        "maja",
+        "czerwca",
+        "lipca",
+        "sierpnia",
+        "września",
+        "października",
+        "listopada",
+        "grudnia",
+    ],
+    months_standalone: [
+        "styczeń",
+        "luty",
+        "marzec",
+        "kwiecień",
+        "maj",
+        "czerwiec",
+        "lipiec",
+        "sierpień",
+        "wrzesień",
+        "październik",
+        "listopad",
+        "grudzień",
+    ],
+    months_format_before_day: false,
+    months_short: [
+        "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+    ],
+    weekdays: [
+        "niedziela",
+        "poniedziałek",
+        "wtorek",
+        "środa",
+        "czwartek",
+        "piątek",
+        "sobota",
+    ],
+    weekdays_short: ["ndz", "pon", "wt", "śr", "czw", "pt", "sob"],
+    week_start: Weekday::Mon,
+    year_start: 4,
+    weekdays_relative: [
+        [
+            "w zeszłą niedzielę",
+            "w tę niedzielę",
+            "w przyszłą niedzielę",
+        ],
+        [
+            "w zeszły poniedziałek",
+            "w ten poniedziałek",
+            "w przyszły poniedziałek",
+        ],
+        ["w zeszły wtorek", "w ten wtorek", "w przyszły wtorek"],
+        ["w zeszłą środę", "w tę środę", "w przyszłą środę"],
+        ["w zeszły czwartek", "w ten czwartek", "w przyszły czwartek"],
+        ["w zeszły piątek", "w ten piątek", "w przyszły piątek"],
+        ["w zeszłą sobotę", "w tę sobotę", "w przyszłą sobotę"],
+    ],
+    meridiem: ["AM", "PM"],
+    date_formats: [
+        "dddd, D MMMM YYYY",
+        "D MMMM YYYY",
+        "D MMM YYYY",
+        "D.MM.YYYY",
+    ],
+    time_formats: ["HH:mm:ss zzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    datetime_formats: ["{1} {0}", "{1} {0}", "{1}, {0}", "{1}, {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    utc_names: ["uniwersalny czas koordynowany", "UTC"],
+    zone_names: &[
+        ("Afghanistan", ["czas Afganistan", "", "", ""]),
+        (
+            "Alaska",
+            ["Alaska (czas standardowy)", "Alaska (czas letni)", "", ""],
+        ),
+        (
+            "Amazon_Standard",
+            ["czas amazoński standardowy", "", "", ""],
+        ),
+        (
+            "American_Samoa_Standard",
+            ["Samoa (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Arabian_Standard",
+            ["Półwysep Arabski (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Argentina_Standard",
+            ["Argentyna (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Armenia_Standard",
+            ["Armenia (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Atlantic",
+            [
+                "czas atlantycki standardowy",
+                "czas atlantycki letni",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Atlantic_Standard",
+            ["czas atlantycki standardowy", "", "", ""],
+        ),
+        (
+            "Australian_Central",
+            [
+                "czas środkowoaustralijski standardowy",
+                "czas środkowoaustralijski letni",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Central_Standard",
+            ["czas środkowoaustralijski standardowy", "", "", ""],
+        ),
+        (
+            "Australian_Central_Western_Standard",
+            [
+                "czas środkowo-zachodnioaustralijski standardowy",
+                "",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern",
+            [
+                "czas wschodnioaustralijski standardowy",
+                "czas wschodnioaustralijski letni",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern_Standard",
+            ["czas wschodnioaustralijski standardowy", "", "", ""],
+        ),
+        (
+            "Australian_Western_Standard",
+            ["czas zachodnioaustralijski standardowy", "", "", ""],
+        ),
+        (
+            "Azerbaijan_Standard",
+            ["Azerbejdżan (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Azores",
+            ["Azory (czas standardowy)", "Azory (czas letni)", "", ""],
+        ),
+        (
+            "Bangladesh_Standard",
+            ["Bangladesz (czas standardowy)", "", "", ""],
+        ),
+        ("Bhutan", ["czas Bhutan", "", "", ""]),
+        ("Bolivia", ["czas Boliwia", "", "", ""]),
+        (
+            "Brasilia_Standard",
+            ["Brasília (czas standardowy)", "", "", ""],
+        ),
+        ("Brunei", ["czas Brunei", "", "", ""]),
+        (
+            "Cape_Verde_Standard",
+            ["Wyspy Zielonego Przylądka (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Central",
+            [
+                "czas środkowoamerykański standardowy",
+                "czas środkowoamerykański letni",
+                "",
+                "",
+            ],
+        ),
+        ("Central_Africa", ["czas środkowoafrykański", "", "", ""]),
+        (
+            "Central_European",
+            [
+                "czas środkowoeuropejski standardowy",
+                "czas środkowoeuropejski letni",
+                "CET",
+                "CEST",
+            ],
+        ),
+        (
+            "Central_European_Standard",
+            ["czas środkowoeuropejski standardowy", "", "CET", ""],
+        ),
+        ("Central_Indonesia", ["czas Indonezja Środkowa", "", "", ""]),
+        (
+            "Central_Standard",
+            ["czas środkowoamerykański standardowy", "", "", ""],
+        ),
+        ("Chamorro_Standard", ["czas Czamorro", "", "", ""]),
+        (
+            "Chatham",
+            ["Chatham (czas standardowy)", "Chatham (czas letni)", "", ""],
+        ),
+        (
+            "Chile",
+            ["Chile (czas standardowy)", "Chile (czas letni)", "", ""],
+        ),
+        ("China_Standard", ["Chiny (czas standardowy)", "", "", ""]),
+        (
+            "Christmas_Island",
+            ["czas Wyspa Bożego Narodzenia", "", "", ""],
+        ),
+        ("Chuuk", ["czas Chuuk", "", "", ""]),
+        ("Cocos_Islands", ["czas Wyspy Kokosowe", "", "", ""]),
+        (
+            "Colombia_Standard",
+            ["Kolumbia (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Cook_Islands_Standard",
+            ["Wyspy Cooka (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Cuba",
+            ["Kuba (czas standardowy)", "Kuba (czas letni)", "", ""],
+        ),
+        ("Davis", ["czas Davis", "", "", ""]),
+        ("Dumont_d_Urville", ["czas Dumont-d’Urville", "", "", ""]),
+        ("East_Africa", ["czas wschodnioafrykański", "", "", ""]),
+        (
+            "East_Greenland",
+            ["Grenlandia Wschodnia (czas standardowy)", "", "", ""],
+        ),
+        (
+            "East_Kazakhstan",
+            ["czas Kazachstan", "czas Kazachstan Wschodni", "", ""],
+        ),
+        (
+            "Easter_Island",
+            [
+                "Wyspa Wielkanocna (czas standardowy)",
+                "Wyspa Wielkanocna (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern",
+            [
+                "czas wschodnioamerykański standardowy",
+                "czas wschodnioamerykański letni",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European",
+            [
+                "czas wschodnioeuropejski standardowy",
+                "czas wschodnioeuropejski letni",
+                "EET",
+                "EEST",
+            ],
+        ),
+        (
+            "Eastern_European_Standard",
+            ["czas wschodnioeuropejski standardowy", "", "EET", ""],
+        ),
+        (
+            "Eastern_Indonesia",
+            ["czas Indonezja Wschodnia", "", "", ""],
+        ),
+        (
+            "Eastern_Standard",
+            ["czas wschodnioamerykański standardowy", "", "", ""],
+        ),
+        ("Ecuador", ["czas Ekwador", "", "", ""]),
+        (
+            "Falkland_Islands_Standard",
+            ["Falklandy (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Fernando_de_Noronha_Standard",
+            ["Fernando de Noronha (czas standardowy)", "", "", ""],
+        ),
+        ("Fiji_Standard", ["Fidżi (czas standardowy)", "", "", ""]),
+        ("French_Guiana", ["czas Gujana Francuska", "", "", ""]),
+        (
+            "French_Southern_Antarctic",
+            [
+                "czas Francuskie Terytoria Południowe i Antarktyczne",
+                "",
+                "",
+                "",
+            ],
+        ),
+        ("Galapagos", ["czas Galapagos", "", "", ""]),
+        ("Gambier", ["czas Wyspy Gambiera", "", "", ""]),
+        (
+            "Georgia_Standard",
+            ["Gruzja (czas standardowy)", "", "", ""],
+        ),
+        ("Gilbert_Islands", ["czas Wyspy Gilberta", "", "", ""]),
+        ("Greenwich_Mean", ["czas uniwersalny", "", "", ""]),
+        ("Guernsey", ["czas uniwersalny", "", "", ""]),
+        ("Gulf_Standard", ["czas Zatoka Perska", "", "", ""]),
+        ("Guyana", ["czas Gujana", "", "", ""]),
+        (
+            "Hawaii_Aleutian",
+            [
+                "Hawaje-Aleuty (czas standardowy)",
+                "Hawaje-Aleuty (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Hawaii_Aleutian_Standard",
+            ["Hawaje-Aleuty (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Hong_Kong_Standard",
+            ["Hongkong (czas standardowy)", "", "", ""],
+        ),
+        ("India_Standard", ["czas indyjski standardowy", "", "", ""]),
+        ("Indian_Ocean", ["czas Ocean Indyjski", "", "", ""]),
+        ("Indochina", ["czas indochiński", "", "", ""]),
+        ("Iran_Standard", ["Iran (czas standardowy)", "", "", ""]),
+        (
+            "Ireland",
+            ["czas uniwersalny", "Irlandia (czas letni)", "", ""],
+        ),
+        (
+            "Irkutsk_Standard",
+            ["Irkuck (czas standardowy)", "", "", ""],
+        ),
+        ("Isle_of_Man", ["czas uniwersalny", "", "", ""]),
+        (
+            "Israel",
+            ["Izrael (czas standardowy)", "Izrael (czas letni)", "", ""],
+        ),
+        ("Japan_Standard", ["Japonia (czas standardowy)", "", "", ""]),
+        ("Jersey", ["czas uniwersalny", "", "", ""]),
+        (
+            "Kamchatka_Standard",
+            ["czas standardowy Pietropawłowsk Kamczacki", "", "", ""],
+        ),
+        ("Khovd_Standard", ["Kobdo (czas standardowy)", "", "", ""]),
+        ("Korean_Standard", ["Korea (czas standardowy)", "", "", ""]),
+        ("Kosrae", ["czas Kosrae", "", "", ""]),
+        (
+            "Krasnoyarsk_Standard",
+            ["Krasnojarsk (czas standardowy)", "", "", ""],
+        ),
+        ("Kyrgyzstan", ["czas Kirgistan", "", "", ""]),
+        ("Line_Islands", ["czas Line Islands", "", "", ""]),
+        (
+            "Lord_Howe",
+            [
+                "Lord Howe (czas standardowy)",
+                "Lord Howe (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Magadan_Standard",
+            ["Magadan (czas standardowy)", "", "", ""],
+        ),
+        ("Malaysia", ["czas Malezja", "", "", ""]),
+        ("Maldives", ["czas Malediwy", "", "", ""]),
+        ("Marquesas", ["czas Markizy", "", "", ""]),
+        ("Marshall_Islands", ["czas Wyspy Marshalla", "", "", ""]),
+        (
+            "Mauritius_Standard",
+            ["Mauritius (czas standardowy)", "", "", ""],
+        ),
+        ("Mawson", ["czas Mawson", "", "", ""]),
+        (
+            "Mexican_Pacific_Standard",
+            ["Meksyk (czas pacyficzny standardowy)", "", "", ""],
+        ),
+        ("Moscow_Standard", ["Moskwa (czas standardowy)", "", "", ""]),
+        (
+            "Mountain",
+            ["czas górski standardowy", "czas górski letni", "", ""],
+        ),
+        ("Mountain_Standard", ["czas górski standardowy", "", "", ""]),
+        ("Myanmar", ["czas Mjanma", "", "", ""]),
+        ("Nauru", ["czas Nauru", "", "", ""]),
+        ("Nepal", ["czas Nepal", "", "", ""]),
+        (
+            "New_Caledonia_Standard",
+            ["Nowa Kaledonia (czas standardowy)", "", "", ""],
+        ),
+        (
+            "New_Zealand",
+            [
+                "Nowa Zelandia (czas standardowy)",
+                "Nowa Zelandia (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Newfoundland",
+            [
+                "Nowa Fundlandia (czas standardowy)",
+                "Nowa Fundlandia (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        ("Niue", ["czas Niue", "", "", ""]),
+        (
+            "Norfolk_Island",
+            ["Norfolk (czas standardowy)", "Norfolk (czas letni)", "", ""],
+        ),
+        ("Omsk_Standard", ["Omsk (czas standardowy)", "", "", ""]),
+        (
+            "Pacific",
+            [
+                "czas pacyficzny standardowy",
+                "czas pacyficzny letni",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Pakistan_Standard",
+            ["Pakistan (czas standardowy)", "", "", ""],
+        ),
+        ("Palau", ["czas Palau", "", "", ""]),
+        ("Papua_New_Guinea", ["czas Papua-Nowa Gwinea", "", "", ""]),
+        (
+            "Paraguay",
+            [
+                "Paragwaj (czas standardowy)",
+                "Paragwaj (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        ("Peru_Standard", ["Peru (czas standardowy)", "", "", ""]),
+        (
+            "Philippine_Standard",
+            ["Filipiny (czas standardowy)", "", "", ""],
+        ),
+        ("Phoenix_Islands", ["czas Feniks", "", "", ""]),
+        ("Pitcairn", ["czas Pitcairn", "", "", ""]),
+        ("Pohnpei", ["czas Pohnpei", "", "", ""]),
+        ("R_union", ["czas Reunion", "", "", ""]),
+        ("Rothera", ["czas Rothera", "", "", ""]),
+        ("Samara_Standard", ["czas standardowy Samara", "", "", ""]),
+        ("Samoa_Standard", ["Apia (czas standardowy)", "", "", ""]),
+        ("Seychelles", ["czas Seszele", "", "", ""]),
+        ("Singapore_Standard", ["czas Singapur", "", "", ""]),
+        ("Solomon_Islands", ["czas Wyspy Salomona", "", "", ""]),
+        (
+            "South_Africa_Standard",
+            ["czas południowoafrykański", "", "", ""],
+        ),
+        ("South_Georgia", ["czas Georgia Południowa", "", "", ""]),
+        (
+            "St_Pierre_Miquelon",
+            [
+                "Saint-Pierre i Miquelon (czas standardowy)",
+                "Saint-Pierre i Miquelon (czas letni)",
+                "",
+                "",
+            ],
+        ),
+        ("Suriname", ["czas Surinam", "", "", ""]),
+        ("Syowa", ["czas Syowa", "", "", ""]),
+        ("Tahiti", ["czas Tahiti", "", "", ""]),
+        ("Taiwan_Standard", ["Tajpej (czas standardowy)", "", "", ""]),
+        ("Tajikistan", ["czas Tadżykistan", "", "", ""]),
+        ("Timor_Leste", ["czas Timor Wschodni", "", "", ""]),
+        ("Tokelau", ["czas Tokelau", "", "", ""]),
+        ("Tonga_Standard", ["Tonga (czas standardowy)", "", "", ""]),
+        ("Troll_Station", ["czas uniwersalny", "", "", ""]),
+        (
+            "Turkmenistan_Standard",
+            ["Turkmenistan (czas standardowy)", "", "", ""],
+        ),
+        ("Tuvalu", ["czas Tuvalu", "", "", ""]),
+        (
+            "Ulaanbaatar_Standard",
+            ["Ułan Bator (czas standardowy)", "", "", ""],
+        ),
+        (
+            "United_Kingdom",
+            ["czas uniwersalny", "Brytyjski czas letni", "", ""],
+        ),
+        (
+            "Uruguay_Standard",
+            ["Urugwaj (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Uzbekistan_Standard",
+            ["Uzbekistan (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Vanuatu_Standard",
+            ["Vanuatu (czas standardowy)", "", "", ""],
+        ),
+        ("Venezuela", ["czas Wenezuela", "", "", ""]),
+        (
+            "Vladivostok_Standard",
+            ["Władywostok (czas standardowy)", "", "", ""],
+        ),
+        ("Vostok", ["czas Wostok", "", "", ""]),
+        ("Wake_Island", ["czas Wake", "", "", ""]),
+        ("Wallis_Futuna", ["czas Wallis i Futuna", "", "", ""]),
+        ("West_Africa", ["czas zachodnioafrykański", "", "", ""]),
+        (
+            "West_Greenland",
+            ["Grenlandia Zachodnia (czas standardowy)", "", "", ""],
+        ),
+        ("West_Kazakhstan", ["czas Kazachstan Zachodni", "", "", ""]),
+        (
+            "Western_European",
+            [
+                "czas zachodnioeuropejski standardowy",
+                "czas zachodnioeuropejski letni",
+                "WET",
+                "WEST",
+            ],
+        ),
+        (
+            "Western_Indonesia",
+            ["czas Indonezja Zachodnia", "", "", ""],
+        ),
+        (
+            "Yakutsk_Standard",
+            ["Jakuck (czas standardowy)", "", "", ""],
+        ),
+        (
+            "Yekaterinburg_Standard",
+            ["Jekaterynburg (czas standardowy)", "", "", ""],
+        ),
+        ("Yukon", ["czas Jukon", "", "", ""]),
+    ],
+    units_long: [
+        &[(One, "%d dzień"), (Few, "%d dni"), (Many, "%d dni")],
+        &[
+            (One, "%d godzina"),
+            (Few, "%d godziny"),
+            (Many, "%d godzin"),
+        ],
+        &[(One, "%d minuta"), (Few, "%d minuty"), (Many, "%d minut")],
+        &[
+            (One, "%d sekunda"),
+            (Few, "%d sekundy"),
+            (Many, "%d sekund"),
+        ],
+    ],
+    units_short: [
+        &[(One, "%d dzień"), (Few, "%d dni"), (Many, "%d dni")],
+        &[(One, "%d godz."), (Few, "%d godz."), (Many, "%d godz.")],
+        &[(One, "%d min"), (Few, "%d min"), (Many, "%d min")],
+        &[(One, "%d sek."), (Few, "%d sek."), (Many, "%d sek.")],
+    ],
+    units_narrow: [
+        &[(One, "%d d."), (Few, "%d d."), (Many, "%d d.")],
+        &[(One, "%d h"), (Few, "%d h"), (Many, "%d h")],
+        &[(One, "%d min"), (Few, "%d min"), (Many, "%d min")],
+        &[(One, "%d s"), (Few, "%d s"), (Many, "%d s")],
+    ],
+    plural: plural_pl,
+    ordinal_plural: ordinal_pl,
+    ordinals: &[(Other, "%d.")],
+    localized_formats: [
+        "HH:mm",
+        "HH:mm:ss",
+        "DD.MM.YYYY",
+        "D MMMM YYYY",
+        "D MMMM YYYY HH:mm",
+        "dddd, D MMMM YYYY HH:mm",
+    ],
+    calendar: [
+        "[Dziś o] HH:mm",
+        "[Jutro o] HH:mm",
+        "dddd [o] HH:mm",
+        "[Wczoraj o] HH:mm",
+        "[W zeszły] dddd [o] HH:mm",
+        "DD.MM.YYYY",
+    ],
+    relative_time: RelativeTime {
+        future: "za %s",
+        past: "%s temu",
+        units: RelativeUnits {
+            second: "kilka sekund",
+            minute: "minuta",
+            minutes: &[(One, "%d minuta"), (Few, "%d minuty"), (Many, "%d minut")],
+            hour: "godzina",
+            hours: &[
+                (One, "%d godzina"),
+                (Few, "%d godziny"),
+                (Many, "%d godzin"),
+            ],
+            day: "dzień",
+            days: &[(One, "%d dzień"), (Few, "%d dni"), (Many, "%d dni")],
+            month: "miesiąc",
+            months: &[
+                (One, "%d miesiąc"),
+                (Few, "%d miesiące"),
+                (Many, "%d miesięcy"),
+            ],
+            year: "rok",
+            years: &[(One, "%d rok"), (Few, "%d lata"), (Many, "%d lat")],
+        },
+        future_units: RelativeUnits {
+            second: "kilka sekund",
+            minute: "minutę",
+            minutes: &[(One, "%d minutę"), (Few, "%d minuty"), (Many, "%d minut")],
+            hour: "godzinę",
+            hours: &[
+                (One, "%d godzinę"),
+                (Few, "%d godziny"),
+                (Many, "%d godzin"),
+            ],
+            day: "dzień",
+            days: &[(One, "%d dzień"), (Few, "%d dni"), (Many, "%d dni")],
+            month: "miesiąc",
+            months: &[
+                (One, "%d miesiąc"),
+                (Few, "%d miesiące"),
+                (Many, "%d miesięcy"),
+            ],
+            year: "rok",
+            years: &[(One, "%d rok"), (Few, "%d lata"), (Many, "%d lat")],
+        },
+        past_units: RelativeUnits {
+            second: "kilka sekund",
+            minute: "minutę",
+            minutes: &[(One, "%d minutę"), (Few, "%d minuty"), (Many, "%d minut")],
+            hour: "godzinę",
+            hours: &[
+                (One, "%d godzinę"),
+                (Few, "%d godziny"),
+                (Many, "%d godzin"),
+            ],
+            day: "dzień",
+            days: &[(One, "%d dzień"), (Few, "%d dni"), (Many, "%d dni")],
+            month: "miesiąc",
+            months: &[
+                (One, "%d miesiąc"),
+                (Few, "%d miesiące"),
+                (Many, "%d miesięcy"),
+            ],
+            year: "rok",
+            years: &[(One, "%d rok"), (Few, "%d lata"), (Many, "%d lat")],
+        },
+    },
+};
+
+pub static RU: Locale = Locale {
+    name: "ru",
+    months_format: [
+        "января",
+        "февраля",
+        "марта",
+        "апреля",
+        "мая",
+        "июня",
+        "июля",
+        "августа",
+        "сентября",
+        "октября",
+        "ноября",
+        "декабря",
+    ],
+    months_standalone: [
+        "январь",
+        "февраль",
+        "март",
+        "апрель",
+        "май",
+        "июнь",
+        "июль",
+        "август",
+        "сентябрь",
+        "октябрь",
+        "ноябрь",
+        "декабрь",
+    ],
+    months_format_before_day: false,
+    months_short: [
+        "янв.",
+        "февр.",
+        "март",
+        "апр.",
+        "май",
+        "июнь",
+        "июль",
+        "авг.",
+        "сент.",
+        "окт.",
+        "нояб.",
+        "дек.",
+    ],
+    weekdays: [
+        "воскресенье",
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+    ],
+    weekdays_short: ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+    week_start: Weekday::Mon,
+    year_start: 4,
+    weekdays_relative: [
+        [
+            "в прошлое воскресенье",
+            "в это воскресенье",
+            "в следующее воскресенье",
+        ],
+        [
+            "в прошлый понедельник",
+            "в этот понедельник",
+            "в следующий понедельник",
+        ],
+        ["в прошлый вторник", "в этот вторник", "в следующий вторник"],
+        ["в прошлую среду", "в эту среду", "в следующую среду"],
+        ["в прошлый четверг", "в этот четверг", "в следующий четверг"],
+        ["в прошлую пятницу", "в эту пятницу", "в следующую пятницу"],
+        ["в прошлую субботу", "в эту субботу", "в следующую субботу"],
+    ],
+    meridiem: ["AM", "PM"],
+    date_formats: [
+        "dddd, D MMMM YYYY [г].",
+        "D MMMM YYYY [г].",
+        "D MMM YYYY [г].",
+        "DD.MM.YYYY",
+    ],
+    time_formats: ["HH:mm:ss zzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    datetime_formats: ["{1} [в] {0}", "{1} [в] {0}", "{1}, {0}", "{1}, {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    utc_names: ["Всемирное координированное время", "UTC"],
+    zone_names: &[
+        ("Acre_Standard", ["Акри стандартное время", "", "", ""]),
+        ("Afghanistan", ["Афганистан", "", "", ""]),
+        (
+            "Alaska",
+            ["Аляска, стандартное время", "Аляска, летнее время", "", ""],
+        ),
+        (
+            "Amazon_Standard",
+            ["Амазонка, стандартное время", "", "", ""],
+        ),
+        (
+            "American_Samoa_Standard",
+            ["Самоа, стандартное время", "", "", ""],
+        ),
+        (
+            "Arabian_Standard",
+            ["Саудовская Аравия, стандартное время", "", "", ""],
+        ),
+        (
+            "Argentina_Standard",
+            ["Аргентина, стандартное время", "", "", ""],
+        ),
+        (
+            "Armenia_Standard",
+            ["Армения, стандартное время", "", "", ""],
+        ),
+        (
+            "Atlantic",
+            [
+                "Атлантическое стандартное время",
+                "Атлантическое летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Atlantic_Standard",
+            ["Атлантическое стандартное время", "", "", ""],
+        ),
+        (
+            "Australian_Central",
+            [
+                "Центральная Австралия, стандартное время",
+                "Центральная Австралия, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Central_Standard",
+            ["Центральная Австралия, стандартное время", "", "", ""],
+        ),
+        (
+            "Australian_Central_Western_Standard",
+            [
+                "Центральная Австралия, западное стандартное время",
+                "",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern",
+            [
+                "Восточная Австралия, стандартное время",
+                "Восточная Австралия, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern_Standard",
+            ["Восточная Австралия, стандартное время", "", "", ""],
+        ),
+        (
+            "Australian_Western_Standard",
+            ["Западная Австралия, стандартное время", "", "", ""],
+        ),
+        (
+            "Azerbaijan_Standard",
+            ["Азербайджан, стандартное время", "", "", ""],
+        ),
+        (
+            "Azores",
+            [
+                "Азорские о-ва, стандартное время",
+                "Азорские о-ва, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Bangladesh_Standard",
+            ["Бангладеш, стандартное время", "", "", ""],
+        ),
+        ("Bhutan", ["Бутан", "", "", ""]),
+        ("Bolivia", ["Боливия", "", "", ""]),
+        (
+            "Brasilia_Standard",
+            ["Бразилия, стандартное время", "", "", ""],
+        ),
+        ("Brunei", ["Бруней-Даруссалам", "", "", ""]),
+        (
+            "Cape_Verde_Standard",
+            ["Кабо-Верде, стандартное время", "", "", ""],
+        ),
+        (
+            "Central",
+            [
+                "Центральная Америка, стандартное время",
+                "Центральная Америка, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Central_Africa", ["Центральная Африка", "", "", ""]),
+        (
+            "Central_European",
+            [
+                "Центральная Европа, стандартное время",
+                "Центральная Европа, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Central_European_Standard",
+            ["Центральная Европа, стандартное время", "", "", ""],
+        ),
+        ("Central_Indonesia", ["Центральная Индонезия", "", "", ""]),
+        (
+            "Central_Standard",
+            ["Центральная Америка, стандартное время", "", "", ""],
+        ),
+        ("Chamorro_Standard", ["Чаморро", "", "", ""]),
+        (
+            "Chatham",
+            ["Чатем, стандартное время", "Чатем, летнее время", "", ""],
+        ),
+        (
+            "Chile",
+            ["Чили, стандартное время", "Чили, летнее время", "", ""],
+        ),
+        ("China_Standard", ["Китай, стандартное время", "", "", ""]),
+        ("Christmas_Island", ["о-в Рождества", "", "", ""]),
+        ("Chuuk", ["Трук", "", "", ""]),
+        ("Cocos_Islands", ["Кокосовые о-ва", "", "", ""]),
+        (
+            "Colombia_Standard",
+            ["Колумбия, стандартное время", "", "", ""],
+        ),
+        (
+            "Cook_Islands_Standard",
+            ["Острова Кука, стандартное время", "", "", ""],
+        ),
+        (
+            "Cuba",
+            ["Куба, стандартное время", "Куба, летнее время", "", ""],
+        ),
+        ("Davis", ["Дейвис", "", "", ""]),
+        ("Dumont_d_Urville", ["Дюмон-д’Юрвиль", "", "", ""]),
+        ("East_Africa", ["Восточная Африка", "", "", ""]),
+        (
+            "East_Greenland",
+            ["Восточная Гренландия, стандарное время", "", "", ""],
+        ),
+        (
+            "East_Kazakhstan",
+            ["Казахстан", "Восточный Казахстан", "", ""],
+        ),
+        (
+            "Easter_Island",
+            [
+                "О-в Пасхи, стандартное время",
+                "О-в Пасхи, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern",
+            [
+                "Восточная Америка, стандартное время",
+                "Восточная Америка, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European",
+            [
+                "Восточная Европа, стандартное время",
+                "Восточная Европа, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European_Standard",
+            ["Восточная Европа, стандартное время", "", "", ""],
+        ),
+        ("Eastern_Indonesia", ["Восточная Индонезия", "", "", ""]),
+        (
+            "Eastern_Standard",
+            ["Восточная Америка, стандартное время", "", "", ""],
+        ),
+        ("Ecuador", ["Эквадор", "", "", ""]),
+        (
+            "Falkland_Islands_Standard",
+            ["Фолклендские о-ва, стандартное время", "", "", ""],
+        ),
+        (
+            "Fernando_de_Noronha_Standard",
+            ["Фернанду-ди-Норонья, стандартное время", "", "", ""],
+        ),
+        ("Fiji_Standard", ["Фиджи, стандартное время", "", "", ""]),
+        ("French_Guiana", ["Французская Гвиана", "", "", ""]),
+        (
+            "French_Southern_Antarctic",
+            ["Французские Южные и Антарктические территории", "", "", ""],
+        ),
+        ("Galapagos", ["Галапагосские о-ва", "", "", ""]),
+        ("Gambier", ["Гамбье", "", "", ""]),
+        (
+            "Georgia_Standard",
+            ["Грузия, стандартное время", "", "", ""],
+        ),
+        ("Gilbert_Islands", ["о-ва Гилберта", "", "", ""]),
+        ("Greenwich_Mean", ["Среднее время по Гринвичу", "", "", ""]),
+        ("Guernsey", ["Среднее время по Гринвичу", "", "", ""]),
+        ("Gulf_Standard", ["Персидский залив", "", "", ""]),
+        ("Guyana", ["Гайана", "", "", ""]),
+        (
+            "Hawaii_Aleutian",
+            [
+                "Гавайско-алеутское стандартное время",
+                "Гавайско-алеутское летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Hawaii_Aleutian_Standard",
+            ["Гавайско-алеутское стандартное время", "", "", ""],
+        ),
+        (
+            "Hong_Kong_Standard",
+            ["Гонконг, стандартное время", "", "", ""],
+        ),
+        ("India_Standard", ["Индия", "", "", ""]),
+        ("Indian_Ocean", ["Индийский океан", "", "", ""]),
+        ("Indochina", ["Индокитай", "", "", ""]),
+        ("Iran_Standard", ["Иран, стандартное время", "", "", ""]),
+        (
+            "Ireland",
+            [
+                "Среднее время по Гринвичу",
+                "Ирландия, стандартное время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Irkutsk_Standard",
+            ["Иркутск, стандартное время", "", "", ""],
+        ),
+        ("Isle_of_Man", ["Среднее время по Гринвичу", "", "", ""]),
+        (
+            "Israel",
+            [
+                "Израиль, стандартное время",
+                "Израиль, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Japan_Standard", ["Япония, стандартное время", "", "", ""]),
+        ("Jersey", ["Среднее время по Гринвичу", "", "", ""]),
+        (
+            "Kamchatka_Standard",
+            ["Петропавловск-Камчатский, стандартное время", "", "", ""],
+        ),
+        ("Khovd_Standard", ["Ховд, стандартное время", "", "", ""]),
+        ("Korean_Standard", ["Корея, стандартное время", "", "", ""]),
+        ("Kosrae", ["Косрае", "", "", ""]),
+        (
+            "Krasnoyarsk_Standard",
+            ["Красноярск, стандартное время", "", "", ""],
+        ),
+        ("Kyrgyzstan", ["Киргизия", "", "", ""]),
+        ("Line_Islands", ["о-ва Лайн", "", "", ""]),
+        (
+            "Lord_Howe",
+            [
+                "Лорд-Хау, стандартное время",
+                "Лорд-Хау, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Magadan_Standard",
+            ["Магадан, стандартное время", "", "", ""],
+        ),
+        ("Malaysia", ["Малайзия", "", "", ""]),
+        ("Maldives", ["Мальдивы", "", "", ""]),
+        ("Marquesas", ["Маркизские о-ва", "", "", ""]),
+        ("Marshall_Islands", ["Маршалловы Острова", "", "", ""]),
+        (
+            "Mauritius_Standard",
+            ["Маврикий, стандартное время", "", "", ""],
+        ),
+        ("Mawson", ["Моусон", "", "", ""]),
+        (
+            "Mexican_Pacific_Standard",
+            ["Тихоокеанское мексиканское стандартное время", "", "", ""],
+        ),
+        ("Moscow_Standard", ["Москва, стандартное время", "", "", ""]),
+        (
+            "Mountain",
+            [
+                "Стандартное горное время (Северная Америка)",
+                "Летнее горное время (Северная Америка)",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Mountain_Standard",
+            ["Стандартное горное время (Северная Америка)", "", "", ""],
+        ),
+        ("Myanmar", ["Мьянма", "", "", ""]),
+        ("Nauru", ["Науру", "", "", ""]),
+        ("Nepal", ["Непал", "", "", ""]),
+        (
+            "New_Caledonia_Standard",
+            ["Новая Каледония, стандартное время", "", "", ""],
+        ),
+        (
+            "New_Zealand",
+            [
+                "Новая Зеландия, стандартное время",
+                "Новая Зеландия, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Newfoundland",
+            [
+                "Ньюфаундленд, стандартное время",
+                "Ньюфаундленд, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Niue", ["Ниуэ", "", "", ""]),
+        (
+            "Norfolk_Island",
+            [
+                "Норфолк, стандартное время",
+                "Норфолк, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Omsk_Standard", ["Омск, стандартное время", "", "", ""]),
+        (
+            "Pacific",
+            [
+                "Тихоокеанское стандартное время",
+                "Тихоокеанское летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Pakistan_Standard",
+            ["Пакистан, стандартное время", "", "", ""],
+        ),
+        ("Palau", ["Палау", "", "", ""]),
+        ("Papua_New_Guinea", ["Папуа – Новая Гвинея", "", "", ""]),
+        (
+            "Paraguay",
+            [
+                "Парагвай, стандартное время",
+                "Парагвай, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Peru_Standard", ["Перу, стандартное время", "", "", ""]),
+        (
+            "Philippine_Standard",
+            ["Филиппины, стандартное время", "", "", ""],
+        ),
+        ("Phoenix_Islands", ["о-ва Феникс", "", "", ""]),
+        ("Pitcairn", ["Питкэрн", "", "", ""]),
+        ("Pohnpei", ["Понпеи", "", "", ""]),
+        ("R_union", ["Реюньон", "", "", ""]),
+        ("Rothera", ["Ротера", "", "", ""]),
+        (
+            "Samara_Standard",
+            ["Самарское стандартное время", "", "", ""],
+        ),
+        ("Samoa_Standard", ["Апиа, стандартное время", "", "", ""]),
+        ("Seychelles", ["Сейшельские Острова", "", "", ""]),
+        ("Singapore_Standard", ["Сингапур", "", "", ""]),
+        ("Solomon_Islands", ["Соломоновы Острова", "", "", ""]),
+        ("South_Africa_Standard", ["Южная Африка", "", "", ""]),
+        ("South_Georgia", ["Южная Георгия", "", "", ""]),
+        (
+            "St_Pierre_Miquelon",
+            [
+                "Сен-Пьер и Микелон, стандартное время",
+                "Сен-Пьер и Микелон, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Suriname", ["Суринам", "", "", ""]),
+        ("Syowa", ["Сёва", "", "", ""]),
+        (
+            "T_rkiye_Standard",
+            ["Турецкое стандартное время", "", "", ""],
+        ),
+        ("Tahiti", ["Таити", "", "", ""]),
+        (
+            "Taiwan_Standard",
+            ["Тайвань, стандартное время", "", "", ""],
+        ),
+        ("Tajikistan", ["Таджикистан", "", "", ""]),
+        ("Timor_Leste", ["Восточный Тимор", "", "", ""]),
+        ("Tokelau", ["Токелау", "", "", ""]),
+        ("Tonga_Standard", ["Тонга, стандартное время", "", "", ""]),
+        ("Troll_Station", ["Среднее время по Гринвичу", "", "", ""]),
+        (
+            "Turkmenistan_Standard",
+            ["Туркменистан, стандартное время", "", "", ""],
+        ),
+        ("Tuvalu", ["Тувалу", "", "", ""]),
+        (
+            "Ulaanbaatar_Standard",
+            ["Улан-Батор, стандартное время", "", "", ""],
+        ),
+        (
+            "United_Kingdom",
+            [
+                "Среднее время по Гринвичу",
+                "Великобритания, летнее время",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Uruguay_Standard",
+            ["Уругвай, стандартное время", "", "", ""],
+        ),
+        (
+            "Uzbekistan_Standard",
+            ["Узбекистан, стандартное время", "", "", ""],
+        ),
+        (
+            "Vanuatu_Standard",
+            ["Вануату, стандартное время", "", "", ""],
+        ),
+        ("Venezuela", ["Венесуэла", "", "", ""]),
+        (
+            "Vladivostok_Standard",
+            ["Владивосток, стандартное время", "", "", ""],
+        ),
+        ("Vostok", ["Восток", "", "", ""]),
+        ("Wake_Island", ["Уэйк", "", "", ""]),
+        ("Wallis_Futuna", ["Уоллис и Футуна", "", "", ""]),
+        ("West_Africa", ["Западная Африка", "", "", ""]),
+        (
+            "West_Greenland",
+            ["Западная Гренландия, стандартное время", "", "", ""],
+        ),
+        ("West_Kazakhstan", ["Западный Казахстан", "", "", ""]),
+        (
+            "Western_European",
+            [
+                "Западная Европа, стандартное время",
+                "Западная Европа, летнее время",
+                "",
+                "",
+            ],
+        ),
+        ("Western_Indonesia", ["Западная Индонезия", "", "", ""]),
+        (
+            "Yakutsk_Standard",
+            ["Якутск, стандартное время", "", "", ""],
+        ),
+        (
+            "Yekaterinburg_Standard",
+            ["Екатеринбург, стандартное время", "", "", ""],
+        ),
+        ("Yukon", ["Юкон", "", "", ""]),
+    ],
+    units_long: [
+        &[(One, "%d день"), (Few, "%d дня"), (Many, "%d дней")],
+        &[(One, "%d час"), (Few, "%d часа"), (Many, "%d часов")],
+        &[(One, "%d минута"), (Few, "%d минуты"), (Many, "%d минут")],
+        &[
+            (One, "%d секунда"),
+            (Few, "%d секунды"),
+            (Many, "%d секунд"),
+        ],
+    ],
+    units_short: [
+        &[(One, "%d дн."), (Few, "%d дн."), (Many, "%d дн.")],
+        &[(One, "%d ч"), (Few, "%d ч"), (Many, "%d ч")],
+        &[(One, "%d мин"), (Few, "%d мин"), (Many, "%d мин")],
+        &[(One, "%d с"), (Few, "%d с"), (Many, "%d с")],
+    ],
+    units_narrow: [
+        &[(One, "%d д."), (Few, "%d д."), (Many, "%d д.")],
+        &[(One, "%d ч"), (Few, "%d ч"), (Many, "%d ч")],
+        &[(One, "%d мин"), (Few, "%d мин"), (Many, "%d мин")],
+        &[(One, "%d с"), (Few, "%d с"), (Many, "%d с")],
+    ],
+    plural: plural_ru,
+    ordinal_plural: ordinal_ru,
+    ordinals: &[(Other, "%d")],
+    localized_formats: [
+        "H:mm",
+        "H:mm:ss",
+        "DD.MM.YYYY",
+        "D MMMM YYYY г.",
+        "D MMMM YYYY г., H:mm",
+        "dddd, D MMMM YYYY г., H:mm",
+    ],
+    calendar: [
+        "[Сегодня, в] H:mm",
+        "[Завтра, в] H:mm",
+        "dddd, [в] H:mm",
+        "[Вчера, в] H:mm",
+        "[В прошлый] dddd, [в] H:mm",
+        "DD.MM.YYYY",
+    ],
+    relative_time: RelativeTime {
+        future: "через %s",
+        past: "%s назад",
+        units: RelativeUnits {
+            second: "несколько секунд",
+            minute: "минута",
+            minutes: &[(One, "%d минута"), (Few, "%d минуты"), (Many, "%d минут")],
+            hour: "час",
+            hours: &[(One, "%d час"), (Few, "%d часа"), (Many, "%d часов")],
+            day: "день",
+            days: &[(One, "%d день"), (Few, "%d дня"), (Many, "%d дней")],
+            month: "месяц",
+            months: &[(One, "%d месяц"), (Few, "%d месяца"), (Many, "%d месяцев")],
+            year: "год",
+            years: &[(One, "%d год"), (Few, "%d года"), (Many, "%d лет")],
+        },
+        future_units: RelativeUnits {
+            second: "несколько секунд",
+            minute: "минуту",
+            minutes: &[(One, "%d минуту"), (Few, "%d минуты"), (Many, "%d минут")],
+            hour: "час",
+            hours: &[(One, "%d час"), (Few, "%d часа"), (Many, "%d часов")],
+            day: "день",
+            days: &[(One, "%d день"), (Few, "%d дня"), (Many, "%d дней")],
+            month: "месяц",
+            months: &[(One, "%d месяц"), (Few, "%d месяца"), (Many, "%d месяцев")],
+            year: "год",
+            years: &[(One, "%d год"), (Few, "%d года"), (Many, "%d лет")],
+        },
+        past_units: RelativeUnits {
+            second: "несколько секунд",
+            minute: "минуту",
+            minutes: &[(One, "%d минуту"), (Few, "%d минуты"), (Many, "%d минут")],
+            hour: "час",
+            hours: &[(One, "%d час"), (Few, "%d часа"), (Many, "%d часов")],
+            day: "день",
+            days: &[(One, "%d день"), (Few, "%d дня"), (Many, "%d дней")],
+            month: "месяц",
+            months: &[(One, "%d месяц"), (Few, "%d месяца"), (Many, "%d месяцев")],
+            year: "год",
+            years: &[(One, "%d год"), (Few, "%d года"), (Many, "%d лет")],
+        },
+    },
+};
+
+pub static UK: Locale = Locale {
+    name: "uk",
+    months_format: [
+        "січня",
+        "лютого",
+        "березня",
+        "квітня",
+        "травня",
+        "червня",
+        "липня",
+        "серпня",
+        "вересня",
+        "жовтня",
+        "листопада",
+        "грудня",
+    ],
+    months_standalone: [
+        "січень",
+        "лютий",
+        "березень",
+        "квітень",
+        "травень",
+        "червень",
+        "липень",
+        "серпень",
+        "вересень",
+        "жовтень",
+        "листопад",
+        "грудень",
+    ],
+    months_format_before_day: false,
+    months_short: [
+        "січ", "лют", "бер", "квіт", "трав", "черв", "лип", "серп", "вер", "жовт", "лист", "груд",
+    ],
+    weekdays: [
+        "неділя",
+        "понеділок",
+        "вівторок",
+        "середа",
+        "четвер",
+        "пʼятниця",
+        "субота",
+    ],
+    weekdays_short: ["нд", "пн", "вт", "ср", "чт", "пт", "сб"],
+    week_start: Weekday::Mon,
+    year_start: 4,
+    weekdays_relative: [
+        ["минулої неділі", "цієї неділі", "наступної неділі"],
+        [
+            "минулого понеділка",
+            "цього понеділка",
+            "наступного понеділка",
+        ],
+        ["минулого вівторка", "цього вівторка", "наступного вівторка"],
+        ["минулої середи", "цієї середи", "наступної середи"],
+        ["минулого четверга", "цього четверга", "наступного четверга"],
+        ["минулої пʼятниці", "цієї пʼятниці", "наступної пʼятниці"],
+        ["минулої суботи", "цієї суботи", "наступної суботи"],
+    ],
+    meridiem: ["дп", "пп"],
+    date_formats: [
+        "dddd, D MMMM YYYY [р].",
+        "D MMMM YYYY [р].",
+        "D MMM YYYY [р].",
+        "DD.MM.YY",
+    ],
+    time_formats: ["HH:mm:ss zzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    datetime_formats: ["{1} [о] {0}", "{1} [о] {0}", "{1}, {0}", "{1}, {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    utc_names: ["за всесвітнім координованим часом", "UTC"],
+    zone_names: &[
+        ("Acre_Standard", ["час: Акрі, стандартний", "", "", ""]),
+        ("Afghanistan", ["за часом в Афганістані", "", "", ""]),
+        (
+            "Alaska",
+            [
+                "за стандартним часом на Алясці",
+                "за літнім часом на Алясці",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Amazon_Standard",
+            ["за стандартним часом на Амазонці", "", "", ""],
+        ),
+        (
+            "American_Samoa_Standard",
+            ["за стандартним часом на острові Самоа", "", "", ""],
+        ),
+        (
+            "Arabian_Standard",
+            ["за арабським стандартним часом", "", "", ""],
+        ),
+        (
+            "Argentina_Standard",
+            ["за стандартним аргентинським часом", "", "", ""],
+        ),
+        (
+            "Armenia_Standard",
+            ["за вірменським стандартним часом", "", "", ""],
+        ),
+        (
+            "Atlantic",
+            [
+                "за атлантичним стандартним часом",
+                "за атлантичним літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Atlantic_Standard",
+            ["за атлантичним стандартним часом", "", "", ""],
+        ),
+        (
+            "Australian_Central",
+            [
+                "за стандартним центральноавстралійським часом",
+                "за літнім центральноавстралійським часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Central_Standard",
+            ["за стандартним центральноавстралійським часом", "", "", ""],
+        ),
+        (
+            "Australian_Central_Western_Standard",
+            [
+                "за стандартним центральнозахідним австралійським часом",
+                "",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern",
+            [
+                "за стандартним східноавстралійським часом",
+                "за літнім східноавстралійським часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Australian_Eastern_Standard",
+            ["за стандартним східноавстралійським часом", "", "", ""],
+        ),
+        (
+            "Australian_Western_Standard",
+            ["за стандартним західноавстралійським часом", "", "", ""],
+        ),
+        (
+            "Azerbaijan_Standard",
+            ["за стандартним азербайджанським часом", "", "", ""],
+        ),
+        (
+            "Azores",
+            [
+                "за стандартним часом на Азорських островах",
+                "за літнім часом на Азорських островах",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Bangladesh_Standard",
+            ["за стандартним часом у Бангладеш", "", "", ""],
+        ),
+        ("Bhutan", ["за часом у Бутані", "", "", ""]),
+        ("Bolivia", ["за болівійським часом", "", "", ""]),
+        (
+            "Brasilia_Standard",
+            ["за стандартним бразильським часом", "", "", ""],
+        ),
+        ("Brunei", ["за часом у Брунеї", "", "", ""]),
+        (
+            "Cape_Verde_Standard",
+            ["за стандартним часом на островах Кабо-Верде", "", "", ""],
+        ),
+        (
+            "Central",
+            [
+                "за північноамериканським центральним стандартним часом",
+                "за північноамериканським центральним літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Central_Africa",
+            ["за центральноафриканським часом", "", "", ""],
+        ),
+        (
+            "Central_European",
+            [
+                "за центральноєвропейським стандартним часом",
+                "за центральноєвропейським літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Central_European_Standard",
+            ["за центральноєвропейським стандартним часом", "", "", ""],
+        ),
+        (
+            "Central_Indonesia",
+            ["за центральноіндонезійським часом", "", "", ""],
+        ),
+        (
+            "Central_Standard",
+            [
+                "за північноамериканським центральним стандартним часом",
+                "",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Chamorro_Standard",
+            ["за часом на Північних Маріанських островах", "", "", ""],
+        ),
+        (
+            "Chatham",
+            [
+                "за стандартним часом на архіпелазі Чатем",
+                "за літнім часом на архіпелазі Чатем",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Chile",
+            [
+                "за стандартним чилійським часом",
+                "за літнім чилійським часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "China_Standard",
+            ["за китайським стандартним часом", "", "", ""],
+        ),
+        (
+            "Christmas_Island",
+            ["за часом на острові Різдва", "", "", ""],
+        ),
+        ("Chuuk", ["за часом на островах Чуук", "", "", ""]),
+        (
+            "Cocos_Islands",
+            ["за часом на Кокосових островах", "", "", ""],
+        ),
+        (
+            "Colombia_Standard",
+            ["за стандартним колумбійським часом", "", "", ""],
+        ),
+        (
+            "Cook_Islands_Standard",
+            ["за стандартним часом на Островах Кука", "", "", ""],
+        ),
+        (
+            "Cuba",
+            [
+                "за стандартним часом на Кубі",
+                "за літнім часом на Кубі",
+                "",
+                "",
+            ],
+        ),
+        ("Davis", ["за часом на станції Девіс", "", "", ""]),
+        (
+            "Dumont_d_Urville",
+            ["за часом на станції Дюмон дʼЮрвіль", "", "", ""],
+        ),
+        ("East_Africa", ["за східноафриканським часом", "", "", ""]),
+        (
+            "East_Greenland",
+            ["за стандартним східним часом у Ґренландії", "", "", ""],
+        ),
+        (
+            "East_Kazakhstan",
+            [
+                "за часом у Казахстані",
+                "за східним часом у Казахстані",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Easter_Island",
+            [
+                "за стандартним часом на острові Пасхи",
+                "за літнім часом на острові Пасхи",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern",
+            [
+                "за північноамериканським східним стандартним часом",
+                "за північноамериканським східним літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European",
+            [
+                "за східноєвропейським стандартним часом",
+                "за східноєвропейським літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Eastern_European_Standard",
+            ["за східноєвропейським стандартним часом", "", "", ""],
+        ),
+        (
+            "Eastern_Indonesia",
+            ["за східноіндонезійським часом", "", "", ""],
+        ),
+        (
+            "Eastern_Standard",
+            [
+                "за північноамериканським східним стандартним часом",
+                "",
+                "",
+                "",
+            ],
+        ),
+        ("Ecuador", ["за часом в Еквадорі", "", "", ""]),
+        (
+            "Falkland_Islands_Standard",
+            ["за стандартним часом на Фолклендських Островах", "", "", ""],
+        ),
+        (
+            "Fernando_de_Noronha_Standard",
+            [
+                "за стандартним часом на архіпелазі Фернанду-ді-Норонья",
+                "",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Fiji_Standard",
+            ["за стандартним часом у Фіджі", "", "", ""],
+        ),
+        ("French_Guiana", ["за часом Французької Ґвіани", "", "", ""]),
+        (
+            "French_Southern_Antarctic",
+            [
+                "за часом на Французьких Південних і Антарктичних територіях",
+                "",
+                "",
+                "",
+            ],
+        ),
+        ("Galapagos", ["за часом Ґалапаґосу", "", "", ""]),
+        ("Gambier", ["за часом на острові Ґамбʼє", "", "", ""]),
+        (
+            "Georgia_Standard",
+            ["за стандартним грузинським часом", "", "", ""],
+        ),
+        (
+            "Gilbert_Islands",
+            ["за часом на островах Гілберта", "", "", ""],
+        ),
+        ("Greenwich_Mean", ["за Гринвічем", "", "", ""]),
+        ("Guernsey", ["за Гринвічем", "", "", ""]),
+        ("Gulf_Standard", ["за часом Перської затоки", "", "", ""]),
+        ("Guyana", ["за часом у Ґаяні", "", "", ""]),
+        (
+            "Hawaii_Aleutian",
+            [
+                "за стандартним гавайсько-алеутським часом",
+                "за літнім гавайсько-алеутським часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Hawaii_Aleutian_Standard",
+            ["за стандартним гавайсько-алеутським часом", "", "", ""],
+        ),
+        (
+            "Hong_Kong_Standard",
+            ["за стандартним часом у Гонконзі", "", "", ""],
+        ),
+        (
+            "India_Standard",
+            ["за індійським стандартним часом", "", "", ""],
+        ),
+        (
+            "Indian_Ocean",
+            ["за часом в Індійському Океані", "", "", ""],
+        ),
+        ("Indochina", ["за часом в Індокитаї", "", "", ""]),
+        (
+            "Iran_Standard",
+            ["за іранським стандартним часом", "", "", ""],
+        ),
+        (
+            "Ireland",
+            ["за Гринвічем", "за літнім часом в Ірландії", "", ""],
+        ),
+        (
+            "Irkutsk_Standard",
+            ["за іркутським стандартним часом", "", "", ""],
+        ),
+        ("Isle_of_Man", ["за Гринвічем", "", "", ""]),
+        (
+            "Israel",
+            [
+                "за ізраїльським стандартним часом",
+                "за ізраїльським літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Japan_Standard",
+            ["за японським стандартним часом", "", "", ""],
+        ),
+        ("Jersey", ["за Гринвічем", "", "", ""]),
+        (
+            "Kamchatka_Standard",
+            ["за камчатським стандартним часом", "", "", ""],
+        ),
+        (
+            "Khovd_Standard",
+            ["за стандартним часом у Ховді", "", "", ""],
+        ),
+        (
+            "Korean_Standard",
+            ["за корейським стандартним часом", "", "", ""],
+        ),
+        ("Kosrae", ["за часом на острові Косрае", "", "", ""]),
+        (
+            "Krasnoyarsk_Standard",
+            ["за красноярським стандартним часом", "", "", ""],
+        ),
+        ("Kyrgyzstan", ["за часом у Киргизстані", "", "", ""]),
+        ("Line_Islands", ["за часом на острові Лайн", "", "", ""]),
+        (
+            "Lord_Howe",
+            [
+                "за стандартним часом на острові Лорд-Хау",
+                "за літнім часом на острові Лорд-Хау",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Magadan_Standard",
+            ["за магаданським стандартним часом", "", "", ""],
+        ),
+        ("Malaysia", ["за часом у Малайзії", "", "", ""]),
+        ("Maldives", ["за часом на Мальдівах", "", "", ""]),
+        ("Marquesas", ["за часом на Маркізьких островах", "", "", ""]),
+        (
+            "Marshall_Islands",
+            ["за часом на Маршаллових Островах", "", "", ""],
+        ),
+        (
+            "Mauritius_Standard",
+            ["за стандартним часом на острові Маврикій", "", "", ""],
+        ),
+        ("Mawson", ["за часом на станції Моусон", "", "", ""]),
+        (
+            "Mexican_Pacific_Standard",
+            ["за стандартним тихоокеанським часом у Мексиці", "", "", ""],
+        ),
+        (
+            "Moscow_Standard",
+            ["за московським стандартним часом", "", "", ""],
+        ),
+        (
+            "Mountain",
+            [
+                "за північноамериканським гірським стандартним часом",
+                "за північноамериканським гірським літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Mountain_Standard",
+            [
+                "за північноамериканським гірським стандартним часом",
+                "",
+                "",
+                "",
+            ],
+        ),
+        ("Myanmar", ["за часом у Мʼянмі", "", "", ""]),
+        ("Nauru", ["за часом на острові Науру", "", "", ""]),
+        ("Nepal", ["за часом у Непалі", "", "", ""]),
+        (
+            "New_Caledonia_Standard",
+            [
+                "за стандартним часом на островах Нової Каледонії",
+                "",
+                "",
+                "",
+            ],
+        ),
+        (
+            "New_Zealand",
+            [
+                "за стандартним часом у Новій Зеландії",
+                "за літнім часом у Новій Зеландії",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Newfoundland",
+            [
+                "за стандартним часом на острові Ньюфаундленд",
+                "за літнім часом у Ньюфаундленд",
+                "",
+                "",
+            ],
+        ),
+        ("Niue", ["за часом на острові Ніуе", "", "", ""]),
+        (
+            "Norfolk_Island",
+            [
+                "за стандартним часом на острові Норфолк",
+                "за літнім часом на острові Норфолк",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Omsk_Standard",
+            ["за омським стандартним часом", "", "", ""],
+        ),
+        (
+            "Pacific",
+            [
+                "за північноамериканським тихоокеанським стандартним часом",
+                "за північноамериканським тихоокеанським літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Pakistan_Standard",
+            ["за стандартним часом у Пакистані", "", "", ""],
+        ),
+        ("Palau", ["за часом на острові Палау", "", "", ""]),
+        (
+            "Papua_New_Guinea",
+            ["за часом на островах Папуа-Нова Ґвінея", "", "", ""],
+        ),
+        (
+            "Paraguay",
+            [
+                "за стандартним параґвайським часом",
+                "за літнім параґвайським часом",
+                "",
+                "",
+            ],
+        ),
+        ("Peru_Standard", ["за стандартним часом у Перу", "", "", ""]),
+        (
+            "Philippine_Standard",
+            ["за стандартним часом на Філіппінах", "", "", ""],
+        ),
+        (
+            "Phoenix_Islands",
+            ["за часом на островах Фенікс", "", "", ""],
+        ),
+        ("Pitcairn", ["за часом на островах Піткерн", "", "", ""]),
+        ("Pohnpei", ["за часом на острові Понапе", "", "", ""]),
+        ("R_union", ["за часом на острові Реюньйон", "", "", ""]),
+        ("Rothera", ["за часом на станції Ротера", "", "", ""]),
+        (
+            "Samara_Standard",
+            ["за самарським стандартним часом", "", "", ""],
+        ),
+        (
+            "Samoa_Standard",
+            ["за стандартним часом в Апіа", "", "", ""],
+        ),
+        (
+            "Seychelles",
+            ["за часом на Сейшельських Островах", "", "", ""],
+        ),
+        ("Singapore_Standard", ["за часом у Сінгапурі", "", "", ""]),
+        (
+            "Solomon_Islands",
+            ["за часом на Соломонових Островах", "", "", ""],
+        ),
+        (
+            "South_Africa_Standard",
+            ["за південноафриканським часом", "", "", ""],
+        ),
+        (
+            "South_Georgia",
+            ["за часом на острові Південна Джорджія", "", "", ""],
+        ),
+        (
+            "St_Pierre_Miquelon",
+            [
+                "за стандартним часом на островах Сен-П’єр і Мікелон",
+                "за літнім часом на островах Сен-П’єр і Мікелон",
+                "",
+                "",
+            ],
+        ),
+        ("Suriname", ["за часом у Суринамі", "", "", ""]),
+        ("Syowa", ["за часом на станції Сева", "", "", ""]),
+        (
+            "T_rkiye_Standard",
+            ["за турецьким стандартним часом", "", "", ""],
+        ),
+        ("Tahiti", ["за часом на острові Таїті", "", "", ""]),
+        (
+            "Taiwan_Standard",
+            ["за стандартним часом у Тайбеї", "", "", ""],
+        ),
+        ("Tajikistan", ["за часом у Таджикистані", "", "", ""]),
+        ("Timor_Leste", ["за часом у Східному Тиморі", "", "", ""]),
+        ("Tokelau", ["за часом на островах Токелау", "", "", ""]),
+        (
+            "Tonga_Standard",
+            ["за стандартним часом на островах Тонга", "", "", ""],
+        ),
+        ("Troll_Station", ["за Гринвічем", "", "", ""]),
+        (
+            "Turkmenistan_Standard",
+            ["за стандартним часом у Туркменістані", "", "", ""],
+        ),
+        ("Tuvalu", ["за часом на островах Тувалу", "", "", ""]),
+        (
+            "Ulaanbaatar_Standard",
+            ["за стандартним часом в Улан-Баторі", "", "", ""],
+        ),
+        (
+            "United_Kingdom",
+            ["за Гринвічем", "за літнім часом у Великій Британії", "", ""],
+        ),
+        (
+            "Uruguay_Standard",
+            ["за стандартним часом в Уруґваї", "", "", ""],
+        ),
+        (
+            "Uzbekistan_Standard",
+            ["за стандартним часом в Узбекистані", "", "", ""],
+        ),
+        (
+            "Vanuatu_Standard",
+            ["за стандартним часом на островах Вануату", "", "", ""],
+        ),
+        ("Venezuela", ["за часом у Венесуелі", "", "", ""]),
+        (
+            "Vladivostok_Standard",
+            ["за владивостоцьким стандартним часом", "", "", ""],
+        ),
+        ("Vostok", ["за часом на станції Восток", "", "", ""]),
+        ("Wake_Island", ["за часом на острові Вейк", "", "", ""]),
+        (
+            "Wallis_Futuna",
+            ["за часом на островах Уолліс і Футуна", "", "", ""],
+        ),
+        ("West_Africa", ["за західноафриканським часом", "", "", ""]),
+        (
+            "West_Greenland",
+            ["за стандартним західним часом у Ґренландії", "", "", ""],
+        ),
+        (
+            "West_Kazakhstan",
+            ["за західним часом у Казахстані", "", "", ""],
+        ),
+        (
+            "Western_European",
+            [
+                "за західноєвропейським стандартним часом",
+                "за західноєвропейським літнім часом",
+                "",
+                "",
+            ],
+        ),
+        (
+            "Western_Indonesia",
+            ["за західноіндонезійським часом", "", "", ""],
+        ),
+        (
+            "Yakutsk_Standard",
+            ["за якутським стандартним часом", "", "", ""],
+        ),
+        (
+            "Yekaterinburg_Standard",
+            ["за єкатеринбурзьким стандартним часом", "", "", ""],
+        ),
+        ("Yukon", ["за стандартним часом на Юконі", "", "", ""]),
+    ],
+    units_long: [
+        &[(One, "%d день"), (Few, "%d дні"), (Many, "%d днів")],
+        &[(One, "%d година"), (Few, "%d години"), (Many, "%d годин")],
+        &[
+            (One, "%d хвилина"),
+            (Few, "%d хвилини"),
+            (Many, "%d хвилин"),
+        ],
+        &[
+            (One, "%d секунда"),
+            (Few, "%d секунди"),
+            (Many, "%d секунд"),
+        ],
+    ],
+    units_short: [
+        &[(One, "%d дн."), (Few, "%d дн."), (Many, "%d дн.")],
+        &[(One, "%d год"), (Few, "%d год"), (Many, "%d год")],
+        &[(One, "%d хв"), (Few, "%d хв"), (Many, "%d хв")],
+        &[(One, "%d с"), (Few, "%d с"), (Many, "%d с")],
+    ],
+    units_narrow: [
+        &[(One, "%dд"), (Few, "%dд"), (Many, "%dд")],
+        &[(One, "%dг"), (Few, "%dг"), (Many, "%dг")],
+        &[(One, "%dх"), (Few, "%dх"), (Many, "%dх")],
+        &[(One, "%dс"), (Few, "%dс"), (Many, "%dс")],
+    ],
+    plural: plural_uk,
+    ordinal_plural: ordinal_uk,
+    ordinals: &[(Other, "%d")],
+    localized_formats: [
+        "HH:mm",
+        "HH:mm:ss",
+        "DD.MM.YYYY",
+        "D MMMM YYYY р.",
+        "D MMMM YYYY р., HH:mm",
+        "dddd, D MMMM YYYY р., HH:mm",
+    ],
+    calendar: [
+        "[Сьогодні о] HH:mm",
+        "[Завтра о] HH:mm",
+        "dddd [о] HH:mm",
+        "[Вчора о] HH:mm",
+        "[Минулого] dddd [о] HH:mm",
+        "DD.MM.YYYY",
+    ],
+    relative_time: RelativeTime {
+        future: "за %s",
+        past: "%s тому",
+        units: RelativeUnits {
+            second: "декілька секунд",
+            minute: "хвилина",
+            minutes: &[
+                (One, "%d хвилина"),
+                (Few, "%d хвилини"),
+                (Many, "%d хвилин"),
+            ],
+            hour: "година",
+            hours: &[(One, "%d година"), (Few, "%d години"), (Many, "%d годин")],
+            day: "день",
+            days: &[(One, "%d день"), (Few, "%d дні"), (Many, "%d днів")],
+            month: "місяць",
+            months: &[(One, "%d місяць"), (Few, "%d місяці"), (Many, "%d місяців")],
+            year: "рік",
+            years: &[(One, "%d рік"), (Few, "%d роки"), (Many, "%d років")],
+        },
+        future_units: RelativeUnits {
+            second: "декілька секунд",
+            minute: "хвилину",
+            minutes: &[
+                (One, "%d хвилину"),
+                (Few, "%d хвилини"),
+                (Many, "%d хвилин"),
+            ],
+            hour: "годину",
+            hours: &[(One, "%d годину"), (Few, "%d години"), (Many, "%d годин")],
+            day: "день",
+            days: &[(One, "%d день"), (Few, "%d дні"), (Many, "%d днів")],
+            month: "місяць",
+            months: &[(One, "%d місяць"), (Few, "%d місяці"), (Many, "%d місяців")],
+            year: "рік",
+            years: &[(One, "%d рік"), (Few, "%d роки"), (Many, "%d років")],
+        },
+        past_units: RelativeUnits {
+            second: "декілька секунд",
+            minute: "хвилину",
+            minutes: &[
+                (One, "%d хвилину"),
+                (Few, "%d хвилини"),
+                (Many, "%d хвилин"),
+            ],
+            hour: "годину",
+            hours: &[(One, "%d годину"), (Few, "%d години"), (Many, "%d годин")],
+            day: "день",
+            days: &[(One, "%d день"), (Few, "%d дні"), (Many, "%d днів")],
+            month: "місяць",
+            months: &[(One, "%d місяць"), (Few, "%d місяці"), (Many, "%d місяців")],
+            year: "рік",
+            years: &[(One, "%d рік"), (Few, "%d роки"), (Many, "%d років")],
+        },
+    },
+};
+
+pub static ZH_CN: Locale = Locale {
+    name: "zh-cn",
+    months_format: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    months_standalone: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    months_format_before_day: false,
+    months_short: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "星期日",
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+    ],
+    weekdays_short: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+    week_start: Weekday::Mon,
+    year_start: 4,
+    weekdays_relative: [
+        ["上周日", "本周日", "下周日"],
+        ["上周一", "本周一", "下周一"],
+        ["上周二", "本周二", "下周二"],
+        ["上周三", "本周三", "下周三"],
+        ["上周四", "本周四", "下周四"],
+        ["上周五", "本周五", "下周五"],
+        ["上周六", "本周六", "下周六"],
+    ],
+    meridiem: ["上午", "下午"],
+    date_formats: [
+        "YYYY年M月D日dddd",
+        "YYYY年M月D日",
+        "YYYY年M月D日",
+        "YYYY/M/D",
+    ],
+    time_formats: ["zzz HH:mm:ss", "z HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    datetime_formats: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    gmt_format: "GMT{0}",
+    gmt_zero_format: "GMT",
+    utc_names: ["协调世界时", "UTC"],
+    zone_names: &[
+        ("Acre_Standard", ["阿克里标准时间", "", "", ""]),
+        ("Afghanistan", ["阿富汗时间", "", "", ""]),
+        ("Alaska", ["阿拉斯加标准时间", "阿拉斯加夏令时间", "", ""]),
+        ("Amazon_Standard", ["亚马逊标准时间", "", "", ""]),
+        ("American_Samoa_Standard", ["萨摩亚标准时间", "", "", ""]),
+        ("Arabian_Standard", ["阿拉伯标准时间", "", "", ""]),
+        ("Argentina_Standard", ["阿根廷标准时间", "", "", ""]),
+        ("Armenia_Standard", ["亚美尼亚标准时间", "", "", ""]),
+        ("Atlantic", ["大西洋标准时间", "大西洋夏令时间", "", ""]),
+        ("Atlantic_Standard", ["大西洋标准时间", "", "", ""]),
+        (
+            "Australian_Central",
+            ["澳大利亚中部标准时间", "澳大利亚中部夏令时间", "", ""],
+        ),
+        (
+            "Australian_Central_Standard",
+            ["澳大利亚中部标准时间", "", "", ""],
+        ),
+        (
+            "Australian_Central_Western_Standard",
+            ["澳大利亚中西部标准时间", "", "", ""],
+        ),
+        (
+            "Australian_Eastern",
+            ["澳大利亚东部标准时间", "澳大利亚东部夏令时间", "", ""],
+        ),
+        (
+            "Australian_Eastern_Standard",
+            ["澳大利亚东部标准时间", "", "", ""],
+        ),
+        (
+            "Australian_Western_Standard",
+            ["澳大利亚西部标准时间", "", "", ""],
+        ),
+        ("Azerbaijan_Standard", ["阿塞拜疆标准时间", "", "", ""]),
+        (
+            "Azores",
+            ["亚速尔群岛标准时间", "亚速尔群岛夏令时间", "", ""],
+        ),
+        ("Bangladesh_Standard", ["孟加拉标准时间", "", "", ""]),
+        ("Bhutan", ["不丹时间", "", "", ""]),
+        ("Bolivia", ["玻利维亚标准时间", "", "", ""]),
+        ("Brasilia_Standard", ["巴西利亚标准时间", "", "", ""]),
+        ("Brunei", ["文莱达鲁萨兰时间", "", "", ""]),
+        ("Cape_Verde_Standard", ["佛得角标准时间", "", "", ""]),
+        ("Central", ["北美中部标准时间", "北美中部夏令时间", "", ""]),
+        ("Central_Africa", ["中部非洲时间", "", "", ""]),
+        ("Central_European", ["中欧标准时间", "中欧夏令时间", "", ""]),
+        ("Central_European_Standard", ["中欧标准时间", "", "", ""]),
+        ("Central_Indonesia", ["印度尼西亚中部时间", "", "", ""]),
+        ("Central_Standard", ["北美中部标准时间", "", "", ""]),
+        ("Chamorro_Standard", ["查莫罗时间", "", "", ""]),
+        ("Chatham", ["查塔姆标准时间", "查塔姆夏令时间", "", ""]),
+        ("Chile", ["智利标准时间", "智利夏令时间", "", ""]),
+        ("China_Standard", ["中国标准时间", "", "", ""]),
+        ("Christmas_Island", ["圣诞岛时间", "", "", ""]),
+        ("Chuuk", ["楚克时间", "", "", ""]),
+        ("Cocos_Islands", ["科科斯群岛时间", "", "", ""]),
+        ("Colombia_Standard", ["哥伦比亚标准时间", "", "", ""]),
+        ("Cook_Islands_Standard", ["库克群岛标准时间", "", "", ""]),
+        ("Cuba", ["古巴标准时间", "古巴夏令时间", "", ""]),
+        ("Davis", ["戴维斯时间", "", "", ""]),
+        ("Dumont_d_Urville", ["迪蒙·迪维尔时间", "", "", ""]),
+        ("East_Africa", ["东部非洲时间", "", "", ""]),
+        ("East_Greenland", ["格陵兰岛东部标准时间", "", "", ""]),
+        (
+            "East_Kazakhstan",
+            ["哈萨克斯坦时间", "哈萨克斯坦东部时间", "", ""],
+        ),
+        (
+            "Easter_Island",
+            ["复活节岛标准时间", "复活节岛夏令时间", "", ""],
+        ),
+        ("Eastern", ["北美东部标准时间", "北美东部夏令时间", "", ""]),
+        ("Eastern_European", ["东欧标准时间", "东欧夏令时间", "", ""]),
+        ("Eastern_European_Standard", ["东欧标准时间", "", "", ""]),
+        ("Eastern_Indonesia", ["印度尼西亚东部时间", "", "", ""]),
+        ("Eastern_Standard", ["北美东部标准时间", "", "", ""]),
+        ("Ecuador", ["厄瓜多尔标准时间", "", "", ""]),
+        (
+            "Falkland_Islands_Standard",
+            ["福克兰群岛标准时间", "", "", ""],
+        ),
+        (
+            "Fernando_de_Noronha_Standard",
+            ["费尔南多-迪诺罗尼亚岛标准时间", "", "", ""],
+        ),
+        ("Fiji_Standard", ["斐济标准时间", "", "", ""]),
+        ("French_Guiana", ["法属圭亚那标准时间", "", "", ""]),
+        (
+            "French_Southern_Antarctic",
+            ["法属南方和南极领地时间", "", "", ""],
+        ),
+        ("Galapagos", ["科隆群岛时间", "", "", ""]),
+        ("Gambier", ["甘比尔时间", "", "", ""]),
+        ("Georgia_Standard", ["格鲁吉亚标准时间", "", "", ""]),
+        ("Gilbert_Islands", ["吉尔伯特群岛时间", "", "", ""]),
+        ("Greenwich_Mean", ["格林尼治标准时间", "", "", ""]),
+        ("Guernsey", ["格林尼治标准时间", "", "", ""]),
+        ("Gulf_Standard", ["海湾标准时间", "", "", ""]),
+        ("Guyana", ["圭亚那时间", "", "", ""]),
+        (
+            "Hawaii_Aleutian",
+            ["夏威夷-阿留申标准时间", "夏威夷-阿留申夏令时间", "", ""],
+        ),
+        (
+            "Hawaii_Aleutian_Standard",
+            ["夏威夷-阿留申标准时间", "", "", ""],
+        ),
+        ("Hong_Kong_Standard", ["香港标准时间", "", "", ""]),
+        ("India_Standard", ["印度时间", "", "", ""]),
+        ("Indian_Ocean", ["印度洋时间", "", "", ""]),
+        ("Indochina", ["中南半岛时间", "", "", ""]),
+        ("Iran_Standard", ["伊朗标准时间", "", "", ""]),
+        ("Ireland", ["格林尼治标准时间", "爱尔兰标准时间", "", ""]),
+        ("Irkutsk_Standard", ["伊尔库茨克标准时间", "", "", ""]),
+        ("Isle_of_Man", ["格林尼治标准时间", "", "", ""]),
+        ("Israel", ["以色列标准时间", "以色列夏令时间", "", ""]),
+        ("Japan_Standard", ["日本标准时间", "", "", ""]),
+        ("Jersey", ["格林尼治标准时间", "", "", ""]),
+        (
+            "Kamchatka_Standard",
+            ["彼得罗巴甫洛夫斯克-堪察加标准时间", "", "", ""],
+        ),
+        ("Khovd_Standard", ["科布多标准时间", "", "", ""]),
+        ("Korean_Standard", ["韩国标准时间", "", "", ""]),
+        ("Kosrae", ["科斯雷时间", "", "", ""]),
+        (
+            "Krasnoyarsk_Standard",
+            ["克拉斯诺亚尔斯克标准时间", "", "", ""],
+        ),
+        ("Kyrgyzstan", ["吉尔吉斯斯坦时间", "", "", ""]),
+        ("Line_Islands", ["莱恩群岛时间", "", "", ""]),
+        (
+            "Lord_Howe",
+            ["豪勋爵岛标准时间", "豪勋爵岛夏令时间", "", ""],
+        ),
+        ("Magadan_Standard", ["马加丹标准时间", "", "", ""]),
+        ("Malaysia", ["马来西亚时间", "", "", ""]),
+        ("Maldives", ["马尔代夫时间", "", "", ""]),
+        ("Marquesas", ["马克萨斯群岛时间", "", "", ""]),
+        ("Marshall_Islands", ["马绍尔群岛时间", "", "", ""]),
+        ("Mauritius_Standard", ["毛里求斯标准时间", "", "", ""]),
+        ("Mawson", ["莫森时间", "", "", ""]),
+        (
+            "Mexican_Pacific_Standard",
+            ["墨西哥太平洋标准时间", "", "", ""],
+        ),
+        ("Moscow_Standard", ["莫斯科标准时间", "", "", ""]),
+        ("Mountain", ["北美山区标准时间", "北美山区夏令时间", "", ""]),
+        ("Mountain_Standard", ["北美山区标准时间", "", "", ""]),
+        ("Myanmar", ["缅甸时间", "", "", ""]),
+        ("Nauru", ["瑙鲁时间", "", "", ""]),
+        ("Nepal", ["尼泊尔时间", "", "", ""]),
+        (
+            "New_Caledonia_Standard",
+            ["新喀里多尼亚标准时间", "", "", ""],
+        ),
+        ("New_Zealand", ["新西兰标准时间", "新西兰夏令时间", "", ""]),
+        ("Newfoundland", ["纽芬兰标准时间", "纽芬兰夏令时间", "", ""]),
+        ("Niue", ["纽埃时间", "", "", ""]),
+        (
+            "Norfolk_Island",
+            ["诺福克岛标准时间", "诺福克岛夏令时间", "", ""],
+        ),
+        ("Omsk_Standard", ["鄂木斯克标准时间", "", "", ""]),
+        (
+            "Pacific",
+            ["北美太平洋标准时间", "北美太平洋夏令时间", "", ""],
+        ),
+        ("Pakistan_Standard", ["巴基斯坦标准时间", "", "", ""]),
+        ("Palau", ["帕劳时间", "", "", ""]),
+        ("Papua_New_Guinea", ["巴布亚新几内亚时间", "", "", ""]),
+        ("Paraguay", ["巴拉圭标准时间", "巴拉圭夏令时间", "", ""]),
+        ("Peru_Standard", ["秘鲁标准时间", "", "", ""]),
+        ("Philippine_Standard", ["菲律宾标准时间", "", "", ""]),
+        ("Phoenix_Islands", ["菲尼克斯群岛时间", "", "", ""]),
+        ("Pitcairn", ["皮特凯恩时间", "", "", ""]),
+        ("Pohnpei", ["波纳佩时间", "", "", ""]),
+        ("R_union", ["留尼汪时间", "", "", ""]),
+        ("Rothera", ["罗瑟拉时间", "", "", ""]),
+        ("Samara_Standard", ["萨马拉标准时间", "", "", ""]),
+        ("Samoa_Standard", ["阿皮亚标准时间", "", "", ""]),
+        ("Seychelles", ["塞舌尔时间", "", "", ""]),
+        ("Singapore_Standard", ["新加坡标准时间", "", "", ""]),
+        ("Solomon_Islands", ["所罗门群岛时间", "", "", ""]),
+        ("South_Africa_Standard", ["南非标准时间", "", "", ""]),
+        ("South_Georgia", ["南乔治亚岛时间", "", "", ""]),
+        (
+            "St_Pierre_Miquelon",
+            [
+                "圣皮埃尔和密克隆群岛标准时间",
+                "圣皮埃尔和密克隆群岛夏令时间",
+                "",
+                "",
+            ],
+        ),
+        ("Suriname", ["苏里南时间", "", "", ""]),
+        ("Syowa", ["昭和时间", "", "", ""]),
+        ("T_rkiye_Standard", ["土耳其标准时间", "", "", ""]),
+        ("Tahiti", ["塔希提岛时间", "", "", ""]),
+        ("Taiwan_Standard", ["台北标准时间", "", "", ""]),
+        ("Tajikistan", ["塔吉克斯坦时间", "", "", ""]),
+        ("Timor_Leste", ["东帝汶时间", "", "", ""]),
+        ("Tokelau", ["托克劳时间", "", "", ""]),
+        ("Tonga_Standard", ["汤加标准时间", "", "", ""]),
+        ("Troll_Station", ["格林尼治标准时间", "", "", ""]),
+        ("Turkmenistan_Standard", ["土库曼斯坦标准时间", "", "", ""]),
+        ("Tuvalu", ["图瓦卢时间", "", "", ""]),
+        ("Ulaanbaatar_Standard", ["乌兰巴托标准时间", "", "", ""]),
+        (
+            "United_Kingdom",
+            ["格林尼治标准时间", "英国夏令时间", "", ""],
+        ),
+        ("Uruguay_Standard", ["乌拉圭标准时间", "", "", ""]),
+        ("Uzbekistan_Standard", ["乌兹别克斯坦标准时间", "", "", ""]),
+        ("Vanuatu_Standard", ["瓦努阿图标准时间", "", "", ""]),
+        ("Venezuela", ["委内瑞拉时间", "", "", ""]),
+        ("Vladivostok_Standard", ["海参崴标准时间", "", "", ""]),
+        ("Vostok", ["沃斯托克时间", "", "", ""]),
+        ("Wake_Island", ["威克岛时间", "", "", ""]),
+        ("Wallis_Futuna", ["瓦利斯和富图纳时间", "", "", ""]),
+        ("West_Africa", ["西部非洲时间", "", "", ""]),
+        ("West_Greenland", ["格陵兰岛西部标准时间", "", "", ""]),
+        ("West_Kazakhstan", ["哈萨克斯坦西部时间", "", "", ""]),
+        ("Western_European", ["西欧标准时间", "西欧夏令时间", "", ""]),
+        ("Western_Indonesia", ["印度尼西亚西部时间", "", "", ""]),
+        ("Yakutsk_Standard", ["雅库茨克标准时间", "", "", ""]),
+        ("Yekaterinburg_Standard", ["叶卡捷琳堡标准时间", "", "", ""]),
+        ("Yukon", ["育空时间", "", "", ""]),
+    ],
+    units_long: [
+        &[(Other, "%d天")],
+        &[(Other, "%d小时")],
+        &[(Other, "%d分钟")],
+        &[(Other, "%d秒钟")],
+    ],
+    units_short: [
+        &[(Other, "%d天")],
+        &[(Other, "%d小时")],
+        &[(Other, "%d分钟")],
+        &[(Other, "%d秒")],
+    ],
+    units_narrow: [
+        &[(Other, "%d天")],
+        &[(Other, "%d小时")],
+        &[(Other, "%d分钟")],
+        &[(Other, "%d秒")],
+    ],
+    plural: plural_zh_cn,
+    ordinal_plural: ordinal_zh_cn,
+    ordinals: &[(Other, "%d日")],
+    localized_formats: [
+        "HH:mm",
+        "HH:mm:ss",
+        "YYYY/MM/DD",
+        "YYYY年M月D日",
+        "YYYY年M月D日Ah点mm分",
+        "YYYY年M月D日ddddAh点mm分",
+    ],
+    calendar: [
+        "[今天]HH:mm",
+        "[明天]HH:mm",
+        "[下]dddHH:mm",
+        "[昨天]HH:mm",
+        "[上]dddHH:mm",
+        "YYYY/MM/DD",
+    ],
+    relative_time: RelativeTime {
+        future: "%s内",
+        past: "%s前",
+        units: RelativeUnits {
+            second: "几秒",
+            minute: "1 分钟",
+            minutes: &[(Other, "%d 分钟")],
+            hour: "1 小时",
+            hours: &[(Other, "%d 小时")],
+            day: "1 天",
+            days: &[(Other, "%d 天")],
+            month: "1 个月",
+            months: &[(Other, "%d 个月")],
+            year: "1 年",
+            years: &[(Other, "%d 年")],
+        },
+        future_units: RelativeUnits {
+            second: "几秒",
+            minute: "1 分钟",
+            minutes: &[(Other, "%d 分钟")],
+            hour: "1 小时",
+            hours: &[(Other, "%d 小时")],
+            day: "1 天",
+            days: &[(Other, "%d 天")],
+            month: "1 个月",
+            months: &[(Other, "%d 个月")],
+            year: "1 年",
+            years: &[(Other, "%d 年")],
+        },
+        past_units: RelativeUnits {
+            second: "几秒",
+            minute: "1 分钟",
+            minutes: &[(Other, "%d 分钟")],
+            hour: "1 小时",
+            hours: &[(Other, "%d 小时")],
+            day: "1 天",
+            days: &[(Other, "%d 天")],
+            month: "1 个月",
+            months: &[(Other, "%d 个月")],
+            year: "1 年",
+            years: &[(Other, "%d 年")],
+        },
+    },
+};
+
+fn plural_en(n: u64) -> PluralCategory {
+    if n == 1 {
+        return One;
+    }
+    Other
+}
+
+fn ordinal_en(n: u64) -> PluralCategory {
+    if n % 10 == 1 && n % 100 != 11 {
+        return One;
+    }
+    if n % 10 == 2 && n % 100 != 12 {
+        return Two;
+    }
+    if n % 10 == 3 && n % 100 != 13 {
+        return Few;
+    }
+    Other
+}
+
+fn plural_lt(n: u64) -> PluralCategory {
+    if n % 10 == 1 && !matches!(n % 100, 11..=19) {
+        return One;
+    }
+    if matches!(n % 10, 2..=9) && !matches!(n % 100, 11..=19) {
+        return Few;
+    }
+    Other
+}
+
+fn ordinal_lt(_n: u64) -> PluralCategory {
+    Other
+}
+
+fn plural_pl(n: u64) -> PluralCategory {
+    if n == 1 {
+        return One;
+    }
+    if matches!(n % 10, 2..=4) && !matches!(n % 100, 12..=14) {
+        return Few;
+    }
+    if (n != 1 && matches!(n % 10, 0..=1)) || matches!(n % 10, 5..=9) || matches!(n % 100, 12..=14)
+    {
+        return Many;
+    }
+    Other
+}
+
+fn ordinal_pl(_n: u64) -> PluralCategory {
+    Other
+}
+
+fn plural_ru(n: u64) -> PluralCategory {
+    if n % 10 == 1 && n % 100 != 11 {
+        return One;
+    }
+    if matches!(n % 10, 2..=4) && !matches!(n % 100, 12..=14) {
+        return Few;
+    }
+    if n.is_multiple_of(10) || matches!(n % 10, 5..=9) || matches!(n % 100, 11..=14) {
+        return Many;
+    }
+    Other
+}
+
+fn ordinal_ru(_n: u64) -> PluralCategory {
+    Other
+}
+
+fn plural_uk(n: u64) -> PluralCategory {
+    if n % 10 == 1 && n % 100 != 11 {
+        return One;
+    }
+    if matches!(n % 10, 2..=4) && !matches!(n % 100, 12..=14) {
+        return Few;
+    }
+    if n.is_multiple_of(10) || matches!(n % 10, 5..=9) || matches!(n % 100, 11..=14) {
+        return Many;
+    }
+    Other
+}
+
+fn ordinal_uk(n: u64) -> PluralCategory {
+    if n % 10 == 3 && n % 100 != 13 {
+        return Few;
+    }
+    Other
+}
+
+fn plural_zh_cn(_n: u64) -> PluralCategory {
+    Other
+}
+
+fn ordinal_zh_cn(_n: u64) -> PluralCategory {
+    Other
+}
@@ -0,0 +1,494 @@
+use crate::warning::warn;
+use crate::{Dayjs, DayjsTimeZone, Locale, Unit, Warning};
+use chrono::{DateTime, Datelike, Duration, DurationRound, FixedOffset, Months, Utc};
+use chrono::{Days, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+impl Dayjs {
+    /// wall clock time in the instance timezone, with its offset at this instant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// let mut date = dayjs::from_timestamp(1720009800);
+    /// date.tz = dayjs::DayjsTimeZone::city("America/New_York").unwrap();
+    /// assert_eq!(date.to_local().to_rfc3339(), "2024-07-03T08:30:00-04:00");
+    /// # }
+    /// ```
+    pub fn to_local(&self) -> DateTime<FixedOffset> {
+        self.datetime()
+    }
+
+    /// start of a time unit in the instance timezone, e.g. `start_of("day")` is local midnight
+    ///
+    /// weeks start on the active locale's first day, so they change with the global
+    /// [`crate::locale()`]; unknown units leave the instance unchanged with a [`Warning`], see
+    /// [`Dayjs::start_of_unit`] for a checked unit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.start_of("month").to_string(), "2024-01-01T00:00:00+00:00");
+    /// assert_eq!(date.start_of("week").to_string(), "2023-12-31T00:00:00+00:00");
+    /// assert_eq!(date.start_of("hour").to_string(), "2024-01-05T08:00:00+00:00");
+    /// ```
+    pub fn start_of(&self, unit: &str) -> Dayjs {
+        match unit.parse::<Unit>() {
+            Ok(unit) => self.start_of_unit(unit),
+            Err(_) => {
+                warn(|| Warning::UnknownUnit(unit.to_string()));
+                *self
+            }
+        }
+    }
+
+    /// end of a time unit in the instance timezone, the last millisecond before the next one
+    ///
+    /// like [`Dayjs::start_of`], weeks follow the global locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// assert_eq!(date.end_of("year").to_string(), "2024-12-31T23:59:59.999+00:00");
+    /// assert_eq!(date.end_of("day").to_string(), "2024-01-05T23:59:59.999+00:00");
+    /// ```
+    pub fn end_of(&self, unit: &str) -> Dayjs {
+        match unit.parse::<Unit>() {
+            Ok(unit) => self.end_of_unit(unit),
+            Err(_) => {
+                warn(|| Warning::UnknownUnit(unit.to_string()));
+                *self
+            }
+        }
+    }
+
+    /// start of a time unit in another timezone, which the result carries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTimeZone, Unit};
+    /// let date = dayjs::from_timestamp(1704482215);
+    /// let ist = DayjsTimeZone::Offset(5 * 3600 + 1800);
+    /// let start = date.start_of_in(Unit::Day, &ist);
+    /// assert_eq!(start.to_string(), "2024-01-05T18:30:00+00:00");
+    /// assert_eq!(start.tz, ist);
+    /// ```
+    pub fn start_of_in(&self, unit: Unit, tz: &DayjsTimeZone) -> Dayjs {
+        Dayjs { tz: *tz, ..*self }.start_of_unit(unit)
+    }
+
+    /// end of a time unit in another timezone, which the result carries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTimeZone, Unit};
+    /// let date = dayjs::from_timestamp(1704482215);
+    /// let end = date.end_of_in(Unit::Day, &DayjsTimeZone::Offset(5 * 3600 + 1800));
+    /// assert_eq!(end.to_string(), "2024-01-06T18:29:59.999+00:00");
+    /// assert_eq!(end.format("YYYY-MM-DD HH:mm:ss"), "2024-01-06 23:59:59");
+    /// ```
+    pub fn end_of_in(&self, unit: Unit, tz: &DayjsTimeZone) -> Dayjs {
+        Dayjs { tz: *tz, ..*self }.end_of_unit(unit)
+    }
+
+    /// add an amount of a unit, returning a new instance so calls can be chained
+    ///
+    /// days, weeks, months and years move the wall clock in the instance timezone, months clamp
+    /// to the end of shorter months; smaller units add elapsed time. amounts that leave the
+    /// supported range leave the instance unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1706704215);
+    /// let next = date.add(1, Unit::Month).start_of("day");
+    /// assert_eq!(next.to_string(), "2024-02-29T00:00:00+00:00");
+    /// assert_eq!(date.add(90, Unit::Minute).format("HH:mm"), "14:00");
+    /// ```
+    pub fn add(&self, value: i64, unit: Unit) -> Dayjs {
+        let local = self.datetime().naive_local();
+        let time = match unit {
+            Unit::Day | Unit::Week | Unit::Isoweek => scaled(unit, value)
+                .and_then(|delta| local.checked_add_signed(delta))
+                .map_or(self.time, |local| self.tz.local_to_utc(local)),
+            Unit::Month | Unit::Quarter | Unit::Year => {
+                let months = value.saturating_mul(months_in(unit));
+                let moved = match u32::try_from(months.unsigned_abs()).map(Months::new) {
+                    Ok(m) if months >= 0 => local.checked_add_months(m),
+                    Ok(m) => local.checked_sub_months(m),
+                    Err(_) => None,
+                };
+                moved.map_or(self.time, |local| self.tz.local_to_utc(local))
+            }
+            _ => scaled(unit, value)
+                .and_then(|delta| self.time.checked_add_signed(delta))
+                .unwrap_or(self.time),
+        };
+        Dayjs { time, ..*self }
+    }
+
+    /// subtract an amount of a unit, see [`Dayjs::add`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1709214615);
+    /// assert_eq!(date.subtract(1, Unit::Year).format("YYYY-MM-DD"), "2023-02-28");
+    /// ```
+    pub fn subtract(&self, value: i64, unit: Unit) -> Dayjs {
+        self.add(-value, unit)
+    }
+
+    /// add calendar days, keeping the wall clock in the instance timezone so a day across a DST
+    /// change lasts 23 or 25 hours
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// use dayjs::DayjsTimeZone;
+    /// // 2024-03-09T12:00:00 in New York, clocks spring forward the next night
+    /// let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    /// let date = dayjs::from_timestamp(1710003600).tz(new_york);
+    /// let next = date.add_days(1);
+    /// assert_eq!(next.format("YYYY-MM-DD HH:mm Z"), "2024-03-10 12:00 -04:00");
+    /// assert_eq!((next - date).num_hours(), 23);
+    /// # }
+    /// ```
+    pub fn add_days(&self, days: i64) -> Dayjs {
+        self.add(days, Unit::Day)
+    }
+
+    /// add calendar months, the day is clamped to the end of a shorter month like dayjs,
+    /// negative values go back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1706704215);
+    /// assert_eq!(date.add_months(1).format("YYYY-MM-DD"), "2024-02-29");
+    /// assert_eq!(date.add_months(-2).format("YYYY-MM-DD"), "2023-11-30");
+    /// assert_eq!(date.add_months(13).format("YYYY-MM-DD"), "2025-02-28");
+    /// ```
+    pub fn add_months(&self, months: i64) -> Dayjs {
+        self.add(months, Unit::Month)
+    }
+
+    /// add calendar years, february 29 becomes february 28 in a common year
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1709214615);
+    /// assert_eq!(date.add_years(1).format("YYYY-MM-DD"), "2025-02-28");
+    /// assert_eq!(date.add_years(-4).format("YYYY-MM-DD"), "2020-02-29");
+    /// ```
+    pub fn add_years(&self, years: i64) -> Dayjs {
+        self.add(years, Unit::Year)
+    }
+
+    /// start of a time unit in the instance timezone, [`Unit::Week`] starts on the active
+    /// locale's first day, so it changes with the global [`crate::locale()`], and
+    /// [`Unit::Isoweek`] on monday
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1715243415);
+    /// assert_eq!(date.start_of_unit(Unit::Quarter).format("YYYY-MM-DD"), "2024-04-01");
+    /// assert_eq!(date.start_of_unit(Unit::Week).format("YYYY-MM-DD dddd"), "2024-05-05 Sunday");
+    /// assert_eq!(date.start_of_unit(Unit::Isoweek).format("YYYY-MM-DD dddd"), "2024-05-06 Monday");
+    /// ```
+    pub fn start_of_unit(&self, unit: Unit) -> Dayjs {
+        let local = self.datetime();
+        let time = match unit {
+            Unit::Day | Unit::Week | Unit::Isoweek | Unit::Month | Unit::Quarter | Unit::Year => {
+                // near the supported range the wall clock may be read in UTC, never move forward
+                self.tz
+                    .local_to_utc(local_start(local.naive_local(), unit))
+                    .min(self.time)
+            }
+            // the offset does not change within these units, truncate the wall clock
+            _ => local
+                .duration_trunc(unit_duration(unit))
+                .map_or(self.time, |time| time.to_utc()),
+        };
+        Dayjs { time, ..*self }
+    }
+
+    /// end of a time unit in the instance timezone, the last millisecond before the next one;
+    /// [`Unit::Week`] follows the global locale like [`Dayjs::start_of_unit`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1715243415);
+    /// let end = date.end_of_unit(Unit::Quarter);
+    /// assert_eq!(end.to_string(), "2024-06-30T23:59:59.999+00:00");
+    /// ```
+    pub fn end_of_unit(&self, unit: Unit) -> Dayjs {
+        let time = self
+            .next_start(unit)
+            .map_or(DateTime::<Utc>::MAX_UTC, |next| {
+                next - Duration::milliseconds(1)
+            });
+        Dayjs { time, ..*self }
+    }
+
+    /// the half-open `[start, next start)` of a time unit in the instance timezone, for range
+    /// queries that should not depend on the precision of [`Dayjs::end_of_unit`]; [`Unit::Week`]
+    /// follows the global locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// let date = dayjs::from_timestamp(1715243415);
+    /// let (start, end) = date.bounds_of(Unit::Month);
+    /// assert_eq!(start.to_string(), "2024-05-01T00:00:00+00:00");
+    /// assert_eq!(end.to_string(), "2024-06-01T00:00:00+00:00");
+    /// assert!(start <= date && date < end);
+    /// ```
+    pub fn bounds_of(&self, unit: Unit) -> (Dayjs, Dayjs) {
+        let end = Dayjs {
+            time: self.next_start(unit).unwrap_or(DateTime::<Utc>::MAX_UTC),
+            ..*self
+        };
+        (self.start_of_unit(unit), end)
+    }
+
+    /// instant the unit after the one of this instance starts, none past the supported range
+    fn next_start(&self, unit: Unit) -> Option<DateTime<Utc>> {
+        match unit {
+            Unit::Day | Unit::Week | Unit::Isoweek | Unit::Month | Unit::Quarter | Unit::Year => {
+                let start = local_start(self.datetime().naive_local(), unit);
+                let next = match unit {
+                    Unit::Month | Unit::Quarter | Unit::Year => {
+                        start.checked_add_months(Months::new(months_in(unit) as u32))
+                    }
+                    _ => start.checked_add_signed(unit_duration(unit)),
+                };
+                next.map(|next| self.tz.local_to_utc(next))
+            }
+            _ => self
+                .start_of_unit(unit)
+                .time
+                .checked_add_signed(unit_duration(unit)),
+        }
+    }
+
+    /// start of the first day of the month in the instance timezone, the same as
+    /// `start_of_unit(Unit::Month)`
+    pub fn first_day_of_month(&self) -> Dayjs {
+        self.start_of_unit(Unit::Month)
+    }
+
+    /// start of the last day of the month in the instance timezone, found on the wall clock so
+    /// a DST change during the month does not move it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1709214615);
+    /// assert_eq!(date.first_day_of_month().to_string(), "2024-02-01T00:00:00+00:00");
+    /// assert_eq!(date.last_day_of_month().to_string(), "2024-02-29T00:00:00+00:00");
+    /// ```
+    pub fn last_day_of_month(&self) -> Dayjs {
+        let start = local_start(self.datetime().naive_local(), Unit::Month);
+        let last = start.with_day(self.days_in_month()).unwrap_or(start);
+        Dayjs {
+            time: self.tz.local_to_utc(last),
+            ..*self
+        }
+    }
+
+    /// start of the week beginning on `first`, whatever the locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// // sunday 2024-01-07
+    /// let date = dayjs::from_timestamp(1704616200);
+    /// assert_eq!(date.start_of_week(Weekday::Sun).format("YYYY-MM-DD"), "2024-01-07");
+    /// assert_eq!(date.start_of_week(Weekday::Mon).format("YYYY-MM-DD"), "2024-01-01");
+    /// assert_eq!(date.start_of_week(Weekday::Sat).format("YYYY-MM-DD"), "2024-01-06");
+    /// ```
+    pub fn start_of_week(&self, first: Weekday) -> Dayjs {
+        let date = week_start(self.datetime().date_naive(), first);
+        Dayjs {
+            time: self.tz.local_to_utc(date.and_time(NaiveTime::MIN)),
+            ..*self
+        }
+    }
+
+    /// end of the week beginning on `first`, the last millisecond before the next one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// let date = dayjs::from_timestamp(1704616200);
+    /// let end = date.end_of_week(Weekday::Mon);
+    /// assert_eq!(end.to_string(), "2024-01-07T23:59:59.999+00:00");
+    /// ```
+    pub fn end_of_week(&self, first: Weekday) -> Dayjs {
+        let next = week_start(self.datetime().date_naive(), first).checked_add_days(Days::new(7));
+        let time = next.map_or(DateTime::<Utc>::MAX_UTC, |next| {
+            let next = self.tz.local_to_utc(next.and_time(NaiveTime::MIN));
+            next.checked_sub_signed(Duration::milliseconds(1))
+                .unwrap_or(next)
+        });
+        Dayjs { time, ..*self }
+    }
+
+    /// whether `other` is in the same unit as this instance, both read in this instance's
+    /// timezone; weeks are the active locale's like [`Dayjs::start_of_unit`], so the answer for
+    /// [`Unit::Week`] depends on the global locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// // saturday 2024-01-06 and sunday 2024-01-07
+    /// let saturday = dayjs::from_timestamp(1704529800);
+    /// let sunday = dayjs::from_timestamp(1704616200);
+    /// assert!(!saturday.is_same_unit(&sunday, Unit::Week));
+    /// assert!(saturday.is_same_unit(&sunday, Unit::Isoweek));
+    /// assert!(saturday.is_same_unit(&sunday, Unit::Month));
+    /// ```
+    pub fn is_same_unit(&self, other: &Dayjs, unit: Unit) -> bool {
+        let other = Dayjs {
+            tz: self.tz,
+            ..*other
+        };
+        self.start_of_unit(unit).time == other.start_of_unit(unit).time
+    }
+}
+
+/// elapsed time, saturating at the earliest and latest supported instants
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// let now = dayjs::from_timestamp(1704443415);
+/// let mut tomorrow = now + Duration::days(1);
+/// assert_eq!(tomorrow.to_string(), "2024-01-06T08:30:15+00:00");
+/// assert_eq!(tomorrow - now, Duration::days(1));
+/// tomorrow -= Duration::minutes(30);
+/// assert_eq!(tomorrow.format("HH:mm"), "08:00");
+/// ```
+impl Add<Duration> for Dayjs {
+    type Output = Dayjs;
+
+    fn add(self, rhs: Duration) -> Dayjs {
+        Dayjs {
+            time: shifted(self.time, rhs),
+            ..self
+        }
+    }
+}
+
+impl Sub<Duration> for Dayjs {
+    type Output = Dayjs;
+
+    fn sub(self, rhs: Duration) -> Dayjs {
+        Dayjs {
+            time: shifted(self.time, -rhs),
+            ..self
+        }
+    }
+}
+
+/// time elapsed between two instants, negative when `rhs` is later
+impl Sub<Dayjs> for Dayjs {
+    type Output = Duration;
+
+    fn sub(self, rhs: Dayjs) -> Duration {
+        self.time - rhs.time
+    }
+}
+
+impl AddAssign<Duration> for Dayjs {
+    fn add_assign(&mut self, rhs: Duration) {
+        self.time = shifted(self.time, rhs);
+    }
+}
+
+impl SubAssign<Duration> for Dayjs {
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.time = shifted(self.time, -rhs);
+    }
+}
+
+/// `time + delta`, clamped to the supported range
+fn shifted(time: DateTime<Utc>, delta: Duration) -> DateTime<Utc> {
+    time.checked_add_signed(delta)
+        .unwrap_or(if delta < Duration::zero() {
+            DateTime::<Utc>::MIN_UTC
+        } else {
+            DateTime::<Utc>::MAX_UTC
+        })
+}
+
+/// fixed length units
+fn unit_duration(unit: Unit) -> Duration {
+    match unit {
+        Unit::Millisecond => Duration::milliseconds(1),
+        Unit::Second => Duration::seconds(1),
+        Unit::Minute => Duration::minutes(1),
+        Unit::Hour => Duration::hours(1),
+        Unit::Day => Duration::days(1),
+        _ => Duration::weeks(1),
+    }
+}
+
+/// months in a calendar unit, month or longer
+fn months_in(unit: Unit) -> i64 {
+    match unit {
+        Unit::Quarter => 3,
+        Unit::Year => 12,
+        _ => 1,
+    }
+}
+
+/// `value` fixed length units
+fn scaled(unit: Unit, value: i64) -> Option<Duration> {
+    unit_duration(unit)
+        .num_milliseconds()
+        .checked_mul(value)
+        .and_then(Duration::try_milliseconds)
+}
+
+/// the last `first` weekday on or before `date`
+pub(crate) fn week_start(date: NaiveDate, first: Weekday) -> NaiveDate {
+    let days = Days::new(date.weekday().days_since(first).into());
+    date.checked_sub_days(days).unwrap_or(NaiveDate::MIN)
+}
+
+/// wall clock start of a calendar unit, day or longer
+fn local_start(local: NaiveDateTime, unit: Unit) -> NaiveDateTime {
+    let date = local.date();
+    let date = match unit {
+        Unit::Week => week_start(date, Locale::current().week_start),
+        Unit::Isoweek => week_start(date, Weekday::Mon),
+        Unit::Month => date.with_day(1).unwrap_or(date),
+        Unit::Quarter => date
+            .with_day(1)
+            .and_then(|d| d.with_month(date.month0() / 3 * 3 + 1))
+            .unwrap_or(date),
+        Unit::Year => date.with_ordinal(1).unwrap_or(date),
+        _ => date,
+    };
+    date.and_time(NaiveTime::MIN)
+}
@@ -0,0 +1,99 @@
+use crate::parse::{self, Cursor};
+use crate::{from_epoch, Dayjs, DayjsError, Epoch, Precision};
+use chrono::{NaiveDate, NaiveTime};
+
+/// parse an EXIF `DateTimeOriginal` style value, `YYYY:MM:DD HH:MM:SS`
+///
+/// `offset` is the matching `OffsetTime` tag, e.g. `+09:00`, without it the value is read as UTC;
+/// blank and all zero values, which cameras write when the clock was never set, are errors
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::media::from_exif("2024:01:05 17:30:00", Some("+09:00")).unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// assert!(dayjs::media::from_exif("0000:00:00 00:00:00", None).is_err());
+/// ```
+pub fn from_exif(datetime: &str, offset: Option<&str>) -> Result<Dayjs, DayjsError> {
+    // values are often NUL terminated
+    let datetime = datetime.trim_end_matches('\0');
+    let mut c = Cursor::new(datetime, "EXIF date time");
+    if datetime.trim_matches([' ', ':', '0']).is_empty() {
+        return Err(c.error("date time is not set"));
+    }
+    let year = c.digits(4, "year")?;
+    c.expect(b':')?;
+    let month = c.digits(2, "month")?;
+    c.expect(b':')?;
+    let day = c.digits(2, "day")?;
+    c.expect(b' ')?;
+    let hour = c.digits(2, "hour")?;
+    c.expect(b':')?;
+    let minute = c.digits(2, "minute")?;
+    c.expect(b':')?;
+    let second = c.digits(2, "second")?;
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    let date = parse::date(&c, year, month, day)?;
+    let time = parse::time(&c, hour, minute, second, 0)?;
+    let offset = match offset.map(|o| o.trim_end_matches('\0')) {
+        None | Some("") | Some("   :  ") => None,
+        Some(offset) => {
+            let mut c = Cursor::new(offset, "EXIF offset time");
+            let negative = c.sign()?;
+            let seconds = parse::offset(&mut c, negative, true)?;
+            if !c.at_end() {
+                return Err(c.error("unexpected trailing characters"));
+            }
+            Some(seconds)
+        }
+    };
+    Ok(parse::zoned(date.and_time(time), offset))
+}
+
+/// get dayjs instance from QuickTime / MP4 seconds since 1904-01-01 UTC
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::media::from_quicktime(3_787_288_200);
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// ```
+pub fn from_quicktime(seconds: u64) -> Dayjs {
+    from_epoch(
+        seconds.min(i64::MAX as u64) as i64,
+        Epoch::QuickTime,
+        Precision::Seconds,
+    )
+}
+
+impl Dayjs {
+    /// seconds since 1904-01-01 UTC, as written in QuickTime / MP4 headers
+    pub fn to_quicktime(&self) -> u64 {
+        self.to_epoch(Epoch::QuickTime, Precision::Seconds).max(0) as u64
+    }
+}
+
+/// parse a FAT / DOS date and time pair, a local time with 2 second resolution read as UTC
+///
+/// # Examples
+///
+/// ```
+/// // 2024-01-05 08:30:00
+/// let date = dayjs::media::from_dos(0x5825, 0x43c0).unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// ```
+pub fn from_dos(date: u16, time: u16) -> Result<Dayjs, DayjsError> {
+    let year = 1980 + i32::from(date >> 9);
+    let (month, day) = (u32::from(date >> 5 & 0x0f), u32::from(date & 0x1f));
+    let (hour, minute) = (u32::from(time >> 11), u32::from(time >> 5 & 0x3f));
+    let second = u32::from(time & 0x1f) * 2;
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+        DayjsError::parse("DOS date", &format!("{:#06x}", date), "date out of range")
+    })?;
+    let time = NaiveTime::from_hms_opt(hour, minute, second).ok_or_else(|| {
+        DayjsError::parse("DOS time", &format!("{:#06x}", time), "time out of range")
+    })?;
+    Ok(parse::zoned(date.and_time(time), None))
+}
@@ -0,0 +1,150 @@
+use crate::rss::from_rss_date;
+use crate::{from_epoch, from_format_dayjs, parse_iso8601, parse_rfc3339_strict};
+use crate::{Dayjs, DayjsError, DayjsTimeZone, Epoch, Precision};
+use std::collections::HashMap;
+
+/// a raw timestamp field, text or a unix epoch number
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Raw<'a> {
+    Text(&'a str),
+    Number(i64),
+}
+
+impl<'a> From<&'a str> for Raw<'a> {
+    fn from(s: &'a str) -> Self {
+        Raw::Text(s)
+    }
+}
+
+impl From<i64> for Raw<'_> {
+    fn from(n: i64) -> Self {
+        Raw::Number(n)
+    }
+}
+
+/// outcome counts of one field
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldStats {
+    pub normalized: usize,
+    pub failed: usize,
+    pub last_error: Option<DayjsError>,
+}
+
+/// normalizes timestamps to one template and timezone, counting outcomes per field
+///
+/// text is read with the field's hints first, as dayjs templates, then digits like a number,
+/// then as RFC 3339, ISO 8601 or RFC 2822; numbers are unix time in seconds, milliseconds,
+/// microseconds or nanoseconds by magnitude
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::normalize::Normalizer;
+/// use dayjs::DayjsTimeZone;
+/// let mut normalizer = Normalizer::new("YYYY-MM-DD HH:mm Z", DayjsTimeZone::Offset(3600))
+///     .hint("created", "DD/MM/YYYY HH:mm");
+/// let created = normalizer.normalize("created", "05/01/2024 08:30").unwrap();
+/// assert_eq!(created, "2024-01-05 09:30 +01:00");
+/// let updated = normalizer.normalize("updated", 1704443400000).unwrap();
+/// assert_eq!(updated, "2024-01-05 09:30 +01:00");
+/// assert!(normalizer.normalize("updated", "yesterday").is_err());
+/// assert_eq!(normalizer.stats()["updated"].failed, 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Normalizer {
+    template: String,
+    tz: DayjsTimeZone,
+    hints: HashMap<String, Vec<String>>,
+    stats: HashMap<String, FieldStats>,
+}
+
+impl Normalizer {
+    /// output with a [`Dayjs::format`] template in `tz`
+    pub fn new(template: &str, tz: DayjsTimeZone) -> Normalizer {
+        Normalizer {
+            template: template.to_string(),
+            tz,
+            hints: HashMap::new(),
+            stats: HashMap::new(),
+        }
+    }
+
+    /// try a dayjs template first for a field, hints are tried in the order they are added
+    pub fn hint(mut self, field: &str, template: &str) -> Normalizer {
+        self.hints
+            .entry(field.to_string())
+            .or_default()
+            .push(template.to_string());
+        self
+    }
+
+    /// read a raw value of a field without counting it
+    pub fn parse<'a>(&self, field: &str, raw: impl Into<Raw<'a>>) -> Result<Dayjs, DayjsError> {
+        let s = match raw.into() {
+            Raw::Number(n) => return Ok(from_unix(n)),
+            Raw::Text(s) => s.trim(),
+        };
+        let mut hints = self.hints.get(field).into_iter().flatten();
+        if let Some(date) = hints.find_map(|template| from_format_dayjs(s, template).ok()) {
+            return Ok(date);
+        }
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            return s
+                .parse()
+                .map(from_unix)
+                .map_err(|_| DayjsError::parse("timestamp", s, "number out of range"));
+        }
+        parse_rfc3339_strict(s)
+            .or_else(|_| parse_iso8601(s))
+            .or_else(|_| from_rss_date(s))
+            .map_err(|_| DayjsError::parse("timestamp", s, "no known format matches"))
+    }
+
+    /// the value of a field in the output template and timezone
+    pub fn normalize<'a>(
+        &mut self,
+        field: &str,
+        raw: impl Into<Raw<'a>>,
+    ) -> Result<String, DayjsError> {
+        let result = self
+            .parse(field, raw)
+            .map(|date| date.tz(self.tz).format(&self.template));
+        let stats = self.stats.entry(field.to_string()).or_default();
+        match &result {
+            Ok(_) => stats.normalized += 1,
+            Err(err) => {
+                stats.failed += 1;
+                stats.last_error = Some(err.clone());
+            }
+        }
+        result
+    }
+
+    /// counts by field name
+    pub fn stats(&self) -> &HashMap<String, FieldStats> {
+        &self.stats
+    }
+
+    /// counts of all fields together, without an error
+    pub fn total(&self) -> FieldStats {
+        self.stats
+            .values()
+            .fold(FieldStats::default(), |total, field| FieldStats {
+                normalized: total.normalized + field.normalized,
+                failed: total.failed + field.failed,
+                last_error: None,
+            })
+    }
+}
+
+/// unix time with the precision guessed from the magnitude, seconds up to the year 5138
+fn from_unix(n: i64) -> Dayjs {
+    let precision = match n.unsigned_abs() {
+        0..=99_999_999_999 => Precision::Seconds,
+        100_000_000_000..=99_999_999_999_999 => Precision::Milliseconds,
+        100_000_000_000_000..=99_999_999_999_999_999 => Precision::Microseconds,
+        _ => Precision::Nanoseconds,
+    };
+    from_epoch(n, Epoch::Unix, precision)
+}
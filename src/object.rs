@@ -0,0 +1,281 @@
+use crate::json::{self, Json};
+use crate::{Dayjs, DayjsError, DayjsTimeZone};
+use chrono::{DateTime, Datelike, NaiveDate, Timelike};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const GRAMMAR: &str = "dayjs object";
+
+/// timezone kept by a version 2 [`DayjsObject`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ZoneKind {
+    Utc,
+    /// seconds east of UTC
+    Offset(i32),
+    /// the system timezone of whoever reads the object
+    Local,
+    /// IANA name, reading it back needs the `tz` feature; borrowed from the tz database when
+    /// written by [`Dayjs::to_object_v2`], so that does not allocate
+    City(Cow<'static, str>),
+}
+
+/// plain state of a [`Dayjs`] for storage and queues, written as a flat JSON object
+///
+/// version 1 is the Day.js `toObject()` shape: the wall clock with months from 0 and no zone,
+/// read back in UTC. version 2 names its version and keeps the instant to the nanosecond and
+/// the zone, it is what [`Dayjs::to_object_v2`] writes
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{DayjsObject, DayjsTimeZone};
+/// let mut date = dayjs::from_timestamp(1704443400123);
+/// date.tz = DayjsTimeZone::Offset(8 * 3600);
+/// let text = date.to_object_v2().to_string();
+/// assert_eq!(
+///     text,
+///     r#"{"version":2,"unix":1704443400,"nanos":123000000,"zone":"offset","offset":28800}"#
+/// );
+/// let object: DayjsObject = text.parse().unwrap();
+/// assert_eq!(dayjs::from_object(&object).unwrap(), date);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayjsObject {
+    V1 {
+        years: i32,
+        /// 0 for january
+        months: u32,
+        date: u32,
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+        milliseconds: u32,
+    },
+    V2 {
+        /// seconds since 1970-01-01 UTC
+        unix: i64,
+        nanos: u32,
+        zone: ZoneKind,
+    },
+}
+
+impl DayjsObject {
+    /// schema version, 1 or 2
+    pub fn version(&self) -> u32 {
+        match self {
+            DayjsObject::V1 { .. } => 1,
+            DayjsObject::V2 { .. } => 2,
+        }
+    }
+}
+
+impl Display for DayjsObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayjsObject::V1 {
+                years,
+                months,
+                date,
+                hours,
+                minutes,
+                seconds,
+                milliseconds,
+            } => write!(
+                f,
+                r#"{{"years":{},"months":{},"date":{},"hours":{},"minutes":{},"seconds":{},"milliseconds":{}}}"#,
+                years, months, date, hours, minutes, seconds, milliseconds
+            ),
+            DayjsObject::V2 { unix, nanos, zone } => {
+                write!(
+                    f,
+                    r#"{{"version":2,"unix":{},"nanos":{},"zone":"#,
+                    unix, nanos
+                )?;
+                match zone {
+                    ZoneKind::Utc => write!(f, r#""utc"}}"#),
+                    ZoneKind::Offset(seconds) => write!(f, r#""offset","offset":{}}}"#, seconds),
+                    ZoneKind::Local => write!(f, r#""local"}}"#),
+                    ZoneKind::City(name) => {
+                        f.write_str(r#""city","name":"#)?;
+                        json::write_string(f, name)?;
+                        f.write_str("}")
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// the members of a JSON object
+fn members(s: &str) -> Result<Vec<(String, Json)>, DayjsError> {
+    match json::parse(s, GRAMMAR)? {
+        Json::Object(members) => Ok(members),
+        other => Err(DayjsError::parse(
+            GRAMMAR,
+            s,
+            &format!("expected an object, got {}", other.kind()),
+        )),
+    }
+}
+
+impl FromStr for DayjsObject {
+    type Err = DayjsError;
+
+    /// read either version, an object without a `"version"` is version 1; unknown keys are
+    /// ignored so newer writers can add fields
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let members = members(s)?;
+        let error = |message: String| DayjsError::parse(GRAMMAR, s, &message);
+        let find = |key: &str| members.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+        let number = |key: &str| match find(key) {
+            Some(Json::Number(n)) => n
+                .parse::<i64>()
+                .map_err(|_| error(format!("{:?} must be an integer", key))),
+            Some(_) => Err(error(format!("{:?} must be a number", key))),
+            None => Err(error(format!("missing {:?}", key))),
+        };
+        let field = |key: &str| {
+            number(key).and_then(|n| {
+                u32::try_from(n).map_err(|_| error(format!("{:?} is out of range", key)))
+            })
+        };
+        let name = |key: &str| match find(key) {
+            Some(Json::String(name)) => Ok(name.clone()),
+            Some(_) => Err(error(format!("{:?} must be a string", key))),
+            None => Err(error(format!("missing {:?}", key))),
+        };
+        let version = match find("version") {
+            None => 1,
+            Some(_) => number("version")?,
+        };
+        match version {
+            1 => Ok(DayjsObject::V1 {
+                years: i32::try_from(number("years")?)
+                    .map_err(|_| error("\"years\" is out of range".to_string()))?,
+                months: field("months")?,
+                date: field("date")?,
+                hours: field("hours")?,
+                minutes: field("minutes")?,
+                seconds: field("seconds")?,
+                milliseconds: field("milliseconds")?,
+            }),
+            2 => {
+                let zone = match name("zone")?.as_str() {
+                    "utc" => ZoneKind::Utc,
+                    "offset" => ZoneKind::Offset(
+                        i32::try_from(number("offset")?)
+                            .map_err(|_| error("\"offset\" is out of range".to_string()))?,
+                    ),
+                    "local" => ZoneKind::Local,
+                    "city" => ZoneKind::City(Cow::Owned(name("name")?)),
+                    other => return Err(error(format!("unknown zone kind {:?}", other))),
+                };
+                Ok(DayjsObject::V2 {
+                    unix: number("unix")?,
+                    nanos: field("nanos")?,
+                    zone,
+                })
+            }
+            other => Err(error(format!("unsupported version {}", other))),
+        }
+    }
+}
+
+impl Dayjs {
+    /// the Day.js `toObject()` fields of the wall clock, the zone is not kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443415250);
+    /// assert_eq!(
+    ///     date.to_object().to_string(),
+    ///     r#"{"years":2024,"months":0,"date":5,"hours":8,"minutes":30,"seconds":15,"milliseconds":250}"#
+    /// );
+    /// ```
+    pub fn to_object(&self) -> DayjsObject {
+        let local = self.datetime();
+        DayjsObject::V1 {
+            years: local.year(),
+            months: local.month0(),
+            date: local.day(),
+            hours: local.hour(),
+            minutes: local.minute(),
+            seconds: local.second(),
+            milliseconds: local.timestamp_subsec_millis(),
+        }
+    }
+
+    /// versioned state that reads back to an equal instance, see [`DayjsObject`]
+    pub fn to_object_v2(&self) -> DayjsObject {
+        let zone = match self.tz {
+            DayjsTimeZone::DEFAULT => ZoneKind::Utc,
+            DayjsTimeZone::Offset(seconds) => ZoneKind::Offset(seconds),
+            DayjsTimeZone::Local => ZoneKind::Local,
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(tz) => ZoneKind::City(Cow::Borrowed(tz.name())),
+        };
+        DayjsObject::V2 {
+            unix: self.time.timestamp(),
+            nanos: self.time.timestamp_subsec_nanos(),
+            zone,
+        }
+    }
+}
+
+/// get dayjs instance from either version of [`DayjsObject`], version 1 is read in UTC
+///
+/// # Examples
+///
+/// ```
+/// let object = r#"{"years":2024,"months":0,"date":5,"hours":8,"minutes":30,"seconds":0,"milliseconds":0}"#;
+/// let date = dayjs::from_object(&object.parse().unwrap()).unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+/// ```
+pub fn from_object(object: &DayjsObject) -> Result<Dayjs, DayjsError> {
+    match object {
+        DayjsObject::V1 {
+            years,
+            months,
+            date,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+        } => {
+            let time = NaiveDate::from_ymd_opt(*years, months.saturating_add(1), *date)
+                .and_then(|day| day.and_hms_milli_opt(*hours, *minutes, *seconds, *milliseconds))
+                .filter(|_| *milliseconds < 1000)
+                .ok_or_else(|| DayjsError::OutOfRange(format!("{} is not a valid date", object)))?;
+            Ok(Dayjs {
+                tz: DayjsTimeZone::DEFAULT,
+                time: time.and_utc(),
+            })
+        }
+        DayjsObject::V2 { unix, nanos, zone } => {
+            let time = DateTime::from_timestamp(*unix, *nanos)
+                .filter(|_| *nanos < 1_000_000_000)
+                .ok_or(DayjsError::InvalidTimestamp(unix.saturating_mul(1000)))?;
+            let tz = match zone {
+                ZoneKind::Utc => DayjsTimeZone::DEFAULT,
+                ZoneKind::Offset(seconds) if seconds.unsigned_abs() < 86_400 => {
+                    DayjsTimeZone::Offset(*seconds)
+                }
+                ZoneKind::Offset(seconds) => {
+                    return Err(DayjsError::OutOfRange(format!(
+                        "offset of {} seconds is out of range",
+                        seconds
+                    )))
+                }
+                ZoneKind::Local => DayjsTimeZone::Local,
+                #[cfg(feature = "tz")]
+                ZoneKind::City(name) => DayjsTimeZone::city(name)
+                    .ok_or_else(|| DayjsError::InvalidTimeZone(name.to_string()))?,
+                #[cfg(not(feature = "tz"))]
+                ZoneKind::City(name) => return Err(DayjsError::InvalidTimeZone(name.to_string())),
+            };
+            Ok(Dayjs { tz, time })
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Dayjs, DayjsTime, DayjsTimeZone, Interval};
+use chrono::{Datelike, Days, Duration, NaiveDate, Weekday};
+
+/// weekly on-call rotation, participants take turns from one local handoff to the next
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rotation {
+    participants: Vec<String>,
+    handoff: DayjsTime,
+    weekday: Weekday,
+    tz: DayjsTimeZone,
+    /// local date of a handoff to the first participant
+    anchor: NaiveDate,
+}
+
+/// one participant's turn
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shift<'a> {
+    pub participant: &'a str,
+    pub interval: Interval,
+}
+
+impl Rotation {
+    /// rotation handing off every monday at `handoff` wall clock time in `tz`
+    ///
+    /// the first participant is on call from the first handoff after 1970-01-04, see
+    /// [`Rotation::starting`] to pick the first shift
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::oncall::Rotation;
+    /// use dayjs::DayjsTimeZone;
+    /// let handoff = "09:00".parse().unwrap();
+    /// let start = dayjs::from_timestamp(1704704400); // monday 2024-01-08T09:00Z
+    /// let rotation = Rotation::weekly(["ana", "bo", "cy"], handoff, DayjsTimeZone::DEFAULT)
+    ///     .starting(&start);
+    /// assert_eq!(rotation.who_is_on_call(&start), Some("ana"));
+    /// let shifts: Vec<_> = rotation.shifts(&start).take(4).map(|s| s.participant).collect();
+    /// assert_eq!(shifts, ["ana", "bo", "cy", "ana"]);
+    /// ```
+    pub fn weekly<I, S>(participants: I, handoff: DayjsTime, tz: DayjsTimeZone) -> Rotation
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Rotation {
+            participants: participants.into_iter().map(Into::into).collect(),
+            handoff,
+            weekday: Weekday::Mon,
+            tz,
+            anchor: first_handoff(Weekday::Mon),
+        }
+    }
+
+    /// hand off on another day of the week, this resets [`Rotation::starting`]
+    pub fn on(self, weekday: Weekday) -> Rotation {
+        Rotation {
+            weekday,
+            anchor: first_handoff(weekday),
+            ..self
+        }
+    }
+
+    /// make the shift that covers `date` the first participant's, a date before the first
+    /// handoff of the supported range leaves the rotation unchanged
+    pub fn starting(self, date: &Dayjs) -> Rotation {
+        match self.shift_date(date) {
+            Some(anchor) => Rotation { anchor, ..self },
+            None => self,
+        }
+    }
+
+    /// local date of the handoff that started the shift covering `date`, none before the
+    /// supported dates
+    fn shift_date(&self, date: &Dayjs) -> Option<NaiveDate> {
+        let local = date.tz(self.tz).datetime().naive_local();
+        let back =
+            (local.weekday().num_days_from_monday() + 7 - self.weekday.num_days_from_monday()) % 7;
+        let day = local.date().checked_sub_days(Days::new(back.into()))?;
+        if back == 0 && local.time() < self.handoff.naive() {
+            day.checked_sub_days(Days::new(7))
+        } else {
+            Some(day)
+        }
+    }
+
+    /// the shift starting with the handoff on the local date `day`, none without participants or
+    /// when the next handoff is past the supported dates
+    fn shift(&self, day: NaiveDate) -> Option<Shift<'_>> {
+        if self.participants.is_empty() {
+            return None;
+        }
+        let next = day.checked_add_days(Days::new(7))?;
+        let weeks = (day - self.anchor).num_days().div_euclid(7);
+        let index = weeks.rem_euclid(self.participants.len() as i64) as usize;
+        let at = |day: NaiveDate| Dayjs {
+            tz: self.tz,
+            time: self.tz.local_to_utc(day.and_time(self.handoff.naive())),
+        };
+        Some(Shift {
+            participant: &self.participants[index],
+            interval: Interval::new(at(day), at(next)),
+        })
+    }
+
+    /// participant on call at `date`, `None` without participants or in the first and last week
+    /// of the supported range
+    pub fn who_is_on_call(&self, date: &Dayjs) -> Option<&str> {
+        self.shift(self.shift_date(date)?).map(|s| s.participant)
+    }
+
+    /// shifts from the one covering `from` on, handoffs keep the local time across DST changes;
+    /// the shifts end with the supported dates
+    pub fn shifts(&self, from: &Dayjs) -> impl Iterator<Item = Shift<'_>> + '_ {
+        let first = self.shift_date(from);
+        (0..).map_while(move |week: u64| {
+            self.shift(first?.checked_add_days(Days::new(week.checked_mul(7)?))?)
+        })
+    }
+}
+
+/// first `weekday` after 1970-01-04
+fn first_handoff(weekday: Weekday) -> NaiveDate {
+    let monday = NaiveDate::from_ymd_opt(1970, 1, 5).unwrap_or_default();
+    monday + Duration::days(weekday.num_days_from_monday().into())
+}
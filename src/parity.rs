@@ -0,0 +1,175 @@
+use crate::json::{self, Json};
+use crate::DayjsError;
+use std::fmt::{Display, Formatter};
+
+/// how a value differs between the two outputs of [`compare`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MismatchKind {
+    /// only the Day.js output has the value
+    MissingInRust,
+    /// only the Rust output has the value
+    MissingInJs,
+    /// JSON types differ, e.g. "string" and "number"
+    Type {
+        rust: &'static str,
+        js: &'static str,
+    },
+    /// same type, different values, as compact JSON
+    Value { rust: String, js: String },
+}
+
+/// a difference at a JSON path like `$.format[2].text`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub path: String,
+    pub kind: MismatchKind,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            MismatchKind::MissingInRust => write!(f, "{}: missing in rust", self.path),
+            MismatchKind::MissingInJs => write!(f, "{}: missing in js", self.path),
+            MismatchKind::Type { rust, js } => {
+                write!(f, "{}: rust has a {}, js a {}", self.path, rust, js)
+            }
+            MismatchKind::Value { rust, js } => {
+                write!(f, "{}: rust {}, js {}", self.path, rust, js)
+            }
+        }
+    }
+}
+
+/// outcome of [`compare`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParityReport {
+    /// scalar values found in both outputs
+    pub compared: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl ParityReport {
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl Display for ParityReport {
+    /// a summary line followed by one line per mismatch
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} compared, {} mismatched",
+            self.compared,
+            self.mismatches.len()
+        )?;
+        for mismatch in &self.mismatches {
+            write!(f, "\n{}", mismatch)?;
+        }
+        Ok(())
+    }
+}
+
+/// `.key`, or `["key"]` when the key is not an identifier
+fn member_path(path: &str, key: &str) -> String {
+    let identifier = key.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if identifier {
+        format!("{}.{}", path, key)
+    } else {
+        format!("{}[{}]", path, Json::String(key.to_string()))
+    }
+}
+
+fn walk(path: String, rust: &Json, js: &Json, report: &mut ParityReport) {
+    let mut mismatch = |kind| {
+        report.mismatches.push(Mismatch {
+            path: path.clone(),
+            kind,
+        })
+    };
+    match (rust, js) {
+        (Json::Object(rust), Json::Object(js)) => {
+            for (key, js_value) in js {
+                let path = member_path(&path, key);
+                match rust.iter().find(|(k, _)| k == key) {
+                    Some((_, rust_value)) => walk(path, rust_value, js_value, report),
+                    None => report.mismatches.push(Mismatch {
+                        path,
+                        kind: MismatchKind::MissingInRust,
+                    }),
+                }
+            }
+            for (key, _) in rust.iter().filter(|(k, _)| !js.iter().any(|(j, _)| j == k)) {
+                report.mismatches.push(Mismatch {
+                    path: member_path(&path, key),
+                    kind: MismatchKind::MissingInJs,
+                });
+            }
+        }
+        (Json::Array(rust), Json::Array(js)) => {
+            for i in 0..rust.len().max(js.len()) {
+                let path = format!("{}[{}]", path, i);
+                match (rust.get(i), js.get(i)) {
+                    (Some(rust), Some(js)) => walk(path, rust, js, report),
+                    (None, _) => report.mismatches.push(Mismatch {
+                        path,
+                        kind: MismatchKind::MissingInRust,
+                    }),
+                    (_, None) => report.mismatches.push(Mismatch {
+                        path,
+                        kind: MismatchKind::MissingInJs,
+                    }),
+                }
+            }
+        }
+        _ if rust.kind() != js.kind() => mismatch(MismatchKind::Type {
+            rust: rust.kind(),
+            js: js.kind(),
+        }),
+        _ => {
+            report.compared += 1;
+            let equal = match (rust, js) {
+                // `1.0` and `1` are the same number to javascript
+                (Json::Number(a), Json::Number(b)) => {
+                    a == b || a.parse::<f64>().ok() == b.parse::<f64>().ok()
+                }
+                _ => rust == js,
+            };
+            if !equal {
+                mismatch(MismatchKind::Value {
+                    rust: rust.to_string(),
+                    js: js.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// compare the JSON output of a Rust run with the output of the same cases in Day.js
+///
+/// objects match by key in any order, arrays by index and numbers by value; mismatches come in
+/// document order of the Day.js output, then values only the Rust output has
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::parity::{compare, MismatchKind};
+/// let rust = r#"{"format": ["2024-01-05", "Friday"], "unix": 1704443400.0}"#;
+/// let js = r#"{"unix": 1704443400, "format": ["2024-01-05", "Fri"], "week": 1}"#;
+/// let report = compare(rust, js).unwrap();
+/// assert_eq!(report.compared, 3);
+/// assert_eq!(report.mismatches[0].path, "$.format[1]");
+/// assert_eq!(report.mismatches[1].kind, MismatchKind::MissingInRust);
+/// assert_eq!(
+///     report.to_string(),
+///     "3 compared, 2 mismatched\n$.format[1]: rust \"Friday\", js \"Fri\"\n$.week: missing in rust"
+/// );
+/// ```
+pub fn compare(rust_json: &str, js_json: &str) -> Result<ParityReport, DayjsError> {
+    let rust = json::parse(rust_json, "Rust JSON")?;
+    let js = json::parse(js_json, "Day.js JSON")?;
+    let mut report = ParityReport::default();
+    walk("$".to_string(), &rust, &js, &mut report);
+    Ok(report)
+}
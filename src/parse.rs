@@ -0,0 +1,463 @@
+use crate::{Dayjs, DayjsError, DayjsTimeZone};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// byte cursor over an ASCII grammar, errors name the grammar and the position
+pub(crate) struct Cursor<'a> {
+    s: &'a str,
+    pos: usize,
+    grammar: &'static str,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(s: &'a str, grammar: &'static str) -> Cursor<'a> {
+        Cursor { s, pos: 0, grammar }
+    }
+
+    pub(crate) fn error(&self, message: &str) -> DayjsError {
+        DayjsError::ParseError {
+            grammar: self.grammar,
+            input: self.s.to_string(),
+            message: message.to_string(),
+            position: Some(self.pos),
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    pub(crate) fn peek_at(&self, ahead: usize) -> Option<u8> {
+        self.s.as_bytes().get(self.pos + ahead).copied()
+    }
+
+    /// skip the byte under the cursor
+    pub(crate) fn bump(&mut self) {
+        self.pos += 1;
+    }
+
+    /// the input from the cursor on
+    pub(crate) fn rest(&self) -> &'a str {
+        &self.s[self.pos..]
+    }
+
+    /// skip `n` bytes, which must end on a char boundary
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.pos += n;
+    }
+
+    pub(crate) fn at_end(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+
+    pub(crate) fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn expect(&mut self, c: u8) -> Result<(), DayjsError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c as char)))
+        }
+    }
+
+    /// exactly `n` digits
+    pub(crate) fn digits(&mut self, n: usize, what: &str) -> Result<u32, DayjsError> {
+        let bytes = self.s.as_bytes();
+        let end = self.pos + n;
+        if end > bytes.len() || !bytes[self.pos..end].iter().all(u8::is_ascii_digit) {
+            return Err(self.error(&format!("expected {} digit {}", n, what)));
+        }
+        let value = bytes[self.pos..end]
+            .iter()
+            .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'));
+        self.pos = end;
+        Ok(value)
+    }
+
+    /// 1 to `max` digits
+    pub(crate) fn number(&mut self, max: usize, what: &str) -> Result<u64, DayjsError> {
+        let n = self.digit_run();
+        if n == 0 || n > max {
+            return Err(self.error(&format!("expected {}", what)));
+        }
+        let value = self.s[self.pos..self.pos + n].parse().unwrap_or_default();
+        self.pos += n;
+        Ok(value)
+    }
+
+    /// `+` or `-`, whether it is negative
+    pub(crate) fn sign(&mut self) -> Result<bool, DayjsError> {
+        match self.peek() {
+            Some(sign @ (b'+' | b'-')) => {
+                self.bump();
+                Ok(sign == b'-')
+            }
+            _ => Err(self.error("expected '+' or '-'")),
+        }
+    }
+
+    /// number of digits ahead of the cursor
+    pub(crate) fn digit_run(&self) -> usize {
+        self.s.as_bytes()[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    }
+
+    /// fraction digits after a separator, as nanoseconds
+    pub(crate) fn fraction(&mut self) -> Result<u32, DayjsError> {
+        let n = self.digit_run();
+        if n == 0 {
+            return Err(self.error("expected fraction digits"));
+        }
+        let digits = &self.s[self.pos..self.pos + n];
+        self.pos += n;
+        let nanos = digits
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(9)
+            .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'));
+        Ok(nanos)
+    }
+}
+
+pub(crate) fn date(c: &Cursor, year: u32, month: u32, day: u32) -> Result<NaiveDate, DayjsError> {
+    NaiveDate::from_ymd_opt(year as i32, month, day).ok_or_else(|| c.error("date out of range"))
+}
+
+/// time of day, `second` 60 is a leap second
+pub(crate) fn time(
+    c: &Cursor,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+) -> Result<NaiveTime, DayjsError> {
+    let (second, nanos) = if second == 60 {
+        (59, nanos + 1_000_000_000)
+    } else {
+        (second, nanos)
+    };
+    NaiveTime::from_hms_nano_opt(hour, minute, second, nanos)
+        .ok_or_else(|| c.error("time out of range"))
+}
+
+/// `±hh:mm` after the sign has been seen, as seconds east of UTC, otherwise `±hhmm` or `±hh`
+pub(crate) fn offset(c: &mut Cursor, negative: bool, strict: bool) -> Result<i32, DayjsError> {
+    let hours = c.digits(2, "offset hour")?;
+    let colon = if strict {
+        c.expect(b':')?;
+        true
+    } else {
+        c.eat(b':')
+    };
+    let minutes = if colon || c.digit_run() >= 2 {
+        c.digits(2, "offset minute")?
+    } else {
+        0
+    };
+    if hours > 23 || minutes > 59 {
+        return Err(c.error("offset out of range"));
+    }
+    let seconds = (hours * 3600 + minutes * 60) as i32;
+    Ok(if negative { -seconds } else { seconds })
+}
+
+/// build an instance from wall clock time and its offset, the offset is kept in the tz
+pub(crate) fn zoned(local: NaiveDateTime, offset: Option<i32>) -> Dayjs {
+    let seconds = offset.unwrap_or(0);
+    Dayjs {
+        tz: match offset {
+            Some(seconds) => DayjsTimeZone::Offset(seconds),
+            None => DayjsTimeZone::DEFAULT,
+        },
+        time: (local - Duration::seconds(seconds.into())).and_utc(),
+    }
+}
+
+/// parse an RFC 3339 date time, exactly as its ABNF grammar
+///
+/// `T` and `Z` may be lowercase, seconds and the offset are required and a space separator is
+/// rejected, `Z` keeps the default timezone and numeric offsets are kept in the tz
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::parse_rfc3339_strict("2024-01-05t08:30:00.5+08:00").unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T00:30:00.500+00:00");
+/// assert!(dayjs::parse_rfc3339_strict("2024-01-05 08:30:00Z").is_err());
+/// assert!(dayjs::parse_rfc3339_strict("2024-01-05T08:30:00").is_err());
+/// ```
+pub fn parse_rfc3339_strict(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "RFC 3339 date time");
+    let year = c.digits(4, "year")?;
+    c.expect(b'-')?;
+    let month = c.digits(2, "month")?;
+    c.expect(b'-')?;
+    let day = c.digits(2, "day")?;
+    let date = date(&c, year, month, day)?;
+    match c.peek() {
+        Some(b'T' | b't') => c.bump(),
+        Some(b' ') => return Err(c.error("expected 'T', a space separator is not RFC 3339")),
+        _ => return Err(c.error("expected 'T'")),
+    }
+    let hour = c.digits(2, "hour")?;
+    c.expect(b':')?;
+    let minute = c.digits(2, "minute")?;
+    c.expect(b':')?;
+    let second = c.digits(2, "second")?;
+    let nanos = if c.eat(b'.') { c.fraction()? } else { 0 };
+    let time = time(&c, hour, minute, second, nanos)?;
+    let offset = match c.peek() {
+        Some(b'Z' | b'z') => {
+            c.bump();
+            None
+        }
+        Some(sign @ (b'+' | b'-')) => {
+            c.bump();
+            let seconds = offset(&mut c, sign == b'-', true)?;
+            // -00:00 means the local offset is unknown, keep the default timezone
+            (seconds != 0 || sign == b'+').then_some(seconds)
+        }
+        None => return Err(c.error("expected 'Z' or an offset, RFC 3339 requires one")),
+        _ => return Err(c.error("expected 'Z' or an offset")),
+    };
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    Ok(zoned(date.and_time(time), offset))
+}
+
+/// parse an ISO 8601 date, or date and time, in extended or basic format
+///
+/// supports calendar (`2024-01-05`), ordinal (`2024-005`) and week (`2024-W01-5`) dates,
+/// reduced precision times (`T08`, `T08:30`), decimal fractions with `.` or `,` on the last
+/// time component and `24:00`; `T` and `Z` must be uppercase, and a missing offset is read as UTC
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::parse_iso8601("2024-W01-5T08:30,5Z").unwrap();
+/// assert_eq!(date.to_string(), "2024-01-05T08:30:30+00:00");
+/// assert!(dayjs::parse_iso8601("2024-01-05t08:30Z").is_err());
+/// ```
+pub fn parse_iso8601(s: &str) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "ISO 8601 date time");
+    let year = c.digits(4, "year")?;
+    let extended = c.eat(b'-');
+    let date = if c.eat(b'W') {
+        let week = c.digits(2, "week")?;
+        let weekday = if (extended && c.eat(b'-')) || (!extended && c.digit_run() > 0) {
+            c.digits(1, "weekday")?
+        } else {
+            1
+        };
+        let weekday = match weekday {
+            1..=7 => chrono::Weekday::try_from(weekday as u8 - 1).ok(),
+            _ => None,
+        };
+        weekday
+            .and_then(|w| NaiveDate::from_isoywd_opt(year as i32, week, w))
+            .ok_or_else(|| c.error("week date out of range"))?
+    } else {
+        match (extended, c.digit_run()) {
+            (_, 3) => {
+                let ordinal = c.digits(3, "day of year")?;
+                NaiveDate::from_yo_opt(year as i32, ordinal)
+                    .ok_or_else(|| c.error("day of year out of range"))?
+            }
+            (true, 2) => {
+                let month = c.digits(2, "month")?;
+                let day = if c.eat(b'-') { c.digits(2, "day")? } else { 1 };
+                date(&c, year, month, day)?
+            }
+            (false, 4) => {
+                let month = c.digits(2, "month")?;
+                let day = c.digits(2, "day")?;
+                date(&c, year, month, day)?
+            }
+            (false, 0) if c.at_end() => date(&c, year, 1, 1)?,
+            _ => return Err(c.error("expected month, day of year or week")),
+        }
+    };
+    if c.at_end() {
+        return Ok(zoned(date.and_time(NaiveTime::MIN), None));
+    }
+    match c.peek() {
+        Some(b'T') => c.bump(),
+        Some(b't') => return Err(c.error("expected 'T', lowercase 't' is not ISO 8601")),
+        Some(b' ') => return Err(c.error("expected 'T', a space separator is not ISO 8601")),
+        _ => return Err(c.error("expected 'T'")),
+    }
+    let date = iso_time(&mut c, date)?;
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    Ok(date)
+}
+
+/// an ISO 8601 time of day and optional offset after the `T`, on `date`
+fn iso_time(c: &mut Cursor, date: NaiveDate) -> Result<Dayjs, DayjsError> {
+    // components in order, the last one may carry a decimal fraction
+    let mut parts = vec![c.digits(2, "hour")?];
+    let time_extended = c.peek() == Some(b':');
+    while parts.len() < 3 {
+        if time_extended {
+            if !c.eat(b':') {
+                break;
+            }
+        } else if c.digit_run() < 2 {
+            break;
+        }
+        parts.push(c.digits(2, if parts.len() == 1 { "minute" } else { "second" })?);
+    }
+    let fraction = if c.eat(b'.') || c.eat(b',') {
+        c.fraction()?
+    } else {
+        0
+    };
+    // the fraction of the last component, in nanoseconds
+    let extra = [3_600, 60, 1][parts.len() - 1] * i64::from(fraction);
+    parts.resize(3, 0);
+    let (hour, minute, second) = (parts[0], parts[1], parts[2]);
+    let mut local = if hour == 24 && minute == 0 && second == 0 && extra == 0 {
+        date.and_time(NaiveTime::MIN) + Duration::days(1)
+    } else {
+        date.and_time(time(c, hour, minute, second, 0)?)
+    };
+    local += Duration::nanoseconds(extra);
+    let offset = match c.peek() {
+        Some(b'Z') => {
+            c.bump();
+            None
+        }
+        Some(b'z') => return Err(c.error("expected 'Z', lowercase 'z' is not ISO 8601")),
+        Some(sign @ (b'+' | b'-')) => {
+            c.bump();
+            Some(offset(c, sign == b'-', false)?)
+        }
+        _ => None,
+    };
+    Ok(zoned(local, offset))
+}
+
+/// kind of an ISO 8601 string, see [`validate_iso`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IsoKind {
+    /// `2024-01-05`, `2024-005` or `2024-W01-5`
+    Date,
+    /// `08:30:00`, `T0830` or `T08:30Z`
+    Time,
+    /// `2024-01-05T08:30:00+08:00`
+    DateTime,
+    /// `P1Y2M10DT2H30M` or `P3W`
+    Duration,
+    /// `start/end`, `start/duration` or `duration/end`
+    Interval,
+}
+
+/// classify an ISO 8601 string, or say why it is not one
+///
+/// dates and times follow [`parse_iso8601`], a time without `T` must use the extended format;
+/// durations use designators with a decimal fraction allowed on the last component only
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{validate_iso, IsoKind};
+/// assert_eq!(validate_iso("2024-W01-5"), Ok(IsoKind::Date));
+/// assert_eq!(validate_iso("T08:30Z"), Ok(IsoKind::Time));
+/// assert_eq!(validate_iso("P1DT12H"), Ok(IsoKind::Duration));
+/// assert_eq!(validate_iso("2024-01-05T08:30Z/PT1H"), Ok(IsoKind::Interval));
+/// assert!(validate_iso("P1H").is_err());
+/// ```
+pub fn validate_iso(s: &str) -> Result<IsoKind, DayjsError> {
+    if let Some((start, end)) = s.split_once('/') {
+        return match (validate_iso(start)?, validate_iso(end)?) {
+            (IsoKind::Date | IsoKind::DateTime, IsoKind::Date | IsoKind::DateTime)
+            | (IsoKind::Date | IsoKind::DateTime, IsoKind::Duration)
+            | (IsoKind::Duration, IsoKind::Date | IsoKind::DateTime) => Ok(IsoKind::Interval),
+            _ => Err(DayjsError::parse(
+                "ISO 8601 interval",
+                s,
+                "expected two dates, or a date and a duration",
+            )),
+        };
+    }
+    if s.starts_with('P') {
+        iso_duration(s)?;
+        return Ok(IsoKind::Duration);
+    }
+    if s.starts_with('T') || s.as_bytes().get(2) == Some(&b':') {
+        let mut c = Cursor::new(s, "ISO 8601 time");
+        c.eat(b'T');
+        iso_time(&mut c, NaiveDate::default())?;
+        if !c.at_end() {
+            return Err(c.error("unexpected trailing characters"));
+        }
+        return Ok(IsoKind::Time);
+    }
+    parse_iso8601(s)?;
+    Ok(if s.contains('T') {
+        IsoKind::DateTime
+    } else {
+        IsoKind::Date
+    })
+}
+
+/// check an ISO 8601 duration such as `P1Y2M10DT2H30M`
+fn iso_duration(s: &str) -> Result<(), DayjsError> {
+    let mut c = Cursor::new(s, "ISO 8601 duration");
+    c.expect(b'P')?;
+    let mut designators: &[u8] = b"YMWD";
+    let (mut components, mut weeks, mut fraction, mut time) = (0, false, false, false);
+    while !c.at_end() {
+        if !time && c.eat(b'T') {
+            time = true;
+            designators = b"HMS";
+            if c.at_end() {
+                return Err(c.error("expected a time component after 'T'"));
+            }
+            continue;
+        }
+        if fraction {
+            return Err(c.error("only the last component may have a fraction"));
+        }
+        let digits = c.digit_run();
+        if digits == 0 {
+            return Err(c.error("expected a number"));
+        }
+        c.advance(digits);
+        if c.eat(b'.') || c.eat(b',') {
+            c.fraction()?;
+            fraction = true;
+        }
+        let found = c
+            .peek()
+            .and_then(|d| designators.iter().position(|&allowed| allowed == d));
+        let Some(index) = found else {
+            return Err(c.error(match c.peek() {
+                Some(b'H' | b'S') if !time => "expected 'T' before hours and seconds",
+                Some(b'Y' | b'M' | b'W' | b'D' | b'H' | b'S') => "designator out of order",
+                _ => "expected a designator",
+            }));
+        };
+        weeks |= designators[index] == b'W';
+        c.bump();
+        components += 1;
+        designators = &designators[index + 1..];
+        if weeks && components > 1 {
+            return Err(c.error("weeks cannot be combined with other components"));
+        }
+    }
+    if components == 0 {
+        return Err(c.error("expected at least one component"));
+    }
+    Ok(())
+}
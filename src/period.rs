@@ -0,0 +1,82 @@
+use crate::{Dayjs, DayjsTimeZone, Interval, Unit};
+
+/// reporting period presets, so filters agree on boundaries
+///
+/// [`Period::ThisWeek`] and [`Period::LastWeek`] start on `Locale::current().week_start` like
+/// [`Dayjs::start_of`], so they follow the global locale; `*ToDate` periods end at the current
+/// instant
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Period {
+    Today,
+    Yesterday,
+    ThisWeek,
+    LastWeek,
+    MonthToDate,
+    QuarterToDate,
+    /// the 30 full days before today
+    Last30Days,
+}
+
+impl Period {
+    /// the period around `now` in `tz`, the bounds carry `tz`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTimeZone, Period};
+    /// let now = dayjs::from_timestamp(1715243415);
+    /// let tz = DayjsTimeZone::Offset(-7 * 3600);
+    /// let week = Period::LastWeek.at(&now, &tz);
+    /// assert_eq!(week.start.format("YYYY-MM-DD dddd Z"), "2024-04-28 Sunday -07:00");
+    /// assert_eq!(week.end.format("YYYY-MM-DD dddd Z"), "2024-05-05 Sunday -07:00");
+    /// let quarter = Period::QuarterToDate.at(&now, &tz);
+    /// assert_eq!(quarter.start.format("YYYY-MM-DD HH:mm"), "2024-04-01 00:00");
+    /// assert_eq!(quarter.end, now.tz(tz));
+    /// ```
+    pub fn at(self, now: &Dayjs, tz: &DayjsTimeZone) -> Interval {
+        let now = now.tz(*tz);
+        let current = |unit| {
+            let start = now.start_of_unit(unit);
+            Interval::new(start, start.add(1, unit))
+        };
+        let to_date = |unit| Interval::new(now.start_of_unit(unit), now);
+        match self {
+            Period::Today => current(Unit::Day),
+            Period::Yesterday => now.trailing_units(1, Unit::Day, tz),
+            Period::ThisWeek => current(Unit::Week),
+            Period::LastWeek => now.trailing_units(1, Unit::Week, tz),
+            Period::MonthToDate => to_date(Unit::Month),
+            Period::QuarterToDate => to_date(Unit::Quarter),
+            Period::Last30Days => now.trailing_units(30, Unit::Day, tz),
+        }
+    }
+
+    /// today in `tz`
+    pub fn today(tz: DayjsTimeZone) -> Interval {
+        Period::Today.at(&crate::utc(), &tz)
+    }
+
+    pub fn yesterday(tz: DayjsTimeZone) -> Interval {
+        Period::Yesterday.at(&crate::utc(), &tz)
+    }
+
+    pub fn this_week(tz: DayjsTimeZone) -> Interval {
+        Period::ThisWeek.at(&crate::utc(), &tz)
+    }
+
+    pub fn last_week(tz: DayjsTimeZone) -> Interval {
+        Period::LastWeek.at(&crate::utc(), &tz)
+    }
+
+    pub fn month_to_date(tz: DayjsTimeZone) -> Interval {
+        Period::MonthToDate.at(&crate::utc(), &tz)
+    }
+
+    pub fn quarter_to_date(tz: DayjsTimeZone) -> Interval {
+        Period::QuarterToDate.at(&crate::utc(), &tz)
+    }
+
+    pub fn last_30_days(tz: DayjsTimeZone) -> Interval {
+        Period::Last30Days.at(&crate::utc(), &tz)
+    }
+}
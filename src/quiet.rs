@@ -0,0 +1,120 @@
+use crate::{Dayjs, DayjsTime, DayjsTimeZone};
+use chrono::{Datelike, NaiveDateTime, Weekday};
+
+/// do-not-disturb windows of one recipient, in wall clock time of their timezone
+///
+/// a window belongs to the weekday it starts on, one that ends at or before its start runs
+/// past midnight, e.g. 22:00 to 07:00
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuietHours {
+    tz: DayjsTimeZone,
+    /// `(start, end)` windows by days from monday
+    windows: [Vec<(DayjsTime, DayjsTime)>; 7],
+}
+
+impl QuietHours {
+    /// no quiet windows yet
+    pub fn new(tz: DayjsTimeZone) -> QuietHours {
+        QuietHours {
+            tz,
+            windows: Default::default(),
+        }
+    }
+
+    /// add a window starting on `weekday`, an empty window with `start == end` is ignored
+    pub fn window(mut self, weekday: Weekday, start: DayjsTime, end: DayjsTime) -> QuietHours {
+        if start != end {
+            self.windows[weekday.num_days_from_monday() as usize].push((start, end));
+        }
+        self
+    }
+
+    /// add the same window to every day of the week
+    pub fn every_day(self, start: DayjsTime, end: DayjsTime) -> QuietHours {
+        (0..7u8)
+            .filter_map(|day| Weekday::try_from(day).ok())
+            .fold(self, |quiet, weekday| quiet.window(weekday, start, end))
+    }
+
+    /// end of the window covering the wall clock time, the latest one when windows overlap
+    fn covering_end(&self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        let today = local.date();
+        let time = local.time();
+        let yesterday = today.pred_opt()?;
+        let from_today = self.windows[today.weekday().num_days_from_monday() as usize]
+            .iter()
+            .filter_map(|(start, end)| {
+                if time < start.naive() {
+                    None
+                } else if end > start {
+                    (time < end.naive()).then(|| today.and_time(end.naive()))
+                } else {
+                    Some(today.succ_opt()?.and_time(end.naive()))
+                }
+            });
+        let from_yesterday = self.windows[yesterday.weekday().num_days_from_monday() as usize]
+            .iter()
+            .filter(|(start, end)| end <= start && time < end.naive())
+            .map(|(_, end)| today.and_time(end.naive()));
+        from_today.chain(from_yesterday).max()
+    }
+
+    /// whether `date` falls in a quiet window
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::quiet::QuietHours;
+    /// use dayjs::DayjsTimeZone;
+    /// let night = QuietHours::new(DayjsTimeZone::Offset(3600))
+    ///     .every_day("22:00".parse().unwrap(), "07:00".parse().unwrap());
+    /// // 2024-01-05T05:30:00Z is 06:30 for the recipient
+    /// assert!(night.is_quiet(&dayjs::from_timestamp(1704432600)));
+    /// assert!(!night.is_quiet(&dayjs::from_timestamp(1704436200)));
+    /// ```
+    pub fn is_quiet(&self, date: &Dayjs) -> bool {
+        let local = date.tz(self.tz).datetime().naive_local();
+        self.covering_end(local).is_some()
+    }
+
+    /// `date` itself when it is not quiet, otherwise the end of the quiet time covering it,
+    /// `None` when every hour of the week is quiet
+    ///
+    /// a window ending in a DST gap ends after the gap, the result keeps the timezone of `date`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use dayjs::quiet::QuietHours;
+    /// use dayjs::DayjsTimeZone;
+    /// let quiet = QuietHours::new(DayjsTimeZone::DEFAULT)
+    ///     .every_day("22:00".parse().unwrap(), "07:00".parse().unwrap())
+    ///     .window(Weekday::Sat, "07:00".parse().unwrap(), "10:00".parse().unwrap());
+    /// // saturday 2024-01-06T03:00:00Z
+    /// let next = quiet.next_allowed_send(&dayjs::from_timestamp(1704510000)).unwrap();
+    /// assert_eq!(next.to_string(), "2024-01-06T10:00:00+00:00");
+    /// ```
+    pub fn next_allowed_send(&self, date: &Dayjs) -> Option<Dayjs> {
+        let mut local = date.tz(self.tz).datetime().naive_local();
+        let mut quiet = false;
+        // every step ends a different window, more steps than windows means a quiet week
+        let windows: usize = self.windows.iter().map(Vec::len).sum();
+        for _ in 0..=windows {
+            match self.covering_end(local) {
+                Some(end) => {
+                    quiet = true;
+                    local = end;
+                }
+                None if quiet => {
+                    return Some(Dayjs {
+                        time: self.tz.local_to_utc(local),
+                        ..*date
+                    })
+                }
+                None => return Some(*date),
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,371 @@
+use crate::get::days_in_month;
+use crate::{Dayjs, DayjsError};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use std::str::FromStr;
+
+/// how often a [`Recurrence`] repeats
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    /// periods in a 400 year gregorian cycle, a rule without occurrences in that many periods has
+    /// none at all
+    fn cycle(self) -> usize {
+        match self {
+            Frequency::Daily => 146_097,
+            Frequency::Weekly => 20_872,
+            Frequency::Monthly => 4_800,
+            Frequency::Yearly => 400,
+        }
+    }
+}
+
+/// `year` when chrono can hold dates in it
+fn supported_year(year: i64) -> Option<i32> {
+    i32::try_from(year)
+        .ok()
+        .filter(|year| (NaiveDate::MIN.year()..=NaiveDate::MAX.year()).contains(year))
+}
+
+/// repeating dates like "every 2nd tuesday" or "monthly on the 15th", the RFC 5545 RRULE subset
+/// `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, `BYDAY`, `BYMONTHDAY` and `BYMONTH`
+///
+/// occurrences keep the wall clock time of the start in its timezone, weeks start on monday and
+/// the start is only an occurrence when it matches the rule; `BYDAY` with a position like `2TU`
+/// counts within the month, also for yearly rules
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use dayjs::recurrence::{Frequency, Recurrence};
+/// let start = dayjs::from_str("2024-01-01T09:00:00Z");
+/// let rule = Recurrence::new(Frequency::Monthly, &start).on_nth(2, Weekday::Tue).count(3);
+/// let dates: Vec<_> = rule.iter().map(|d| d.format("YYYY-MM-DD")).collect();
+/// assert_eq!(dates, ["2024-01-09", "2024-02-13", "2024-03-12"]);
+/// let rule = Recurrence::from_rrule("FREQ=MONTHLY;BYMONTHDAY=15", &start).unwrap();
+/// let next = rule.next_after(&dayjs::from_str("2024-05-15T09:00:00Z")).unwrap();
+/// assert_eq!(next.to_string(), "2024-06-15T09:00:00+00:00");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recurrence {
+    start: Dayjs,
+    frequency: Frequency,
+    interval: u32,
+    count: Option<usize>,
+    until: Option<Until>,
+    /// position in the month, 0 for every, and the day
+    weekdays: Vec<(i32, Weekday)>,
+    /// negative from the end of the month
+    month_days: Vec<i32>,
+    months: Vec<u32>,
+}
+
+/// `UNTIL` is an instant, or a wall clock time in the timezone of the start
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Until {
+    Instant(Dayjs),
+    Local(NaiveDateTime),
+}
+
+impl Recurrence {
+    /// repeat every period from `start`
+    pub fn new(frequency: Frequency, start: &Dayjs) -> Recurrence {
+        Recurrence {
+            start: *start,
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            weekdays: Vec::new(),
+            month_days: Vec::new(),
+            months: Vec::new(),
+        }
+    }
+
+    /// the same rule from another start
+    pub fn starting(self, start: &Dayjs) -> Recurrence {
+        Recurrence {
+            start: *start,
+            ..self
+        }
+    }
+
+    /// every `n`th period, 0 is taken as 1
+    pub fn interval(self, n: u32) -> Recurrence {
+        Recurrence {
+            interval: n.max(1),
+            ..self
+        }
+    }
+
+    /// stop after `n` occurrences
+    pub fn count(self, n: usize) -> Recurrence {
+        Recurrence {
+            count: Some(n),
+            ..self
+        }
+    }
+
+    /// stop after `date`, an occurrence at `date` is included
+    pub fn until(self, date: &Dayjs) -> Recurrence {
+        Recurrence {
+            until: Some(Until::Instant(*date)),
+            ..self
+        }
+    }
+
+    /// on a day of the week
+    pub fn on(self, weekday: Weekday) -> Recurrence {
+        self.on_nth(0, weekday)
+    }
+
+    /// on the `n`th day of the week in the month, -1 for the last, 0 for every
+    pub fn on_nth(mut self, n: i32, weekday: Weekday) -> Recurrence {
+        self.weekdays.push((n, weekday));
+        self
+    }
+
+    /// on a day of the month, -1 for the last; months without the day are skipped
+    pub fn on_month_day(mut self, day: i32) -> Recurrence {
+        self.month_days.push(day);
+        self
+    }
+
+    /// only in a month, 1 to 12
+    pub fn in_month(mut self, month: u32) -> Recurrence {
+        self.months.push(month);
+        self
+    }
+
+    /// read an RRULE like `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE` with an optional `RRULE:` prefix
+    pub fn from_rrule(rule: &str, start: &Dayjs) -> Result<Recurrence, DayjsError> {
+        Ok(rule.parse::<Recurrence>()?.starting(start))
+    }
+
+    /// occurrences in order
+    pub fn iter(&self) -> impl Iterator<Item = Dayjs> + '_ {
+        let local = self.start.datetime().naive_local();
+        let mut empty = 0;
+        (0..)
+            .map_while(move |period: i64| {
+                let offset = period.checked_mul(i64::from(self.interval))?;
+                self.dates(offset)
+            })
+            .take_while(move |dates| {
+                empty = if dates.is_empty() { empty + 1 } else { 0 };
+                empty < self.frequency.cycle()
+            })
+            .flatten()
+            .filter(move |date| *date >= local.date())
+            .map(move |date| Dayjs {
+                tz: self.start.tz,
+                time: self.start.tz.local_to_utc(date.and_time(local.time())),
+            })
+            .take_while(move |date| match self.until {
+                None => true,
+                Some(Until::Instant(until)) => *date <= until,
+                Some(Until::Local(until)) => date.datetime().naive_local() <= until,
+            })
+            .take(self.count.unwrap_or(usize::MAX))
+    }
+
+    /// occurrences in `[from, to)`
+    pub fn between<'a>(&'a self, from: &Dayjs, to: &Dayjs) -> impl Iterator<Item = Dayjs> + 'a {
+        let (from, to) = (*from, *to);
+        self.iter()
+            .skip_while(move |date| *date < from)
+            .take_while(move |date| *date < to)
+    }
+
+    /// the first occurrence after `date`
+    pub fn next_after(&self, date: &Dayjs) -> Option<Dayjs> {
+        self.iter().find(|occurrence| occurrence > date)
+    }
+
+    /// dates of the period `offset` periods after the one of the start, in order; none once the
+    /// period is past the supported dates
+    fn dates(&self, offset: i64) -> Option<Vec<NaiveDate>> {
+        let start = self.start.datetime().date_naive();
+        let dates = match self.frequency {
+            Frequency::Daily => {
+                let date = start.checked_add_signed(Duration::try_days(offset)?)?;
+                let day_matches = |&(_, weekday): &(i32, Weekday)| weekday == date.weekday();
+                let keep = self.in_months(date.month())
+                    && (self.month_days.is_empty() || self.month_day_matches(date))
+                    && (self.weekdays.is_empty() || self.weekdays.iter().any(day_matches));
+                if keep {
+                    vec![date]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                let monday = start
+                    .checked_sub_signed(Duration::days(
+                        start.weekday().num_days_from_monday().into(),
+                    ))?
+                    .checked_add_signed(Duration::try_weeks(offset)?)?;
+                (0..7)
+                    .filter_map(|i| monday.checked_add_signed(Duration::days(i)))
+                    .filter(|date| match self.weekdays.is_empty() {
+                        true => date.weekday() == start.weekday(),
+                        false => self.weekdays.iter().any(|(_, w)| *w == date.weekday()),
+                    })
+                    .filter(|date| self.in_months(date.month()))
+                    .filter(|date| self.month_days.is_empty() || self.month_day_matches(*date))
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let month = (i64::from(start.year()) * 12 + i64::from(start.month0()))
+                    .checked_add(offset)?;
+                let year = supported_year(month.div_euclid(12))?;
+                let month = month.rem_euclid(12) as u32 + 1;
+                if self.in_months(month) {
+                    self.month_dates(year, month)
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Yearly => {
+                let year = supported_year(i64::from(start.year()).checked_add(offset)?)?;
+                (1..=12)
+                    .filter(|month| match self.months.is_empty() {
+                        true => *month == start.month(),
+                        false => self.months.contains(month),
+                    })
+                    .flat_map(|month| self.month_dates(year, month))
+                    .collect()
+            }
+        };
+        Some(dates)
+    }
+
+    fn in_months(&self, month: u32) -> bool {
+        self.months.is_empty() || self.months.contains(&month)
+    }
+
+    fn month_day_matches(&self, date: NaiveDate) -> bool {
+        let last = days_in_month(date.year(), date.month()) as i32;
+        let day = date.day() as i32;
+        self.month_days
+            .iter()
+            .any(|&d| d == day || d < 0 && last + 1 + d == day)
+    }
+
+    /// matching dates of a month, the day of the start without day rules
+    fn month_dates(&self, year: i32, month: u32) -> Vec<NaiveDate> {
+        let last = days_in_month(year, month);
+        let start = self.start.datetime();
+        if self.weekdays.is_empty() && self.month_days.is_empty() {
+            return NaiveDate::from_ymd_opt(year, month, start.day())
+                .into_iter()
+                .collect();
+        }
+        (1..=last)
+            .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+            .filter(|date| self.month_days.is_empty() || self.month_day_matches(*date))
+            .filter(|date| {
+                let nth = (date.day() as i32 - 1) / 7 + 1;
+                let nth_last = -((last - date.day()) as i32 / 7 + 1);
+                self.weekdays.is_empty()
+                    || self.weekdays.iter().any(|&(n, weekday)| {
+                        weekday == date.weekday() && (n == 0 || n == nth || n == nth_last)
+                    })
+            })
+            .collect()
+    }
+}
+
+/// a rule starting now, see [`Recurrence::starting`]
+impl FromStr for Recurrence {
+    type Err = DayjsError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let error = |message: &str| DayjsError::parse("RRULE", rule, message);
+        let number = |value: &str, min: i64, max: i64| {
+            value
+                .parse::<i64>()
+                .ok()
+                .filter(|n| (min..=max).contains(n) && *n != 0 || min == 0 && *n == 0)
+                .ok_or_else(|| error(&format!("{} is not between {} and {}", value, min, max)))
+        };
+        let mut frequency = None;
+        let mut recurrence = Recurrence::new(Frequency::Daily, &Dayjs::default());
+        let body = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| error(&format!("expected NAME=VALUE, found {:?}", part)))?;
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(error(&format!("unsupported FREQ {}", value))),
+                    })
+                }
+                "INTERVAL" => recurrence.interval = number(value, 1, i64::from(u32::MAX))? as u32,
+                "COUNT" => recurrence.count = Some(number(value, 0, i64::MAX)? as usize),
+                "UNTIL" => recurrence.until = Some(until(value).ok_or_else(|| error("bad UNTIL"))?),
+                "BYMONTH" => {
+                    for month in value.split(',') {
+                        recurrence.months.push(number(month, 1, 12)? as u32);
+                    }
+                }
+                "BYMONTHDAY" => {
+                    for day in value.split(',') {
+                        recurrence.month_days.push(number(day, -31, 31)? as i32);
+                    }
+                }
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        if !day.is_ascii() {
+                            return Err(error(&format!("unknown day {}", day)));
+                        }
+                        let split = day.len().saturating_sub(2);
+                        let weekday = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"]
+                            .iter()
+                            .position(|name| day[split..].eq_ignore_ascii_case(name))
+                            .and_then(|i| Weekday::try_from(i as u8).ok())
+                            .ok_or_else(|| error(&format!("unknown day {}", day)))?;
+                        let n = match &day[..split] {
+                            "" => 0,
+                            n => number(n.trim_start_matches('+'), -53, 53)? as i32,
+                        };
+                        recurrence.weekdays.push((n, weekday));
+                    }
+                }
+                _ => return Err(error(&format!("unsupported rule part {}", name))),
+            }
+        }
+        recurrence.frequency = frequency.ok_or_else(|| error("missing FREQ"))?;
+        Ok(recurrence)
+    }
+}
+
+/// `UNTIL` as `20240131T000000Z`, a local `20240131T000000` or the end of a day `20240131`
+fn until(value: &str) -> Option<Until> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        let time = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Until::Instant(Dayjs {
+            time: time.and_utc(),
+            ..Dayjs::default()
+        }));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y%m%d").map(|date| {
+                date.and_time(NaiveTime::MIN) + Duration::days(1) - Duration::nanoseconds(1)
+            })
+        })
+        .ok()
+        .map(Until::Local)
+}
@@ -0,0 +1,75 @@
+use crate::duration::{phrase, relative};
+use crate::locale::Locale;
+use crate::{dayjs, Dayjs, Unit};
+
+impl Dayjs {
+    /// relative time from now in the active locale, e.g. "3 hours ago" or "in 2 days"
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::Unit;
+    /// assert_eq!(dayjs::dayjs().subtract(3, Unit::Hour).from_now(), "3 hours ago");
+    /// assert_eq!(dayjs::dayjs().add(2, Unit::Day).from_now(), "in 2 days");
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_now(&self) -> String {
+        self.from(&dayjs())
+    }
+
+    /// relative time of now from this instance, e.g. "in 3 hours" for 3 hours ago
+    pub fn to_now(&self) -> String {
+        self.to(&dayjs())
+    }
+
+    /// relative time from `other`, e.g. "a day ago" when this is a day before it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a = dayjs::from_timestamp(1704443400);
+    /// let b = dayjs::from_timestamp(1704529800);
+    /// assert_eq!(a.from(&b), "a day ago");
+    /// assert_eq!(a.to(&b), "in a day");
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from(&self, other: &Dayjs) -> String {
+        self.relative_to(other, true, Locale::current())
+    }
+
+    /// relative time of `other` from this instance
+    pub fn to(&self, other: &Dayjs) -> String {
+        other.relative_to(self, true, Locale::current())
+    }
+
+    /// relative time from `other` in a locale, with or without the "in" / "ago" suffix
+    ///
+    /// uses the dayjs thresholds: up to 44 seconds is "a few seconds", up to 89 seconds "a minute",
+    /// up to 44 minutes in minutes and so on up to years
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a = dayjs::from_timestamp(1704443400);
+    /// let b = dayjs::from_timestamp(1717403400);
+    /// assert_eq!(a.relative_to(&b, true, &dayjs::locale::RU), "5 месяцев назад");
+    /// assert_eq!(b.relative_to(&a, false, &dayjs::locale::EN), "5 months");
+    /// ```
+    pub fn relative_to(&self, other: &Dayjs, with_suffix: bool, locale: &Locale) -> String {
+        let millis = (self.time - other.time).num_milliseconds() as f64;
+        let (p, n, future) = relative(|unit| match unit {
+            Unit::Month | Unit::Year => self.diff_f64(other, unit),
+            Unit::Day => millis / 86_400_000.0,
+            Unit::Hour => millis / 3_600_000.0,
+            Unit::Minute => millis / 60_000.0,
+            _ => millis / 1_000.0,
+        });
+        if !with_suffix {
+            return phrase(locale, p, n, None);
+        }
+        let text = phrase(locale, p, n, Some(future));
+        let rt = &locale.relative_time;
+        let template = if future { rt.future } else { rt.past };
+        template.replace("%s", &text)
+    }
+}
@@ -0,0 +1,193 @@
+use crate::locale::EN;
+use crate::parse::{self, Cursor};
+use crate::{parse_iso8601, Dayjs, DayjsError};
+use chrono::{NaiveDate, NaiveTime};
+
+/// RFC 822 zone names, in seconds east of UTC, `None` for universal time
+const ZONES: [(&str, Option<i32>); 12] = [
+    ("UT", None),
+    ("UTC", None),
+    ("GMT", None),
+    ("Z", None),
+    ("EST", Some(-5 * 3600)),
+    ("EDT", Some(-4 * 3600)),
+    ("CST", Some(-6 * 3600)),
+    ("CDT", Some(-5 * 3600)),
+    ("MST", Some(-7 * 3600)),
+    ("MDT", Some(-6 * 3600)),
+    ("PST", Some(-8 * 3600)),
+    ("PDT", Some(-7 * 3600)),
+];
+
+impl Dayjs {
+    /// format as an RSS `pubDate`, RFC 822 with a 4 digit year in the instance timezone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(date.to_rss_date(), "Fri, 05 Jan 2024 08:30:00 +0000");
+    /// ```
+    pub fn to_rss_date(&self) -> String {
+        self.format_locale("ddd, DD MMM YYYY HH:mm:ss ZZ", &EN)
+    }
+
+    /// format as an Atom date, RFC 3339 in the instance timezone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// assert_eq!(date.to_atom_date(), "2024-01-05T08:30:00+00:00");
+    /// ```
+    pub fn to_atom_date(&self) -> String {
+        self.format_locale("YYYY-MM-DD[T]HH:mm:ssZ", &EN)
+    }
+}
+
+fn month(name: &str) -> Option<u32> {
+    let name = name.trim_end_matches('.');
+    if name.len() < 3 || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    EN.months_short
+        .iter()
+        .position(|short| name[..3].eq_ignore_ascii_case(short))
+        .filter(|i| {
+            EN.months_standalone[*i]
+                .to_lowercase()
+                .starts_with(&name.to_lowercase())
+        })
+        .map(|i| i as u32 + 1)
+}
+
+fn is_weekday(name: &str) -> bool {
+    let name = name.trim_end_matches('.');
+    name.len() >= 3
+        && EN
+            .weekdays
+            .iter()
+            .any(|day| day.to_lowercase().starts_with(&name.to_lowercase()))
+}
+
+fn error(s: &str, message: &str) -> DayjsError {
+    DayjsError::parse("RSS date", s, message)
+}
+
+/// zone name or numeric offset, `None` for universal time
+fn zone(s: &str, token: &str) -> Result<Option<i32>, DayjsError> {
+    if let Some((_, offset)) = ZONES.iter().find(|(z, _)| z.eq_ignore_ascii_case(token)) {
+        return Ok(*offset);
+    }
+    // military zones are ambiguous in practice, RFC 2822 reads them as unknown
+    if token.len() == 1
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() && !c.eq_ignore_ascii_case(&'j'))
+    {
+        return Ok(None);
+    }
+    let mut z = Cursor::new(token, "RSS date zone");
+    let negative = match z.peek() {
+        Some(b'+') => false,
+        Some(b'-') => true,
+        _ => return Err(error(s, &format!("unknown zone {:?}", token))),
+    };
+    z.bump();
+    let offset = parse::offset(&mut z, negative, false)?;
+    if !z.at_end() {
+        return Err(error(s, &format!("unknown zone {:?}", token)));
+    }
+    // -0000 means the local offset is unknown
+    Ok((offset != 0 || !negative).then_some(offset))
+}
+
+/// a token of 1 to `max` digits
+fn number(s: &str, token: &str, what: &str, max: usize) -> Result<u32, DayjsError> {
+    if token.is_empty() || token.len() > max || !token.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(error(s, &format!("expected {}, found {:?}", what, token)));
+    }
+    Ok(token.parse().unwrap_or_default())
+}
+
+/// parse a feed date, tolerating the pseudo RFC 822 dates found in real feeds
+///
+/// weekdays are optional and not checked, month names may be spelled out, seconds and the zone
+/// may be missing, 2 digit years follow RFC 2822 (`< 50` is 20xx), the obsolete zone names and
+/// `UT` are understood and ISO 8601 dates, as used by Atom, are accepted too
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::rss::from_rss_date;
+/// let date = from_rss_date("Fri, 5 Jan 2024 03:30 EST").unwrap();
+/// assert_eq!(date.to_rss_date(), "Fri, 05 Jan 2024 03:30:00 -0500");
+/// let date = from_rss_date("friday, 05 january 24 08:30:00 UT").unwrap();
+/// assert_eq!(date.to_rss_date(), "Fri, 05 Jan 2024 08:30:00 +0000");
+/// ```
+pub fn from_rss_date(s: &str) -> Result<Dayjs, DayjsError> {
+    let trimmed = s.trim();
+    if trimmed.len() > 4 && trimmed.as_bytes()[4] == b'-' {
+        return parse_iso8601(trimmed);
+    }
+    // a trailing comment, e.g. "+0000 (UTC)"
+    let body = match (trimmed.rfind('('), trimmed.ends_with(')')) {
+        (Some(i), true) => &trimmed[..i],
+        _ => trimmed,
+    };
+    let mut tokens = body
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|t| !t.is_empty())
+        .peekable();
+    if tokens.peek().is_some_and(|t| is_weekday(t)) {
+        tokens.next();
+    }
+    let first = tokens.next().ok_or_else(|| error(s, "expected a day"))?;
+    let (day, month) = match month(first) {
+        // "Jan 05 2024"
+        Some(month) => (tokens.next().unwrap_or_default(), month),
+        None => {
+            let name = tokens.next().unwrap_or_default();
+            let month = month(name)
+                .ok_or_else(|| error(s, &format!("expected a month name, found {:?}", name)))?;
+            (first, month)
+        }
+    };
+    let day = number(s, day, "a day", 2)?;
+    let year = tokens.next().unwrap_or_default();
+    let year = match (year.len(), number(s, year, "a year", 4)?) {
+        (2, year) if year < 50 => year + 2000,
+        (2 | 3, year) => year + 1900,
+        (4, year) => year,
+        _ => return Err(error(s, &format!("expected a year, found {:?}", year))),
+    };
+    let time = tokens.next().unwrap_or_default();
+    let mut parts = time.split(':');
+    let hour = number(s, parts.next().unwrap_or_default(), "an hour", 2)?;
+    let minute = number(s, parts.next().unwrap_or_default(), "a minute", 2)?;
+    let second = match parts.next() {
+        // fractions are dropped
+        Some(second) => number(
+            s,
+            second.split('.').next().unwrap_or_default(),
+            "a second",
+            2,
+        )?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return Err(error(s, &format!("expected a time, found {:?}", time)));
+    }
+    let offset = match tokens.next() {
+        Some(token) => zone(s, token)?,
+        None => None,
+    };
+    if let Some(token) = tokens.next() {
+        return Err(error(s, &format!("unexpected {:?}", token)));
+    }
+    let date = NaiveDate::from_ymd_opt(year as i32, month, day)
+        .ok_or_else(|| error(s, "date out of range"))?;
+    let time = NaiveTime::from_hms_opt(hour, minute, second)
+        .ok_or_else(|| error(s, "time out of range"))?;
+    Ok(parse::zoned(date.and_time(time), offset))
+}
@@ -0,0 +1,272 @@
+use crate::duration::unit_millis;
+use crate::{Dayjs, Unit};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// a step between neighbours that is longer than expected, see [`find_gaps`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gap {
+    /// index of the instant before the gap
+    pub index: usize,
+    pub start: Dayjs,
+    pub end: Dayjs,
+}
+
+impl Gap {
+    /// time between the instants around the gap
+    pub fn duration(&self) -> Duration {
+        self.end.time - self.start.time
+    }
+}
+
+/// whether no instant is before the previous one, equal neighbours are allowed
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::series::is_sorted_ascending;
+/// let a = dayjs::from_timestamp(1704443400);
+/// let b = dayjs::from_timestamp(1704443460);
+/// assert!(is_sorted_ascending(&[a, a, b]));
+/// assert!(!is_sorted_ascending(&[b, a]));
+/// ```
+pub fn is_sorted_ascending(points: &[Dayjs]) -> bool {
+    points.windows(2).all(|w| w[0].time <= w[1].time)
+}
+
+/// steps between neighbours longer than `expected_step`
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use dayjs::Unit;
+/// let start = dayjs::from_timestamp(1704443400);
+/// let points = [start, start.add(1, Unit::Minute), start.add(5, Unit::Minute)];
+/// let gaps = dayjs::series::find_gaps(&points, Duration::minutes(1));
+/// assert_eq!(gaps.len(), 1);
+/// assert_eq!(gaps[0].index, 1);
+/// assert_eq!(gaps[0].duration(), Duration::minutes(4));
+/// ```
+pub fn find_gaps(points: &[Dayjs], expected_step: Duration) -> Vec<Gap> {
+    points
+        .windows(2)
+        .enumerate()
+        .filter(|(_, w)| w[1].time - w[0].time > expected_step)
+        .map(|(index, w)| Gap {
+            index,
+            start: w[0],
+            end: w[1],
+        })
+        .collect()
+}
+
+/// groups of instants that fall in the same `unit`, e.g. two readings within one minute
+///
+/// each group is the start of the unit and the indexes in it, groups are ordered by their first
+/// index and only contain more than one instant
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::Unit;
+/// let start = dayjs::from_timestamp(1704443400);
+/// let points = [start, start.add(70, Unit::Second), start.add(30, Unit::Second)];
+/// let groups = dayjs::series::find_duplicates_by_unit(&points, Unit::Minute);
+/// assert_eq!(groups, vec![(start, vec![0, 2])]);
+/// ```
+pub fn find_duplicates_by_unit(points: &[Dayjs], unit: Unit) -> Vec<(Dayjs, Vec<usize>)> {
+    let mut groups: Vec<(Dayjs, Vec<usize>)> = Vec::new();
+    let mut by_start = HashMap::new();
+    for (index, point) in points.iter().enumerate() {
+        let start = point.start_of_unit(unit);
+        let group = *by_start.entry(start.time).or_insert_with(|| {
+            groups.push((start, Vec::new()));
+            groups.len() - 1
+        });
+        groups[group].1.push(index);
+    }
+    groups.retain(|(_, indexes)| indexes.len() > 1);
+    groups
+}
+
+/// how [`resample`] fills grid instants without a point
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FillPolicy {
+    /// the value of the previous point
+    ForwardFill,
+    /// `0.0`, each point counts for the grid instant at or before it, so no point is dropped
+    Zero,
+    /// linear between the previous and the next point
+    Interpolate,
+}
+
+/// sample points onto a regular grid every `step`, from the first point to the last
+///
+/// points are sorted by time first, a grid instant takes the value of the point at that instant,
+/// the last one if several are, and is filled by `fill` otherwise; with [`FillPolicy::Zero`] the
+/// points are floored to the grid first, an instant takes the last point of its step; an empty
+/// input or a step that is not positive gives an empty grid
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use dayjs::series::{resample, FillPolicy};
+/// use dayjs::Unit;
+/// let start = dayjs::from_timestamp(1704443400);
+/// let points = [(start, 1.0), (start.add(3, Unit::Minute), 4.0)];
+/// let grid = resample(&points, Duration::minutes(1), FillPolicy::Interpolate);
+/// let values: Vec<f64> = grid.iter().map(|(_, v)| *v).collect();
+/// assert_eq!(values, [1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(grid[1].0.format("HH:mm"), "08:31");
+/// ```
+pub fn resample(points: &[(Dayjs, f64)], step: Duration, fill: FillPolicy) -> Vec<(Dayjs, f64)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by_key(|(date, _)| date.time);
+    let (Some(first), Some(last)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    if step <= Duration::zero() {
+        return Vec::new();
+    }
+    let (first, end) = (first.0, last.0.time);
+    let mut grid = Vec::new();
+    let mut at = first.time;
+    // index of the last point at or before `at`
+    let mut prev = 0;
+    while at <= end {
+        let next = at.checked_add_signed(step);
+        // a point before the next instant belongs to this one when floored to the grid
+        let floored = |time| fill == FillPolicy::Zero && next.is_none_or(|next| time < next);
+        while prev + 1 < sorted.len()
+            && (sorted[prev + 1].0.time <= at || floored(sorted[prev + 1].0.time))
+        {
+            prev += 1;
+        }
+        let (before, value) = sorted[prev];
+        let value = if before.time == at {
+            value
+        } else {
+            match fill {
+                FillPolicy::ForwardFill => value,
+                FillPolicy::Zero if before.time > at => value,
+                FillPolicy::Zero => 0.0,
+                FillPolicy::Interpolate => {
+                    let (after, next) = sorted[prev + 1];
+                    let span = (after.time - before.time).num_milliseconds() as f64;
+                    let passed = (at - before.time).num_milliseconds() as f64;
+                    value + (next - value) * passed / span
+                }
+            }
+        };
+        grid.push((Dayjs { time: at, ..first }, value));
+        match next {
+            Some(next) => at = next,
+            None => break,
+        }
+    }
+    grid
+}
+
+/// nanoseconds in a duration
+fn nanos(d: Duration) -> i128 {
+    i128::from(d.num_seconds()) * 1_000_000_000 + i128::from(d.subsec_nanos())
+}
+
+impl Dayjs {
+    /// the latest boundary at or before this instant, boundaries are every `step` from the unix
+    /// epoch moved by `offset`; a step that is not positive leaves the instance unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// let date = dayjs::from_timestamp(1704443415);
+    /// let aligned = date.align_to(Duration::minutes(5), Duration::zero());
+    /// assert_eq!(aligned.to_string(), "2024-01-05T08:30:00+00:00");
+    /// // days in +05:30 start at 18:30 UTC
+    /// let aligned = date.align_to(Duration::days(1), Duration::minutes(-330));
+    /// assert_eq!(aligned.to_string(), "2024-01-04T18:30:00+00:00");
+    /// ```
+    pub fn align_to(&self, step: Duration, offset: Duration) -> Dayjs {
+        let step = nanos(step);
+        if step <= 0 {
+            return *self;
+        }
+        let shifted = nanos(self.time - DateTime::UNIX_EPOCH) - nanos(offset);
+        let back = shifted.rem_euclid(step);
+        // a boundary before the supported range clamps to its first instant
+        let time = Duration::try_seconds((back / 1_000_000_000) as i64)
+            .and_then(|secs| {
+                secs.checked_add(&Duration::nanoseconds((back % 1_000_000_000) as i64))
+            })
+            .and_then(|back| self.time.checked_sub_signed(back))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        Dayjs { time, ..*self }
+    }
+
+    /// whether the instant is on a boundary every `step` from the unix epoch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// assert!(date.is_aligned(Duration::minutes(10)));
+    /// assert!(!date.is_aligned(Duration::hours(1)));
+    /// ```
+    pub fn is_aligned(&self, step: Duration) -> bool {
+        step > Duration::zero() && self.align_to(step, Duration::zero()) == *self
+    }
+}
+
+/// sorted copy of the instants
+fn sorted_times(points: &[Dayjs]) -> Vec<DateTime<Utc>> {
+    let mut times: Vec<_> = points.iter().map(|p| p.time).collect();
+    times.sort();
+    times
+}
+
+/// average rate of events per `unit` between the first and the last instant, `None` for fewer
+/// than two instants or no time between them; months and years are averaged like durations
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::Unit;
+/// let start = dayjs::from_timestamp(1704443400);
+/// let points: Vec<_> = (0..31).map(|i| start.add(i * 2, Unit::Second)).collect();
+/// assert_eq!(dayjs::series::events_per(Unit::Minute, &points), Some(30.0));
+/// ```
+pub fn events_per(unit: Unit, points: &[Dayjs]) -> Option<f64> {
+    let times = sorted_times(points);
+    let span = (*times.last()? - *times.first()?).num_milliseconds();
+    if span == 0 {
+        return None;
+    }
+    Some((times.len() - 1) as f64 * unit_millis(unit) / span as f64)
+}
+
+/// median time between neighbouring instants, after sorting; zero for fewer than two instants
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use dayjs::Unit;
+/// let start = dayjs::from_timestamp(1704443400);
+/// let points = [0, 10, 20, 90, 100].map(|s| start.add(s, Unit::Second));
+/// assert_eq!(dayjs::series::median_interval(&points), Duration::seconds(10));
+/// ```
+pub fn median_interval(points: &[Dayjs]) -> Duration {
+    let times = sorted_times(points);
+    let mut intervals: Vec<Duration> = times.windows(2).map(|w| w[1] - w[0]).collect();
+    intervals.sort();
+    let middle = intervals.len() / 2;
+    match intervals.len() {
+        0 => Duration::zero(),
+        n if n % 2 == 1 => intervals[middle],
+        _ => (intervals[middle - 1] + intervals[middle]) / 2,
+    }
+}
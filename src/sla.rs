@@ -0,0 +1,62 @@
+use crate::interval::{merged, overlap, total};
+use crate::Interval;
+use chrono::Duration;
+
+/// time in `window` that counts towards an SLA, the window without maintenance
+fn eligible(window: &Interval, maintenance: &[Interval]) -> Duration {
+    window.duration() - total(&merged(maintenance, window))
+}
+
+/// downtime in `window`, incidents may overlap each other, time under maintenance is excluded
+pub fn downtime(incidents: &[Interval], window: &Interval, maintenance: &[Interval]) -> Duration {
+    let incidents = merged(incidents, window);
+    total(&incidents) - overlap(&incidents, &merged(maintenance, window))
+}
+
+/// share of the window without incidents, maintenance is left out of both sides; 1.0 when the
+/// whole window is maintenance
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{Interval, Unit};
+/// let start = dayjs::from_timestamp(1704067200);
+/// let window = Interval::new(start, start.add(30, Unit::Day));
+/// let outage = start.add(3, Unit::Day);
+/// let incidents = [Interval::new(outage, outage.add(216, Unit::Minute))];
+/// assert_eq!(dayjs::sla::uptime_ratio(&incidents, window, &[]), 0.995);
+/// ```
+pub fn uptime_ratio(incidents: &[Interval], window: Interval, maintenance: &[Interval]) -> f64 {
+    let eligible = eligible(&window, maintenance).num_milliseconds();
+    if eligible <= 0 {
+        return 1.0;
+    }
+    let down = downtime(incidents, &window, maintenance).num_milliseconds();
+    1.0 - down as f64 / eligible as f64
+}
+
+/// downtime an uptime `target` such as 0.999 allows in the window
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::{Interval, Unit};
+/// let start = dayjs::from_timestamp(1704067200);
+/// let window = Interval::new(start, start.add(30, Unit::Day));
+/// let budget = dayjs::sla::error_budget(0.999, window, &[]);
+/// assert_eq!(budget, chrono::Duration::seconds(2592));
+/// ```
+pub fn error_budget(target: f64, window: Interval, maintenance: &[Interval]) -> Duration {
+    let eligible = eligible(&window, maintenance).num_milliseconds() as f64;
+    Duration::milliseconds((eligible * (1.0 - target)).round() as i64)
+}
+
+/// error budget left after the incidents, negative once it is exceeded
+pub fn error_budget_remaining(
+    target: f64,
+    incidents: &[Interval],
+    window: Interval,
+    maintenance: &[Interval],
+) -> Duration {
+    error_budget(target, window, maintenance) - downtime(incidents, &window, maintenance)
+}
@@ -0,0 +1,131 @@
+use crate::{Dayjs, Interval, Unit};
+
+/// SQL flavour of the literals and placeholders made by [`between_clause`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// `timestamptz` literals and `$1` placeholders
+    Postgres,
+    /// `DATETIME` literals in UTC and `?` placeholders
+    MySql,
+    /// ISO 8601 text in UTC, as SQLite stores dates, and `?` placeholders
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// a column name, each part of a qualified `table.column` quoted on its own
+    pub fn quote_identifier(&self, name: &str) -> String {
+        let quote = match self {
+            SqlDialect::MySql => '`',
+            SqlDialect::Postgres | SqlDialect::Sqlite => '"',
+        };
+        let doubled = format!("{}{}", quote, quote);
+        name.split('.')
+            .map(|part| format!("{}{}{}", quote, part.replace(quote, &doubled), quote))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// the value to bind for an instant, UTC with microseconds
+    pub fn bind_value(&self, date: &Dayjs) -> String {
+        let time = date.time.naive_utc();
+        match self {
+            SqlDialect::Postgres => time.format("%Y-%m-%d %H:%M:%S%.6f+00").to_string(),
+            SqlDialect::MySql => time.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+            SqlDialect::Sqlite => time.format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string(),
+        }
+    }
+
+    /// an instant as a quoted literal
+    pub fn literal(&self, date: &Dayjs) -> String {
+        let value = self.bind_value(date);
+        match self {
+            SqlDialect::Postgres => format!("TIMESTAMPTZ '{}'", value),
+            SqlDialect::MySql | SqlDialect::Sqlite => format!("'{}'", value),
+        }
+    }
+
+    /// placeholders for the two bounds of a clause
+    fn placeholders(&self) -> [&'static str; 2] {
+        match self {
+            SqlDialect::Postgres => ["$1", "$2"],
+            SqlDialect::MySql | SqlDialect::Sqlite => ["?", "?"],
+        }
+    }
+}
+
+/// `column >= start AND column < end` with literal bounds, the half-open interval that keeps
+/// rows at the very end of a range out of the next one, unlike `BETWEEN`
+///
+/// the bounds are UTC instants, so the column should hold UTC for [`SqlDialect::MySql`] and
+/// [`SqlDialect::Sqlite`]
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::sql::{between_clause, SqlDialect};
+/// use dayjs::{DayjsTimeZone, Unit};
+/// let date = dayjs::from_timestamp(1715243415).tz(DayjsTimeZone::Offset(9 * 3600));
+/// let clause = between_clause("orders.created_at", date.bounds_of(Unit::Month), SqlDialect::Postgres);
+/// assert_eq!(
+///     clause,
+///     "\"orders\".\"created_at\" >= TIMESTAMPTZ '2024-04-30 15:00:00.000000+00' \
+///      AND \"orders\".\"created_at\" < TIMESTAMPTZ '2024-05-31 15:00:00.000000+00'"
+/// );
+/// ```
+pub fn between_clause(column: &str, range: impl Into<Interval>, dialect: SqlDialect) -> String {
+    let range = range.into();
+    let column = dialect.quote_identifier(column);
+    format!(
+        "{} >= {} AND {} < {}",
+        column,
+        dialect.literal(&range.start),
+        column,
+        dialect.literal(&range.end)
+    )
+}
+
+/// [`between_clause`] with placeholders, and the values to bind to them in order
+///
+/// # Examples
+///
+/// ```
+/// use dayjs::sql::{between_params, SqlDialect};
+/// let date = dayjs::from_timestamp(1715243415);
+/// let (clause, values) = between_params("ts", date.bounds_of(dayjs::Unit::Day), SqlDialect::Sqlite);
+/// assert_eq!(clause, "\"ts\" >= ? AND \"ts\" < ?");
+/// assert_eq!(values[1], "2024-05-10T00:00:00.000000Z");
+/// ```
+pub fn between_params(
+    column: &str,
+    range: impl Into<Interval>,
+    dialect: SqlDialect,
+) -> (String, [String; 2]) {
+    let range = range.into();
+    let column = dialect.quote_identifier(column);
+    let [start, end] = dialect.placeholders();
+    let clause = format!("{} >= {} AND {} < {}", column, start, column, end);
+    let values = [
+        dialect.bind_value(&range.start),
+        dialect.bind_value(&range.end),
+    ];
+    (clause, values)
+}
+
+impl Dayjs {
+    /// [`between_clause`] for the unit of this instance in its timezone, e.g. this month for the
+    /// user's zone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::sql::SqlDialect;
+    /// let date = dayjs::from_timestamp(1715243415);
+    /// assert_eq!(
+    ///     date.sql_between_clause("created_at", dayjs::Unit::Year, SqlDialect::MySql),
+    ///     "`created_at` >= '2024-01-01 00:00:00.000000' AND `created_at` < '2025-01-01 00:00:00.000000'"
+    /// );
+    /// ```
+    pub fn sql_between_clause(&self, column: &str, unit: Unit, dialect: SqlDialect) -> String {
+        between_clause(column, self.bounds_of(unit), dialect)
+    }
+}
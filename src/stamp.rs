@@ -0,0 +1,87 @@
+use crate::parse::{self, Cursor};
+use crate::{Dayjs, DayjsError};
+
+/// style of [`Dayjs::to_filename_stamp`], both are in UTC and sort by time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StampStyle {
+    /// `20250325T172147Z`
+    Compact,
+    /// `2025-03-25_17-21-47`
+    Readable,
+}
+
+impl StampStyle {
+    fn template(self) -> &'static str {
+        match self {
+            StampStyle::Compact => "%Y%m%dT%H%M%SZ",
+            StampStyle::Readable => "%Y-%m-%d_%H-%M-%S",
+        }
+    }
+}
+
+impl Dayjs {
+    /// timestamp that is safe in file names on every OS and sorts by time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::StampStyle;
+    /// let date = dayjs::from_timestamp(1742923307);
+    /// assert_eq!(date.to_filename_stamp(StampStyle::Compact), "20250325T172147Z");
+    /// assert_eq!(date.to_filename_stamp(StampStyle::Readable), "2025-03-25_17-21-47");
+    /// ```
+    pub fn to_filename_stamp(&self, style: StampStyle) -> String {
+        self.time.format(style.template()).to_string()
+    }
+}
+
+/// parse a stamp at the start of `s`
+fn stamp(s: &str, style: StampStyle) -> Result<Dayjs, DayjsError> {
+    let mut c = Cursor::new(s, "filename stamp");
+    let (date_sep, between, time_sep) = match style {
+        StampStyle::Compact => (None, b'T', None),
+        StampStyle::Readable => (Some(b'-'), b'_', Some(b'-')),
+    };
+    let separator = |c: &mut Cursor, sep: Option<u8>| sep.map_or(Ok(()), |sep| c.expect(sep));
+    let year = c.digits(4, "year")?;
+    separator(&mut c, date_sep)?;
+    let month = c.digits(2, "month")?;
+    separator(&mut c, date_sep)?;
+    let day = c.digits(2, "day")?;
+    c.expect(between)?;
+    let hour = c.digits(2, "hour")?;
+    separator(&mut c, time_sep)?;
+    let minute = c.digits(2, "minute")?;
+    separator(&mut c, time_sep)?;
+    let second = c.digits(2, "second")?;
+    if style == StampStyle::Compact {
+        c.expect(b'Z')?;
+    }
+    let date = parse::date(&c, year, month, day)?;
+    let time = parse::time(&c, hour, minute, second, 0)?;
+    Ok(parse::zoned(date.and_time(time), None))
+}
+
+/// find the first filename stamp of either style in `s`, e.g. in `backup-20250325T172147Z.tar`
+///
+/// # Examples
+///
+/// ```
+/// let date = dayjs::from_filename_stamp("backup_2025-03-25_17-21-47.tar.gz").unwrap();
+/// assert_eq!(date.to_string(), "2025-03-25T17:21:47+00:00");
+/// assert!(dayjs::from_filename_stamp("backup.tar.gz").is_err());
+/// ```
+pub fn from_filename_stamp(s: &str) -> Result<Dayjs, DayjsError> {
+    for (start, _) in s.char_indices() {
+        // a stamp never starts in the middle of a number
+        if start > 0 && s.as_bytes()[start - 1].is_ascii_digit() {
+            continue;
+        }
+        for style in [StampStyle::Compact, StampStyle::Readable] {
+            if let Ok(date) = stamp(&s[start..], style) {
+                return Ok(date);
+            }
+        }
+    }
+    Err(DayjsError::parse("filename stamp", s, "no stamp found"))
+}
@@ -0,0 +1,551 @@
+use crate::locale::{Locale, ZONE_ALIASES};
+use crate::warning::{warn, Warning};
+use crate::Dayjs;
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use std::borrow::Cow;
+
+mod zone_tab;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayjsTimeZone {
+    /// UTC
+    DEFAULT,
+    /// fixed offset, seconds east of UTC
+    Offset(i32),
+    /// the system timezone
+    Local,
+    /// IANA zone such as "Asia/Shanghai"
+    #[cfg(feature = "tz")]
+    City(chrono_tz::Tz),
+}
+
+/// style of [`DayjsTimeZone::display_name`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NameStyle {
+    /// "China Standard Time"
+    Long,
+    /// "PST", most zones have no abbreviation and use the GMT format
+    Short,
+    /// "GMT+8"
+    Offset,
+}
+
+/// style of [`Dayjs::offset_string`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OffsetStyle {
+    /// "+08:00"
+    Colon,
+    /// "+0800"
+    Basic,
+    /// "UTC+8", "UTC+5:30", "UTC"
+    Utc,
+    /// "GMT+08:00"
+    Gmt,
+}
+
+/// render an offset in seconds east of UTC
+pub(crate) fn format_offset(offset: i32, style: OffsetStyle) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes) = (offset / 3600, offset / 60 % 60);
+    match style {
+        OffsetStyle::Colon => format!("{}{:02}:{:02}", sign, hours, minutes),
+        OffsetStyle::Basic => format!("{}{:02}{:02}", sign, hours, minutes),
+        OffsetStyle::Utc if offset == 0 => "UTC".to_string(),
+        OffsetStyle::Utc if minutes == 0 => format!("UTC{}{}", sign, hours),
+        OffsetStyle::Utc => format!("UTC{}{}:{:02}", sign, hours, minutes),
+        OffsetStyle::Gmt => format!("GMT{}{:02}:{:02}", sign, hours, minutes),
+    }
+}
+
+impl Dayjs {
+    /// offset of the instance timezone at its instant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dayjs::{DayjsTimeZone, OffsetStyle};
+    /// let mut date = dayjs::from_timestamp(1705320000);
+    /// date.tz = DayjsTimeZone::Offset(8 * 3600);
+    /// assert_eq!(date.offset_string(OffsetStyle::Colon), "+08:00");
+    /// assert_eq!(date.offset_string(OffsetStyle::Basic), "+0800");
+    /// assert_eq!(date.offset_string(OffsetStyle::Utc), "UTC+8");
+    /// assert_eq!(date.offset_string(OffsetStyle::Gmt), "GMT+08:00");
+    /// ```
+    pub fn offset_string(&self, style: OffsetStyle) -> String {
+        format_offset(self.tz.offset_at(&self.time).local_minus_utc(), style)
+    }
+
+    /// the same instant in another timezone, getters, `format` and `start_of` then use its wall
+    /// clock, like dayjs `.tz()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// use dayjs::DayjsTimeZone;
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// let tokyo = date.tz(DayjsTimeZone::city("Asia/Tokyo").unwrap());
+    /// assert_eq!(tokyo.hour(), 17);
+    /// assert_eq!(tokyo.format("YYYY-MM-DD HH:mm Z"), "2024-01-05 17:30 +09:00");
+    /// assert_eq!(tokyo, date.tz(DayjsTimeZone::city("Asia/Tokyo").unwrap()));
+    /// assert_eq!(tokyo.to_string(), date.to_string());
+    /// # }
+    /// ```
+    pub fn tz(&self, tz: DayjsTimeZone) -> Dayjs {
+        Dayjs { tz, ..*self }
+    }
+
+    /// the same instant in UTC mode, like the dayjs utc plugin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400).set_utc_offset(480, false);
+    /// assert_eq!(date.format("HH:mm"), "16:30");
+    /// assert_eq!(date.utc().format("HH:mm"), "08:30");
+    /// assert!(date.utc().is_utc());
+    /// ```
+    pub fn utc(&self) -> Dayjs {
+        self.tz(DayjsTimeZone::DEFAULT)
+    }
+
+    /// the same instant in the system timezone
+    pub fn local(&self) -> Dayjs {
+        self.tz(DayjsTimeZone::Local)
+    }
+
+    /// whether the instance is in UTC mode
+    pub fn is_utc(&self) -> bool {
+        self.tz == DayjsTimeZone::DEFAULT
+    }
+
+    /// offset from UTC in minutes at this instant, like dayjs `utcOffset()`
+    pub fn utc_offset(&self) -> i32 {
+        self.tz.offset_at(&self.time).local_minus_utc() / 60
+    }
+
+    /// abbreviation of the zone at this instant from the tz database, e.g. "PST", "CET" or
+    /// "IST"; UTC is "UTC", and zones without a lettered abbreviation use the GMT format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// use dayjs::DayjsTimeZone;
+    /// let date = dayjs::from_timestamp(1705320000);
+    /// assert_eq!(date.tz(DayjsTimeZone::city("Europe/Paris").unwrap()).zone_abbr(), "CET");
+    /// assert_eq!(date.tz(DayjsTimeZone::city("Asia/Kolkata").unwrap()).zone_abbr(), "IST");
+    /// assert_eq!(date.tz(DayjsTimeZone::Offset(-3 * 3600)).zone_abbr(), "GMT-3");
+    /// # }
+    /// ```
+    pub fn zone_abbr(&self) -> String {
+        match self.tz {
+            DayjsTimeZone::DEFAULT => "UTC".to_string(),
+            _ => self.tz.tzdb_abbr(&self.time).unwrap_or_else(|| {
+                gmt_format(
+                    &crate::locale::EN,
+                    self.tz.offset_at(&self.time).local_minus_utc(),
+                )
+            }),
+        }
+    }
+
+    /// whether daylight saving time is in effect at this instant, `None` unless the instance is
+    /// in a city timezone; fixed offsets, UTC and the system zone cannot tell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// use dayjs::DayjsTimeZone;
+    /// let paris = DayjsTimeZone::city("Europe/Paris").unwrap();
+    /// let date = dayjs::from_timestamp(1720009800);
+    /// assert_eq!(date.tz(paris).is_dst(), Some(true));
+    /// assert_eq!(date.add(6, dayjs::Unit::Month).tz(paris).is_dst(), Some(false));
+    /// assert_eq!(date.tz(DayjsTimeZone::Offset(7200)).is_dst(), None);
+    /// # }
+    /// ```
+    pub fn is_dst(&self) -> Option<bool> {
+        match self.tz {
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(_) => Some(self.tz.dst_at(&self.time)),
+            _ => None,
+        }
+    }
+
+    /// switch to a fixed offset in minutes, like dayjs `utcOffset(minutes, keepLocalTime)`
+    ///
+    /// the instant stays the same, unless `keep_local_time` is set, then the instant moves so the
+    /// wall clock stays the same; offsets of a day or more are clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let date = dayjs::from_timestamp(1704443400);
+    /// let shifted = date.set_utc_offset(120, false);
+    /// assert_eq!(shifted.format("HH:mm Z"), "10:30 +02:00");
+    /// let kept = date.set_utc_offset(120, true);
+    /// assert_eq!(kept.format("HH:mm Z"), "08:30 +02:00");
+    /// assert_eq!(kept.to_string(), "2024-01-05T06:30:00+00:00");
+    /// ```
+    pub fn set_utc_offset(&self, minutes: i32, keep_local_time: bool) -> Dayjs {
+        let minutes = minutes.clamp(-(24 * 60 - 1), 24 * 60 - 1);
+        let tz = DayjsTimeZone::Offset(minutes * 60);
+        let time = if keep_local_time {
+            tz.local_to_utc(self.datetime().naive_local())
+        } else {
+            self.time
+        };
+        Dayjs { tz, time }
+    }
+}
+
+impl DayjsTimeZone {
+    /// get a city timezone by IANA name
+    #[cfg(feature = "tz")]
+    pub fn city(name: &str) -> Option<DayjsTimeZone> {
+        name.parse().ok().map(DayjsTimeZone::City)
+    }
+
+    /// IANA names [`DayjsTimeZone::city`] accepts, links like "US/Eastern" included, for
+    /// timezone pickers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// use dayjs::DayjsTimeZone;
+    /// assert!(DayjsTimeZone::available_zones().any(|name| name == "Asia/Shanghai"));
+    /// assert!(DayjsTimeZone::is_valid_city("Europe/Berlin"));
+    /// assert!(!DayjsTimeZone::is_valid_city("Europe/Atlantis"));
+    /// # }
+    /// ```
+    #[cfg(feature = "tz")]
+    pub fn available_zones() -> impl Iterator<Item = &'static str> {
+        chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.name())
+    }
+
+    /// whether `name` is an IANA zone [`DayjsTimeZone::city`] accepts, to check user input
+    #[cfg(feature = "tz")]
+    pub fn is_valid_city(name: &str) -> bool {
+        DayjsTimeZone::city(name).is_some()
+    }
+
+    /// offset from UTC at `time`, invalid fixed offsets fall back to UTC with a [`Warning`]
+    pub(crate) fn offset_at(&self, time: &DateTime<Utc>) -> FixedOffset {
+        match self {
+            DayjsTimeZone::DEFAULT => Utc.fix(),
+            DayjsTimeZone::Offset(seconds) => {
+                FixedOffset::east_opt(*seconds).unwrap_or_else(|| {
+                    warn(|| Warning::InvalidOffset(*seconds));
+                    Utc.fix()
+                })
+            }
+            DayjsTimeZone::Local => chrono::Local
+                .offset_from_utc_datetime(&time.naive_utc())
+                .fix(),
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(tz) => tz.offset_from_utc_datetime(&time.naive_utc()).fix(),
+        }
+    }
+
+    /// instant of a wall clock time in this zone
+    ///
+    /// ambiguous times take the earlier instant, times skipped by a DST gap keep the offset from
+    /// before the gap and so land after it
+    pub(crate) fn local_to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        match self {
+            DayjsTimeZone::DEFAULT | DayjsTimeZone::Offset(_) => {
+                at_offset(local, self.offset_at(&local.and_utc()).local_minus_utc())
+            }
+            DayjsTimeZone::Local => self.resolve(local, chrono::Local.from_local_datetime(&local)),
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(tz) => self.resolve(local, tz.from_local_datetime(&local)),
+        }
+    }
+
+    /// total order of zones, used to break ties between equal instants
+    pub(crate) fn sort_key(&self) -> (u8, i32, &'static str) {
+        match self {
+            DayjsTimeZone::DEFAULT => (0, 0, ""),
+            DayjsTimeZone::Offset(seconds) => (1, *seconds, ""),
+            DayjsTimeZone::Local => (2, 0, ""),
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(tz) => (3, 0, tz.name()),
+        }
+    }
+
+    /// pick the instant of a wall clock time in a zone with DST, see [`Self::local_to_utc`]
+    fn resolve<T: TimeZone>(
+        &self,
+        local: NaiveDateTime,
+        result: LocalResult<DateTime<T>>,
+    ) -> DateTime<Utc> {
+        match result {
+            LocalResult::Single(time) | LocalResult::Ambiguous(time, _) => time.to_utc(),
+            LocalResult::None => {
+                warn(|| Warning::SkippedLocalTime(local));
+                let before = local.checked_sub_signed(Duration::days(1)).unwrap_or(local);
+                at_offset(local, self.offset_at(&before.and_utc()).local_minus_utc())
+            }
+        }
+    }
+
+    /// whether daylight saving time is in effect at `time`
+    #[cfg_attr(not(feature = "tz"), allow(unused_variables))]
+    fn dst_at(&self, time: &DateTime<Utc>) -> bool {
+        match self {
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(tz) => {
+                use chrono_tz::OffsetComponents;
+                !tz.offset_from_utc_datetime(&time.naive_utc())
+                    .dst_offset()
+                    .is_zero()
+            }
+            _ => false,
+        }
+    }
+
+    /// lettered tz database abbreviation at `time`, none for numeric ones like "+08"
+    #[cfg_attr(not(feature = "tz"), allow(unused_variables))]
+    fn tzdb_abbr(&self, time: &DateTime<Utc>) -> Option<String> {
+        match self {
+            #[cfg(feature = "tz")]
+            DayjsTimeZone::City(tz) => {
+                use chrono_tz::OffsetName;
+                tz.offset_from_utc_datetime(&time.naive_utc())
+                    .abbreviation()
+                    .filter(|abbr| abbr.bytes().all(|b| b.is_ascii_alphabetic()))
+                    .map(str::to_string)
+            }
+            _ => None,
+        }
+    }
+
+    /// name of the `z` token: the short name in `locale`, then the tz database
+    /// abbreviation, then the GMT format
+    pub(crate) fn short_name_at(&self, at: &Dayjs, locale: &Locale) -> Cow<'static, str> {
+        let short = match self {
+            DayjsTimeZone::DEFAULT => Some(locale.utc_names[1]),
+            _ => self
+                .zone_names(locale)
+                .map(|names| names[2 + usize::from(self.dst_at(&at.time))]),
+        };
+        match short.filter(|name| !name.is_empty()) {
+            Some(name) => Cow::Borrowed(name),
+            None => match self.tzdb_abbr(&at.time) {
+                Some(abbr) => Cow::Owned(abbr),
+                None => self.name_at(at, locale, NameStyle::Offset),
+            },
+        }
+    }
+
+    /// metazone names of the zone in `locale`
+    #[cfg(feature = "tz")]
+    fn zone_names(&self, locale: &Locale) -> Option<[&'static str; 4]> {
+        use crate::locale::METAZONES;
+        let DayjsTimeZone::City(tz) = self else {
+            return None;
+        };
+        let zone = data_zone(tz.name());
+        let metazone = METAZONES
+            .binary_search_by_key(&zone, |(zone, _)| zone)
+            .ok()
+            .map(|i| METAZONES[i].1)?;
+        let names = &locale.zone_names;
+        names
+            .binary_search_by_key(&metazone, |(metazone, _)| metazone)
+            .ok()
+            .map(|i| names[i].1)
+    }
+
+    #[cfg(not(feature = "tz"))]
+    fn zone_names(&self, _locale: &Locale) -> Option<[&'static str; 4]> {
+        None
+    }
+
+    /// localized name at the current instant, e.g. "China Standard Time" or "GMT+8"
+    pub fn display_name(&self, locale: &Locale, style: NameStyle) -> String {
+        self.display_name_at(&crate::dayjs(), locale, style)
+    }
+
+    /// localized name at `at`, which selects standard or daylight names and the GMT offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tz")] {
+    /// use dayjs::{DayjsTimeZone, NameStyle};
+    /// let tz = DayjsTimeZone::city("Asia/Shanghai").unwrap();
+    /// let at = dayjs::from_timestamp(1705320000);
+    /// assert_eq!(tz.display_name_at(&at, &dayjs::locale::EN, NameStyle::Long), "China Standard Time");
+    /// assert_eq!(tz.display_name_at(&at, &dayjs::locale::EN, NameStyle::Offset), "GMT+8");
+    /// # }
+    /// ```
+    pub fn display_name_at(&self, at: &Dayjs, locale: &Locale, style: NameStyle) -> String {
+        self.name_at(at, locale, style).into_owned()
+    }
+
+    /// [`DayjsTimeZone::display_name_at`] borrowing the locale names, for the format tokens
+    pub(crate) fn name_at(
+        &self,
+        at: &Dayjs,
+        locale: &Locale,
+        style: NameStyle,
+    ) -> Cow<'static, str> {
+        let dst = usize::from(self.dst_at(&at.time));
+        let name = match (self, style) {
+            (_, NameStyle::Offset) => "",
+            (DayjsTimeZone::DEFAULT, NameStyle::Long) => locale.utc_names[0],
+            (DayjsTimeZone::DEFAULT, NameStyle::Short) => locale.utc_names[1],
+            (_, NameStyle::Long) => self.zone_names(locale).map_or("", |names| names[dst]),
+            (_, NameStyle::Short) => self.zone_names(locale).map_or("", |names| names[2 + dst]),
+        };
+        if !name.is_empty() {
+            return Cow::Borrowed(name);
+        }
+        Cow::Owned(gmt_format(
+            locale,
+            self.offset_at(&at.time).local_minus_utc(),
+        ))
+    }
+}
+
+/// zone of [`tz_catalog`]
+#[cfg(feature = "tz")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogZone {
+    pub tz: DayjsTimeZone,
+    /// IANA name, e.g. "America/New_York"
+    pub name: &'static str,
+    /// representative city, e.g. "New York"
+    pub city: String,
+    /// offset from UTC in seconds
+    pub offset: i32,
+    /// whether daylight saving time is in effect
+    pub dst: bool,
+}
+
+/// zones of one region of [`tz_catalog`], e.g. "Europe"
+#[cfg(feature = "tz")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogRegion {
+    pub name: &'static str,
+    pub zones: Vec<CatalogZone>,
+}
+
+/// zones of zone.tab grouped by region for timezone pickers, with current offsets
+#[cfg(feature = "tz")]
+pub fn tz_catalog() -> Vec<CatalogRegion> {
+    tz_catalog_at(&crate::dayjs())
+}
+
+/// zones of zone.tab grouped by region, with offsets at `at`
+///
+/// regions are sorted by name, zones by offset and then city
+#[cfg(feature = "tz")]
+pub fn tz_catalog_at(at: &Dayjs) -> Vec<CatalogRegion> {
+    let mut regions: Vec<CatalogRegion> = Vec::new();
+    for &(name, _) in zone_tab::ZONES.iter() {
+        let Some(tz) = DayjsTimeZone::city(name) else {
+            continue;
+        };
+        let (region, rest) = name.split_once('/').unwrap_or((name, name));
+        let zone = CatalogZone {
+            tz,
+            name,
+            city: rest.rsplit('/').next().unwrap_or(rest).replace('_', " "),
+            offset: tz.offset_at(&at.time).local_minus_utc(),
+            dst: tz.dst_at(&at.time),
+        };
+        match regions.iter_mut().find(|r| r.name == region) {
+            Some(r) => r.zones.push(zone),
+            None => regions.push(CatalogRegion {
+                name: region,
+                zones: vec![zone],
+            }),
+        }
+    }
+    regions.sort_by(|a, b| a.name.cmp(b.name));
+    for region in &mut regions {
+        region
+            .zones
+            .sort_by(|a, b| a.offset.cmp(&b.offset).then_with(|| a.city.cmp(&b.city)));
+    }
+    regions
+}
+
+/// zones of an ISO 3166 country code from zone.tab, most populous first
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(dayjs::timezones_for_country("de"), ["Europe/Berlin", "Europe/Busingen"]);
+/// assert!(dayjs::timezones_for_country("XX").is_empty());
+/// ```
+pub fn timezones_for_country(country: &str) -> &'static [&'static str] {
+    let country = country.to_ascii_uppercase();
+    let countries = &zone_tab::COUNTRIES;
+    countries
+        .binary_search_by_key(&country.as_str(), |(code, _)| code)
+        .map_or(&[], |i| countries[i].1)
+}
+
+/// ISO 3166 country code of a zone.tab zone
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(dayjs::country_for_timezone("Asia/Shanghai"), Some("CN"));
+/// ```
+pub fn country_for_timezone(zone: &str) -> Option<&'static str> {
+    let zones = &zone_tab::ZONES;
+    let country = |zone: &str| {
+        zones
+            .binary_search_by_key(&zone, |(name, _)| name)
+            .ok()
+            .map(|i| zones[i].1)
+    };
+    // zone.tab lists one name of a zone, "Asia/Kolkata" but not "Asia/Calcutta"
+    let id = data_zone(zone);
+    country(zone).or_else(|| country(id)).or_else(|| {
+        ZONE_ALIASES
+            .iter()
+            .filter(|(_, alias_id)| *alias_id == id)
+            .find_map(|(alias, _)| country(alias))
+    })
+}
+
+/// id of a zone in the locale data, e.g. "Asia/Calcutta" for "Asia/Kolkata", as its tables
+/// are keyed on it
+fn data_zone(zone: &str) -> &str {
+    ZONE_ALIASES
+        .binary_search_by_key(&zone, |(alias, _)| alias)
+        .map_or(zone, |i| ZONE_ALIASES[i].1)
+}
+
+/// instant of `local` at `offset` seconds east of UTC, clamped to the supported range
+fn at_offset(local: NaiveDateTime, offset: i32) -> DateTime<Utc> {
+    match local.checked_sub_signed(Duration::seconds(offset.into())) {
+        Some(utc) => utc.and_utc(),
+        None if offset > 0 => DateTime::<Utc>::MIN_UTC,
+        None => DateTime::<Utc>::MAX_UTC,
+    }
+}
+
+/// localized GMT format, "GMT+8", "GMT+5:30"
+fn gmt_format(locale: &Locale, offset: i32) -> String {
+    if offset == 0 {
+        return locale.gmt_zero_format.to_string();
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes) = (offset / 3600, offset / 60 % 60);
+    let value = if minutes == 0 {
+        format!("{}{}", sign, hours)
+    } else {
+        format!("{}{}:{:02}", sign, hours, minutes)
+    };
+    locale.gmt_format.replace("{0}", &value)
+}
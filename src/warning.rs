@@ -0,0 +1,73 @@
+use chrono::NaiveDateTime;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, RwLock};
+
+type Handler = Arc<dyn Fn(&Warning) + Send + Sync>;
+
+/// receiver of [`Warning`]s, none until [`set_warning_handler`]
+static HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+
+/// a silent fallback, the API still returns its usual value
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// a fixed offset in seconds of a day or more, read as UTC
+    InvalidOffset(i32),
+    /// a unit name that [`crate::Unit`] does not know, the instance is left unchanged
+    UnknownUnit(String),
+    /// a wall clock time skipped by a DST gap, moved past the gap
+    SkippedLocalTime(NaiveDateTime),
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::InvalidOffset(seconds) => {
+                write!(
+                    f,
+                    "offset of {} seconds is out of range, using UTC",
+                    seconds
+                )
+            }
+            Warning::UnknownUnit(unit) => write!(f, "unknown unit {:?} is ignored", unit),
+            Warning::SkippedLocalTime(local) => {
+                write!(f, "{} is skipped by a DST gap, moved past it", local)
+            }
+        }
+    }
+}
+
+/// send the [`Warning`]s of silent fallbacks to `handler`, replacing the previous one; the
+/// handler may log them, or panic to fail fast, and may itself call dayjs or change the handler
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// static SEEN: AtomicUsize = AtomicUsize::new(0);
+/// dayjs::set_warning_handler(|_| {
+///     SEEN.fetch_add(1, Ordering::Relaxed);
+/// });
+/// let date = dayjs::from_timestamp(1704443415);
+/// assert_eq!(date.start_of("fortnight"), date);
+/// assert_eq!(SEEN.load(Ordering::Relaxed), 1);
+/// dayjs::clear_warning_handler();
+/// ```
+pub fn set_warning_handler(handler: impl Fn(&Warning) + Send + Sync + 'static) {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(handler));
+}
+
+/// stop reporting warnings
+pub fn clear_warning_handler() {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// report a fallback, the warning is only built when a handler is set
+///
+/// the handler runs after the lock is released, so it can replace itself or warn again
+pub(crate) fn warn(warning: impl FnOnce() -> Warning) {
+    let handler = HANDLER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(handler) = handler {
+        handler(&warning());
+    }
+}
@@ -0,0 +1,34 @@
+use dayjs::asn1::{from_generalized_time, from_utc_time};
+use dayjs::{DayjsTimeZone, Unit};
+
+#[test]
+fn test_utc_time() {
+    let date = from_utc_time("240105083000Z").unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+    assert_eq!(date.to_utc_time().unwrap(), "240105083000Z");
+    let ber = from_utc_time("2401051030+0200").unwrap();
+    assert_eq!(ber.tz, DayjsTimeZone::Offset(7200));
+    assert_eq!(ber.to_utc_time().unwrap(), "240105083000Z");
+    assert!(date.add(26, Unit::Year).to_utc_time().is_err());
+    let err = from_utc_time("2401050830").unwrap_err().to_string();
+    assert!(
+        err.contains("expected '+' or '-' at position 10"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_generalized_time() {
+    let date = from_generalized_time("20540105083000Z").unwrap();
+    assert_eq!(date.to_x509_time().unwrap(), "20540105083000Z");
+    let old = from_generalized_time("19491231235959Z").unwrap();
+    assert_eq!(old.to_x509_time().unwrap(), "19491231235959Z");
+    let err = date.add(8000, Unit::Year).to_x509_time().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "year 10054 is out of the GeneralizedTime range"
+    );
+    assert!(from_generalized_time("20540105083000+0000").is_err());
+    assert!(from_generalized_time("205401050830Z").is_err());
+}
@@ -0,0 +1,73 @@
+use dayjs::binary::from_bytes;
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_bytes_round_trip() {
+    let date = dayjs::from_timestamp(1704443400123);
+    assert_eq!(from_bytes(&date.to_bytes()).unwrap(), date);
+    let mut india = date;
+    india.tz = DayjsTimeZone::Offset(19800);
+    let bytes = india.to_bytes();
+    assert_eq!(&bytes[8..], &[1, 74, 0, 1]);
+    let decoded = from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.tz, DayjsTimeZone::Offset(19800));
+    assert_eq!(decoded.to_string(), "2024-01-05T08:30:00.123+00:00");
+    let old = from_bytes(
+        &(-1i64)
+            .to_be_bytes()
+            .iter()
+            .chain(&[0; 4])
+            .copied()
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(old.unwrap().to_string(), "1969-12-31T23:59:59.999+00:00");
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_bytes_city() {
+    let mut date = dayjs::from_timestamp(1720168200);
+    date.tz = DayjsTimeZone::city("America/New_York").unwrap();
+    let bytes = date.to_bytes();
+    assert_eq!(&bytes[8..], &[0xff, 0x10, 0, 3]);
+    let decoded = from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.tz, DayjsTimeZone::Offset(-4 * 3600));
+}
+
+#[test]
+fn test_bytes_errors() {
+    let err = from_bytes(&[0; 11]).unwrap_err().to_string();
+    assert_eq!(err, "expected 12 bytes, got 11");
+    let err = from_bytes(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("reserved"), "{}", err);
+    let err = from_bytes(&[0, 0, 0, 0, 0, 0, 0, 0, 5, 160, 0, 1])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("1440 minutes"), "{}", err);
+    assert!(from_bytes(&[127, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0]).is_err());
+}
+
+#[test]
+fn test_deltas() {
+    use dayjs::binary::{decode_deltas, encode_deltas};
+    assert!(encode_deltas(&[]).is_empty());
+    assert_eq!(decode_deltas(&[]).unwrap(), vec![]);
+    let column = [
+        dayjs::from_timestamp(1704443400000),
+        dayjs::from_timestamp(1704443400001),
+        dayjs::from_timestamp(1704443399999),
+        dayjs::from_timestamp(1704443399999),
+    ];
+    let bytes = encode_deltas(&column);
+    // +1, -2 and 0 zig-zag to 2, 3 and 0
+    assert_eq!(&bytes[6..], &[2, 3, 0]);
+    assert_eq!(decode_deltas(&bytes).unwrap(), column);
+    let old = dayjs::from_epoch(-1, dayjs::Epoch::Unix, dayjs::Precision::Milliseconds);
+    assert_eq!(encode_deltas(&[old]), vec![1]);
+    let err = decode_deltas(&bytes[..5]).unwrap_err().to_string();
+    assert_eq!(err, "truncated varint at byte 5");
+    let err = decode_deltas(&[0xff; 11]).unwrap_err().to_string();
+    assert!(err.contains("overflows"), "{}", err);
+}
@@ -0,0 +1,80 @@
+use chrono::Weekday;
+use dayjs::{DayjsTimeZone, Epoch, Precision, Weekend};
+
+#[test]
+fn test_business_days() {
+    let weekend = Weekend::default();
+    // saturday 2024-01-06T23:30
+    let saturday = dayjs::from_timestamp(1704583800);
+    assert!(!saturday.is_business_day(&weekend));
+    // friday 2024-01-05T20:30 is saturday in +14:00
+    let late_friday = dayjs::from_timestamp(1704486600);
+    assert!(late_friday.is_business_day(&weekend));
+    assert!(!late_friday
+        .tz(DayjsTimeZone::Offset(14 * 3600))
+        .is_business_day(&weekend));
+    let next = saturday.next_business_day(&weekend);
+    assert_eq!(
+        next.format("dddd YYYY-MM-DD HH:mm"),
+        "Monday 2024-01-08 23:30"
+    );
+    let days: Vec<_> = [4, 5, 6, 10, 11, -1, -6]
+        .iter()
+        .map(|n| saturday.add_business_days(*n, &weekend).format("ddd D"))
+        .collect();
+    assert_eq!(
+        days,
+        ["Thu 11", "Fri 12", "Mon 15", "Fri 19", "Mon 22", "Fri 5", "Fri 29"]
+    );
+    for n in -12..=12 {
+        let moved = saturday.add_business_days(n, &weekend);
+        if n != 0 {
+            assert!(moved.is_business_day(&weekend), "{}", n);
+        }
+    }
+    let friday = dayjs::from_timestamp(1704443400);
+    assert_eq!(friday.business_days_between(&friday, &weekend), 0);
+    assert_eq!(friday.business_days_between(&saturday, &weekend), 1);
+    assert_eq!(
+        saturday.business_days_between(&friday.add(31, dayjs::Unit::Day), &weekend),
+        20
+    );
+    let everything = Weekend::new([
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]);
+    assert_eq!(friday.add_business_days(3, &everything), friday);
+    assert_eq!(friday.business_days_between(&saturday, &everything), 0);
+    let sunday_only = Weekend::new([Weekday::Sun]);
+    assert!(sunday_only.contains(Weekday::Sun) && !sunday_only.contains(Weekday::Sat));
+    assert_eq!(
+        friday.add_business_days(2, &sunday_only).format("ddd D"),
+        "Mon 8"
+    );
+    // past the supported range the move stops at its edge
+    let weekend = Weekend::default();
+    let last = chrono::DateTime::<chrono::Utc>::MAX_UTC.timestamp();
+    let first = chrono::DateTime::<chrono::Utc>::MIN_UTC.timestamp();
+    for n in [i64::MAX, 1 << 62] {
+        let moved = friday.add_business_days(n, &weekend);
+        assert_eq!(moved.to_epoch(Epoch::Unix, Precision::Seconds), last);
+    }
+    assert_eq!(
+        friday
+            .add_business_days(i64::MIN, &weekend)
+            .to_epoch(Epoch::Unix, Precision::Seconds),
+        first
+    );
+    let near_end = dayjs::from_epoch(last - 86400, Epoch::Unix, Precision::Seconds);
+    assert_eq!(
+        near_end
+            .add_business_days(10, &weekend)
+            .to_epoch(Epoch::Unix, Precision::Seconds),
+        last
+    );
+}
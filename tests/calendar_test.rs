@@ -0,0 +1,79 @@
+use dayjs::calendar::parse_relative_weekday;
+use dayjs::locale;
+
+// 2024-02-05T15:04:05Z, a monday
+const MONDAY: i64 = 1707145445;
+const DAY: i64 = 86400;
+
+#[test]
+fn test_relative_weekday() {
+    let monday = dayjs::from_timestamp(MONDAY);
+    let last_friday = dayjs::from_timestamp(MONDAY - 3 * DAY);
+    let next_friday = dayjs::from_timestamp(MONDAY + 4 * DAY);
+    assert_eq!(
+        last_friday.relative_weekday(&monday, &locale::EN),
+        Some("last Friday")
+    );
+    assert_eq!(
+        next_friday.relative_weekday(&monday, &locale::RU),
+        Some("в следующую пятницу")
+    );
+    assert_eq!(
+        monday.relative_weekday(&monday, &locale::PL),
+        Some("w ten poniedziałek")
+    );
+    let far = dayjs::from_timestamp(MONDAY + 9 * DAY);
+    assert_eq!(far.relative_weekday(&monday, &locale::EN), None);
+}
+
+#[test]
+fn test_parse_relative_weekday() {
+    let monday = dayjs::from_timestamp(MONDAY);
+    let parse = |s, l| parse_relative_weekday(s, &monday, l).map(|d| d.to_string());
+    let friday = dayjs::from_timestamp(MONDAY + 4 * DAY).to_string();
+    assert_eq!(parse("next Friday", &locale::EN), Some(friday.clone()));
+    assert_eq!(parse("This friday", &locale::EN), Some(friday));
+    let last_monday = dayjs::from_timestamp(MONDAY - 7 * DAY).to_string();
+    assert_eq!(parse("last Monday", &locale::EN), Some(last_monday));
+    let last_friday = dayjs::from_timestamp(MONDAY - 3 * DAY).to_string();
+    assert_eq!(parse("в прошлую пятницу", &locale::RU), Some(last_friday));
+    assert_eq!(parse("someday", &locale::EN), None);
+    #[cfg(feature = "tz")]
+    {
+        // clocks spring forward in New York on sunday 2024-03-10
+        let new_york = dayjs::DayjsTimeZone::city("America/New_York").unwrap();
+        let friday = dayjs::from_timestamp(1709910000).tz(new_york);
+        let monday = parse_relative_weekday("next Monday", &friday, &locale::EN).unwrap();
+        assert_eq!(
+            monday.format("YYYY-MM-DD HH:mm Z"),
+            "2024-03-11 10:00 -04:00"
+        );
+    }
+}
+
+#[test]
+fn test_calendar() {
+    use dayjs::{CalendarFormats, DayjsTimeZone};
+    let reference = dayjs::from_timestamp(MONDAY);
+    let tomorrow = dayjs::from_timestamp(MONDAY + 86400);
+    assert_eq!(tomorrow.calendar(Some(&reference)), "Tomorrow at 3:04 PM");
+    let sunday = dayjs::from_timestamp(MONDAY + 6 * 86400);
+    assert_eq!(sunday.calendar(Some(&reference)), "Sunday at 3:04 PM");
+    let next_monday = dayjs::from_timestamp(MONDAY + 7 * 86400);
+    assert_eq!(next_monday.calendar(Some(&reference)), "02/12/2024");
+    // the day is the reference's day in the instance timezone
+    let tokyo = reference.tz(DayjsTimeZone::Offset(9 * 3600));
+    assert_eq!(tokyo.calendar(Some(&reference)), "Today at 12:04 AM");
+    let formats = CalendarFormats {
+        next_day: Some("[Tomorrow]"),
+        ..Default::default()
+    };
+    assert_eq!(
+        tomorrow.calendar_with(Some(&reference), &formats),
+        "Tomorrow"
+    );
+    assert_eq!(
+        reference.calendar_with(Some(&reference), &formats),
+        "Today at 3:04 PM"
+    );
+}
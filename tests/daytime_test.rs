@@ -0,0 +1,96 @@
+use dayjs::{DayjsTime, DayjsTimeZone};
+
+#[test]
+fn test_at_local_time_in_zone() {
+    let nine: DayjsTime = "09:00".parse().unwrap();
+    let utc = DayjsTimeZone::DEFAULT;
+    let date = dayjs::from_timestamp(1704445200);
+    assert_eq!(date.at_local_time_in_zone(nine, &utc), date);
+    let later = date.add(1, dayjs::Unit::Millisecond);
+    let next = later.at_local_time_in_zone(nine, &utc);
+    assert_eq!(next.to_string(), "2024-01-06T09:00:00+00:00");
+    let last = dayjs::from_epoch(i64::MAX, dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    assert_eq!(
+        last.at_local_time_in_zone(nine, &utc).format("HH:mm"),
+        "09:00"
+    );
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_at_local_time_in_zone_dst() {
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    // 2024-03-10T00:00 in New York, 02:30 is skipped that night
+    let date = dayjs::from_timestamp(1710046800);
+    let send = date.at_local_time_in_zone("02:30".parse().unwrap(), &new_york);
+    assert_eq!(send.to_string(), "2024-03-10T07:30:00+00:00");
+    assert_eq!(send.format("HH:mm Z"), "03:30 -04:00");
+    let send = send.at_local_time_in_zone("09:00".parse().unwrap(), &new_york);
+    assert_eq!(send.format("YYYY-MM-DD HH:mm Z"), "2024-03-10 09:00 -04:00");
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_fanout_local_time() {
+    let city = |name| DayjsTimeZone::city(name).unwrap();
+    let zones = [
+        city("America/New_York"),
+        city("Asia/Tokyo"),
+        DayjsTimeZone::DEFAULT,
+        city("Europe/London"),
+    ];
+    // friday 2024-03-29 in UTC, New York is already on daylight saving time, London is not
+    let date = dayjs::from_timestamp(1711670400);
+    let sends: Vec<_> = dayjs::fanout_local_time("09:00".parse().unwrap(), &zones, &date)
+        .map(|(tz, at)| (tz, at.to_string(), at.format("HH:mm")))
+        .collect();
+    assert_eq!(
+        sends,
+        [
+            (
+                zones[1],
+                "2024-03-29T00:00:00+00:00".to_string(),
+                "09:00".to_string()
+            ),
+            (
+                zones[2],
+                "2024-03-29T09:00:00+00:00".to_string(),
+                "09:00".to_string()
+            ),
+            (
+                zones[3],
+                "2024-03-29T09:00:00+00:00".to_string(),
+                "09:00".to_string()
+            ),
+            (
+                zones[0],
+                "2024-03-29T13:00:00+00:00".to_string(),
+                "09:00".to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        dayjs::fanout_local_time(DayjsTime::MIDNIGHT, &[], &date).count(),
+        0
+    );
+}
+
+#[test]
+fn test_ttl() {
+    let now = dayjs::from_timestamp(1704443400250);
+    let deadline = dayjs::from_timestamp(1704443460);
+    assert_eq!(now.ttl_until(&deadline).as_millis(), 59_750);
+    assert_eq!(
+        now.seconds_until_local("08:31".parse().unwrap(), &DayjsTimeZone::DEFAULT),
+        60
+    );
+    let midnight = dayjs::from_timestamp(1704412800);
+    let utc = DayjsTimeZone::DEFAULT;
+    assert_eq!(
+        midnight.seconds_until_local(DayjsTime::MIDNIGHT, &utc),
+        86_400
+    );
+    // no later boundary after the last supported instant
+    let last = dayjs::from_epoch(i64::MAX, dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    assert_eq!(last.seconds_until_local(DayjsTime::MIDNIGHT, &utc), 0);
+}
@@ -0,0 +1,39 @@
+use dayjs::deadline::{Deadline, HeaderFormat, HEADER};
+use std::time::Duration;
+
+#[test]
+fn test_deadline_header() {
+    let deadline: Deadline = "2024-01-05T16:30:00.5+08:00".parse().unwrap();
+    assert_eq!(
+        deadline.to_header(HeaderFormat::EpochMillis),
+        "1704443400500"
+    );
+    assert_eq!(deadline.to_string(), "2024-01-05T08:30:00.500Z");
+    assert_eq!(Deadline::from_header(" 1704443400500 "), Ok(deadline));
+    assert!(deadline.is_exceeded());
+    assert_eq!(deadline.remaining(), Duration::ZERO);
+    assert_eq!(HEADER, "X-Deadline");
+    assert!(Deadline::from_header("").is_err());
+    assert!(Deadline::from_header("99999999999999999999").is_err());
+    assert!(Deadline::from_header("-5").is_err());
+}
+
+#[test]
+fn test_deadline_after() {
+    let deadline = Deadline::after(Duration::from_secs(60));
+    assert!(!deadline.is_exceeded());
+    let remaining = deadline.remaining();
+    assert!(Duration::from_secs(59) < remaining && remaining <= Duration::from_secs(60));
+    assert!(!Deadline::after(Duration::MAX).is_exceeded());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_deadline_tokio() {
+    let deadline = Deadline::after(Duration::from_secs(60));
+    let instant: tokio::time::Instant = deadline.into();
+    let left = instant - tokio::time::Instant::now();
+    assert!(Duration::from_secs(59) < left && left <= Duration::from_secs(60));
+    let far = Deadline::after(Duration::MAX).to_tokio_instant();
+    assert!(far > tokio::time::Instant::now() + Duration::from_secs(86_400 * 365));
+}
@@ -0,0 +1,61 @@
+use dayjs::Unit;
+
+#[test]
+fn test_diff_f64() {
+    let a = dayjs::from_str("2024-01-31T00:00:00Z");
+    let b = dayjs::from_str("2024-03-15T12:00:00Z");
+    let months = b.diff_f64(&a, Unit::Month);
+    // 2024-02-29 is a month after january 31, then 15.5 of march's days from there
+    assert!((months - (1.0 + 15.5 / 31.0)).abs() < 1e-12, "{}", months);
+    assert_eq!(a.diff_f64(&b, Unit::Month), -months);
+    assert_eq!(b.diff_f64(&a, Unit::Quarter), months / 3.0);
+    assert_eq!(b.diff_f64(&a, Unit::Week), 44.5 / 7.0);
+    assert_eq!(b.diff_f64(&a, Unit::Second), 44.5 * 86_400.0);
+    assert_eq!(a.diff_f64(&a, Unit::Year), 0.0);
+    let leap = dayjs::from_str("2024-02-29T00:00:00Z");
+    let next = dayjs::from_str("2025-02-28T00:00:00Z");
+    assert_eq!(next.diff_f64(&leap, Unit::Year), 1.0);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_diff_f64_dst() {
+    let new_york = dayjs::DayjsTimeZone::city("America/New_York").unwrap();
+    // midnight on 2024-03-10 and 2024-03-11 in New York, 23 hours apart
+    let a = dayjs::from_timestamp(1710046800).tz(new_york);
+    let b = dayjs::from_timestamp(1710129600).tz(new_york);
+    assert_eq!(b.diff_f64(&a, Unit::Hour), 23.0);
+    assert_eq!(b.diff_f64(&a, Unit::Day), 1.0);
+    assert_eq!(b.diff_f64(&a.utc(), Unit::Day), 1.0);
+}
+
+#[test]
+fn test_diff_months_years() {
+    let date = |s: &str| dayjs::from_str(&format!("{}T00:00:00Z", s));
+    let cases = [
+        ("2024-01-31", "2024-02-01", 0),
+        ("2024-01-31", "2024-02-29", 1),
+        ("2024-01-31", "2024-03-01", 1),
+        ("2024-01-31", "2024-03-30", 1),
+        ("2024-01-31", "2024-03-31", 2),
+        ("2023-12-15", "2024-01-14", 0),
+        ("2023-12-15", "2024-01-15", 1),
+    ];
+    for (from, to, months) in cases {
+        assert_eq!(date(to).diff_months(&date(from)), months, "{} {}", from, to);
+        assert_eq!(
+            date(from).diff_months(&date(to)),
+            -months,
+            "{} {}",
+            from,
+            to
+        );
+    }
+    assert_eq!(date("2025-02-28").diff_years(&date("2024-02-29")), 1);
+    assert_eq!(date("2024-12-31").diff_years(&date("2024-01-01")), 0);
+    assert_eq!(date("2020-01-01").diff_years(&date("2024-01-01")), -4);
+    assert_eq!(
+        date("2024-07-01").diff(&date("2024-01-01"), Unit::Quarter),
+        2
+    );
+}
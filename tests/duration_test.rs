@@ -0,0 +1,108 @@
+use chrono::Duration;
+use dayjs::duration::humanize_locale;
+use dayjs::locale;
+
+#[test]
+fn test_humanize_plural_categories() {
+    let ru = &locale::RU;
+    assert_eq!(humanize_locale(&Duration::hours(2), false, ru), "2 часа");
+    assert_eq!(humanize_locale(&Duration::hours(5), false, ru), "5 часов");
+    assert_eq!(humanize_locale(&Duration::hours(21), false, ru), "21 час");
+    assert_eq!(
+        humanize_locale(&Duration::minutes(22), false, ru),
+        "22 минуты"
+    );
+    assert_eq!(
+        humanize_locale(&Duration::minutes(12), false, ru),
+        "12 минут"
+    );
+    assert_eq!(
+        humanize_locale(&Duration::days(22), false, &locale::PL),
+        "22 dni"
+    );
+    assert_eq!(
+        humanize_locale(&Duration::minutes(12), false, &locale::LT),
+        "12 minučių"
+    );
+}
+
+#[test]
+fn test_humanize_thresholds() {
+    let en = &locale::EN;
+    assert_eq!(
+        humanize_locale(&Duration::seconds(30), false, en),
+        "a few seconds"
+    );
+    assert_eq!(
+        humanize_locale(&Duration::seconds(60), false, en),
+        "a minute"
+    );
+    assert_eq!(
+        humanize_locale(&Duration::minutes(2), true, en),
+        "in 2 minutes"
+    );
+    assert_eq!(
+        humanize_locale(&Duration::hours(-30), true, en),
+        "a day ago"
+    );
+    assert_eq!(humanize_locale(&Duration::days(400), false, en), "a year");
+}
+
+#[test]
+fn test_format_locale() {
+    use dayjs::duration::{format_locale, DurationStyle};
+    let d = Duration::hours(26) + Duration::seconds(5);
+    assert_eq!(
+        format_locale(&d, &locale::EN, DurationStyle::Long),
+        "1 day 2 hours 5 seconds"
+    );
+    assert_eq!(
+        format_locale(&d, &locale::EN, DurationStyle::Narrow),
+        "1d 2h 5s"
+    );
+    let d = Duration::minutes(5 * 60 + 22);
+    assert_eq!(
+        format_locale(&d, &locale::RU, DurationStyle::Long),
+        "5 часов 22 минуты"
+    );
+    assert_eq!(
+        format_locale(&d, &locale::RU, DurationStyle::Short),
+        "5 ч 22 мин"
+    );
+    assert_eq!(
+        format_locale(&-d, &locale::PL, DurationStyle::Long),
+        "-5 godzin 22 minuty"
+    );
+    assert_eq!(
+        format_locale(&Duration::zero(), &locale::EN, DurationStyle::Long),
+        "0 seconds"
+    );
+}
+
+#[test]
+fn test_duration_stats() {
+    use dayjs::duration::DurationStats;
+    let latencies = [120, 80, 95, 4_000, 100, 90, 110, 85, 105, 65_500];
+    let stats = DurationStats::from(latencies.iter().map(|ms| Duration::milliseconds(*ms)));
+    assert_eq!(stats.count, 10);
+    assert_eq!(stats.min, Duration::milliseconds(80));
+    assert_eq!(stats.p50, Duration::milliseconds(100));
+    assert_eq!(stats.p95, Duration::milliseconds(65_500));
+    assert_eq!(stats.mean, Duration::microseconds(7_028_500));
+    assert_eq!(
+        stats.to_string(),
+        "count 10, min 80ms, mean 7s, p50 100ms, p95 1m 5s, p99 1m 5s, max 1m 5s"
+    );
+    let empty = DurationStats::from(Vec::new());
+    assert_eq!((empty.count, empty.p99), (0, Duration::zero()));
+}
+
+#[test]
+fn test_format_excel() {
+    use dayjs::duration::format_excel;
+    let d = Duration::hours(49) + Duration::seconds(7);
+    assert_eq!(format_excel(&d, "[h]:mm:ss").unwrap(), "49:00:07");
+    assert_eq!(format_excel(&d, "[s]").unwrap(), "176407");
+    assert_eq!(format_excel(&-d, "[hh]\"h\" mm\"m\"").unwrap(), "-49h 00m");
+    assert!(format_excel(&d, "yyyy").is_err());
+}
@@ -0,0 +1,78 @@
+use dayjs::{from_epoch, Epoch, Precision};
+
+#[test]
+fn test_from_epoch() {
+    let expected = "2024-01-05T08:30:00+00:00";
+    let cases = [
+        (1_704_443_400_000, Epoch::Unix, Precision::Milliseconds),
+        (726_136_200, Epoch::Cocoa, Precision::Seconds),
+        (
+            13_348_917_000_000_000,
+            Epoch::WebKit,
+            Precision::Microseconds,
+        ),
+        (3_787_288_200, Epoch::QuickTime, Precision::Seconds),
+        (1_388_478_618, Epoch::Gps, Precision::Seconds),
+    ];
+    for (value, epoch, precision) in cases {
+        let date = from_epoch(value, epoch, precision);
+        assert_eq!(date.to_string(), expected, "{:?}", epoch);
+        assert_eq!(date.to_epoch(epoch, precision), value, "{:?}", epoch);
+    }
+}
+
+#[test]
+fn test_from_epoch_edges() {
+    let before = from_epoch(-1, Epoch::Cocoa, Precision::Nanoseconds);
+    assert_eq!(before.to_string(), "2000-12-31T23:59:59.999999999+00:00");
+    // GPS epoch itself, no leap seconds yet
+    let gps = from_epoch(0, Epoch::Gps, Precision::Seconds);
+    assert_eq!(gps.to_string(), "1980-01-06T00:00:00+00:00");
+    let max = from_epoch(i64::MAX, Epoch::Unix, Precision::Seconds);
+    let max_utc = chrono::DateTime::<chrono::Utc>::MAX_UTC.timestamp();
+    assert_eq!(max.to_epoch(Epoch::Unix, Precision::Seconds), max_utc);
+}
+
+#[test]
+fn test_clamped_epoch_bounds() {
+    #[allow(unused_mut)]
+    let mut zones = vec![
+        dayjs::DayjsTimeZone::DEFAULT,
+        dayjs::DayjsTimeZone::Offset(14 * 3600),
+        dayjs::DayjsTimeZone::Offset(-12 * 3600),
+    ];
+    #[cfg(feature = "tz")]
+    zones.push(dayjs::DayjsTimeZone::city("America/New_York").unwrap());
+    for value in [i64::MAX, i64::MIN] {
+        for tz in &zones {
+            let date = from_epoch(value, Epoch::Unix, Precision::Seconds).tz(*tz);
+            // the unit after the last one is out of range, its end is the last instant
+            let end = date.end_of("year");
+            assert!(end >= date.start_of("year"), "{:?}", tz);
+            assert!(date.start_of("week") <= date, "{:?}", tz);
+            assert!(date.end_of_week(chrono::Weekday::Mon) >= date.start_of("day"));
+            let (start, end) = date.bounds_of(dayjs::Unit::Month);
+            assert!(start <= end, "{:?}", tz);
+        }
+    }
+    let max = from_epoch(i64::MAX, Epoch::Unix, Precision::Seconds);
+    assert_eq!(max.end_of("year"), max);
+}
+
+#[test]
+fn test_score() {
+    let earliest = dayjs::from_epoch(i64::MIN, Epoch::Unix, Precision::Milliseconds);
+    let latest = dayjs::from_epoch(i64::MAX, Epoch::Unix, Precision::Milliseconds);
+    for date in [earliest, latest] {
+        assert!(date.to_score().abs() < dayjs::MAX_EXACT_SCORE);
+        let read = dayjs::from_score(date.to_score()).unwrap();
+        let millis = |d: &dayjs::Dayjs| d.to_epoch(Epoch::Unix, Precision::Milliseconds);
+        assert_eq!(millis(&read), millis(&date));
+    }
+    let date = dayjs::from_score(-0.6).unwrap();
+    assert_eq!(date.to_string(), "1969-12-31T23:59:59.999+00:00");
+    assert!(dayjs::from_score(f64::NAN).is_err());
+    assert!(dayjs::from_score(dayjs::MAX_EXACT_SCORE).is_err());
+    let err = dayjs::from_score(1e16).unwrap_err().to_string();
+    assert_eq!(err, "score 10000000000000000 is not an exact millisecond");
+}
@@ -0,0 +1,41 @@
+use dayjs::{DayjsError, Unit};
+
+#[test]
+fn test_error_variants() {
+    let err = dayjs::parse_rfc3339_strict("2024-01-05 08:30:00Z").unwrap_err();
+    match &err {
+        DayjsError::ParseError {
+            grammar, position, ..
+        } => {
+            assert_eq!(*grammar, "RFC 3339 date time");
+            assert_eq!(*position, Some(10));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        "fortnight".parse::<Unit>(),
+        Err(DayjsError::UnknownUnit("fortnight".to_string()))
+    );
+    let bytes = [127, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0];
+    assert_eq!(
+        dayjs::binary::from_bytes(&bytes),
+        Err(DayjsError::InvalidTimestamp(i64::MAX))
+    );
+    let far = dayjs::from_timestamp(1704443400).add(30, Unit::Year);
+    assert!(matches!(far.to_utc_time(), Err(DayjsError::OutOfRange(_))));
+}
+
+#[test]
+fn test_error_display() {
+    let err = dayjs::rss::from_rss_date("Fri, 05 Foo 2024").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid RSS date \"Fri, 05 Foo 2024\": expected a month name, found \"Foo\""
+    );
+    let boxed: Box<dyn std::error::Error> = Box::new(err);
+    assert!(boxed.to_string().starts_with("invalid RSS date"));
+    assert_eq!(
+        DayjsError::UnknownUnit("fortnight".to_string()).to_string(),
+        "unknown unit \"fortnight\""
+    );
+}
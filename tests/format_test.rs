@@ -0,0 +1,268 @@
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_format_tokens() {
+    let mut date = dayjs::from_timestamp(1704443415250);
+    assert_eq!(
+        date.format("YYYY-MM-DDTHH:mm:ss.SSSZ"),
+        "2024-01-05T08:30:15.250+00:00"
+    );
+    assert_eq!(date.format("YY M D H m s"), "24 1 5 8 30 15");
+    assert_eq!(date.format("ddd, MMM D, h:mm A"), "Fri, Jan 5, 8:30 AM");
+    date.tz = DayjsTimeZone::Offset(-5 * 3600);
+    assert_eq!(
+        date.format("DD/MM/YYYY hh:mm a ZZ"),
+        "05/01/2024 03:30 am -0500"
+    );
+}
+
+#[test]
+fn test_format_strftime() {
+    let mut date = dayjs::from_timestamp(1704443415);
+    date.tz = DayjsTimeZone::Offset(8 * 3600);
+    assert_eq!(
+        date.format("%Y-%m-%d %H:%M:%S %z"),
+        "2024-01-05 16:30:15 +0800"
+    );
+    assert_eq!(date.format("YYYY [100%]"), "2024 100%");
+}
+
+#[test]
+fn test_format_literals() {
+    let date = dayjs::from_timestamp(1704443415);
+    assert_eq!(date.format("[Today is] YYYY-MM-DD"), "Today is 2024-01-05");
+    assert_eq!(date.format("[Lunch at] LT"), "Lunch at 8:30 AM");
+    assert_eq!(date.format("[%Y] YYYY"), "%Y 2024");
+    assert_eq!(date.format("[] [YYYY"), "[] [2024");
+    assert_eq!(date.format("%Y [YYYY]"), "2024 [YYYY]");
+}
+
+#[test]
+fn test_explain_format() {
+    let explain = |template: &str| -> Vec<(String, &'static str, String)> {
+        dayjs::explain_format(template)
+            .into_iter()
+            .map(|t| (t.token, t.meaning, t.example))
+            .collect()
+    };
+    let tokens = explain("Do h A Z");
+    assert_eq!(
+        tokens[0],
+        ("Do".into(), "day of the month as an ordinal", "5th".into())
+    );
+    assert_eq!(tokens[2], ("h".into(), "hour, 1 to 12", "8".into()));
+    assert_eq!(tokens[4], ("A".into(), "AM or PM", "AM".into()));
+    assert_eq!(
+        tokens[6],
+        ("Z".into(), "UTC offset, +hh:mm", "+00:00".into())
+    );
+    let specs = explain("%Y-%m-%dT%H:%M:%S%.3f%:z %Q 100%%");
+    let summary: Vec<_> = specs
+        .iter()
+        .map(|(t, _, e)| format!("{}={}", t, e))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            "%Y=2024",
+            "-=-",
+            "%m=01",
+            "-=-",
+            "%d=05",
+            "T=T",
+            "%H=08",
+            ":=:",
+            "%M=30",
+            ":=:",
+            "%S=15",
+            "%.3f=.250",
+            "%:z=+00:00",
+            " = ",
+            "%Q=",
+            " 100= 100",
+            "%%=%",
+        ]
+    );
+    assert_eq!(specs[14].1, "unknown specifier");
+    assert_eq!(explain("%_e")[0].2, " 5");
+    let escaped: Vec<_> = explain("[100%] YYYY").into_iter().map(|t| t.2).collect();
+    assert_eq!(escaped, ["100%", " ", "2024"]);
+}
+
+#[test]
+fn test_convert_format() {
+    use dayjs::{convert_format, FormatSyntax::*};
+    let convert = |template, from, to| convert_format(template, from, to).unwrap();
+    assert_eq!(
+        convert("%d/%m/%Y %I:%M %p", Strftime, Dayjs),
+        "DD/MM/YYYY hh:mm A"
+    );
+    assert_eq!(
+        convert("YYYY-MM-DDTHH:mm:ss.SSSZ", Dayjs, Strftime),
+        "%Y-%m-%dT%H:%M:%S.%3f%:z"
+    );
+    assert_eq!(convert("%Y%% [%j]", Strftime, Moment), "YYYY[% []DDDD]");
+    assert_eq!(convert("DDDD", Moment, Java), "DDD");
+    assert_eq!(
+        convert("EEEE, MMMM d ''yy 'o''clock'", Java, Dayjs),
+        "dddd, MMMM D 'YY[ o'clock]"
+    );
+    assert_eq!(
+        convert("yyyy-mm-dd h:mm:ss.000 AM/PM", Excel, Dayjs),
+        "YYYY-MM-DD h:mm:ss.SSS A"
+    );
+    assert_eq!(
+        convert("m/d/yy \"at\" h:mm", Excel, Java),
+        "M/d/yy 'at' H:mm"
+    );
+    assert_eq!(
+        convert("D MMM [de] YYYY", Dayjs, Excel),
+        "d mmm \"de\" yyyy"
+    );
+    let date = dayjs::from_timestamp(1704443415250);
+    let strftime = convert("ddd, DD MMM YYYY HH:mm:ss ZZ", Dayjs, Strftime);
+    assert_eq!(date.format(&strftime), "Fri, 05 Jan 2024 08:30:15 +0000");
+
+    let err = convert_format("%Q", Strftime, Dayjs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid strftime template \"%Q\": unsupported specifier %Q"
+    );
+    assert!(convert_format("HH:mm Z", Dayjs, Excel).is_err());
+    assert!(convert_format("mm", Dayjs, Excel).is_err());
+    assert!(convert_format("hh:mm", Dayjs, Excel).is_err());
+}
+
+#[test]
+fn test_format_excel() {
+    let date = dayjs::from_timestamp(1704443415250).tz(DayjsTimeZone::Offset(-5 * 3600));
+    assert_eq!(
+        date.format_excel("d-mmm-yy h:mm:ss.000").unwrap(),
+        "5-Jan-24 3:30:15.250"
+    );
+    assert_eq!(date.format_excel("[Blue]hh:mm AM/PM").unwrap(), "03:30 AM");
+    assert_eq!(date.format_excel("mm/dd \\w\\k").unwrap(), "01/05 wk");
+}
+
+#[test]
+fn test_strftime_mode() {
+    use dayjs::StrftimeMode::{Chrono, PyStrftime};
+    let date = dayjs::from_timestamp(1704443415250);
+    assert_eq!(date.strftime("%H:%M:%S.%f", PyStrftime), "08:30:15.250000");
+    assert_eq!(date.strftime("%H:%M:%S.%f", Chrono), "08:30:15.250000000");
+    assert_eq!(
+        date.strftime("%-d %#m %-I%p %Z %z", PyStrftime),
+        "5 1 8AM UTC +0000"
+    );
+    assert_eq!(date.strftime("100%% %", PyStrftime), "100% %");
+    // a parse only specifier is kept as text instead of panicking
+    assert_eq!(date.strftime("%H %#z", Chrono), "08 %#z");
+    // like other templates chrono cannot format, dayjs tokens are used
+    assert_eq!(date.format("%H %#z"), "%8 %#UTC");
+    let explained = dayjs::explain_format("%#z %H");
+    assert_eq!(explained[0].token, "%#z");
+    assert_eq!(explained[0].example, "");
+    assert_eq!(explained[2].example, "08");
+    #[cfg(feature = "tz")]
+    {
+        let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+        assert_eq!(date.tz(new_york).strftime("%H %Z", PyStrftime), "03 EST");
+    }
+}
+
+#[test]
+fn test_localized_format() {
+    let date = dayjs::from_timestamp(1704443415);
+    assert_eq!(date.format("L LTS"), "01/05/2024 8:30:15 AM");
+    assert_eq!(date.format("LL"), "January 5, 2024");
+    assert_eq!(date.format("l"), "1/5/2024");
+    assert_eq!(date.format("llll"), "Fri, Jan 5, 2024 8:30 AM");
+    assert_eq!(date.format("[L] LT"), "L 8:30 AM");
+    assert_eq!(
+        date.format_locale("LLLL", &dayjs::locale::ZH_CN),
+        "2024年1月5日星期五上午8点30分"
+    );
+}
+
+#[test]
+fn test_advanced_tokens() {
+    let midnight = dayjs::from_timestamp(1704412800250);
+    assert_eq!(midnight.format("Q Do k kk H"), "1 5th 24 24 0");
+    assert_eq!(midnight.format("X x"), "1704412800 1704412800250");
+    assert_eq!(
+        midnight.format("zzz (z)"),
+        "Coordinated Universal Time (UTC)"
+    );
+    #[cfg(feature = "tz")]
+    {
+        let shanghai = DayjsTimeZone::city("Asia/Shanghai").unwrap();
+        assert_eq!(
+            midnight.tz(shanghai).format("k:mm zzz"),
+            "8:00 China Standard Time"
+        );
+        // no english abbreviation in the locale data, the tz database has one
+        assert_eq!(midnight.tz(shanghai).format("HH:mm z"), "08:00 CST");
+        let paris = DayjsTimeZone::city("Europe/Paris").unwrap();
+        assert_eq!(
+            midnight.add(6, dayjs::Unit::Month).tz(paris).format("z"),
+            "CEST"
+        );
+        let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+        assert_eq!(midnight.tz(new_york).format("z"), "EST");
+        // "+04" in the tz database
+        let dubai = DayjsTimeZone::city("Asia/Dubai").unwrap();
+        assert_eq!(midnight.tz(dubai).format("z"), "GMT+4");
+        assert_eq!(midnight.tz(dubai).zone_abbr(), "GMT+4");
+    }
+    assert_eq!(midnight.zone_abbr(), "UTC");
+    assert_eq!(
+        midnight.tz(DayjsTimeZone::Offset(19800)).format("z"),
+        "GMT+5:30"
+    );
+    let parsed = dayjs::from_format_dayjs("1704412800", "X").unwrap();
+    assert_eq!(parsed, dayjs::from_timestamp(1704412800));
+    let parsed = dayjs::from_format_dayjs("2024-01-05 24:00", "YYYY-MM-DD kk:mm").unwrap();
+    assert_eq!(parsed.format("YYYY-MM-DD HH:mm"), "2024-01-05 00:00");
+    assert!(dayjs::from_format_dayjs("UTC", "z").is_err());
+    let java = dayjs::convert_format(
+        "kk:mm zzz",
+        dayjs::FormatSyntax::Dayjs,
+        dayjs::FormatSyntax::Java,
+    );
+    assert_eq!(java.unwrap(), "kk:mm zzzz");
+}
+
+#[test]
+fn test_format_buffered() {
+    let date = dayjs::from_timestamp(1704443415);
+    let first = date.format_buffered("YYYY-MM-DD HH:mm:ss");
+    let address = first.as_ptr();
+    assert_eq!(&*first, "2024-01-05 08:30:15");
+    drop(first);
+    let again = date.format_buffered("%Y-%m-%d");
+    assert_eq!(&*again, "2024-01-05");
+    assert_eq!(again.as_ptr(), address);
+    let nested = date.format_buffered("[at] HH:mm");
+    assert_ne!(nested.as_ptr(), address);
+    assert_eq!(nested.to_string(), "at 08:30");
+    drop(again);
+    let iso = dayjs::from_timestamp(1704443415007).to_iso_buffered();
+    assert_eq!(&*iso, "2024-01-05T08:30:15.007Z");
+    assert_eq!(iso.as_ptr(), address);
+}
+
+#[test]
+fn test_format_dual() {
+    let date = dayjs::from_timestamp(1704443415);
+    assert_eq!(date.format_dual(), "2024-01-05T08:30:15+00:00 (08:30:15Z)");
+    let west = date.tz(DayjsTimeZone::Offset(-10 * 3600));
+    assert_eq!(
+        west.format_dual(),
+        "2024-01-04T22:30:15-10:00 (2024-01-05T08:30:15Z)"
+    );
+    let late = dayjs::from_timestamp(1704443415007);
+    assert_eq!(
+        late.format_dual(),
+        "2024-01-05T08:30:15.007+00:00 (08:30:15.007Z)"
+    );
+}
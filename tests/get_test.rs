@@ -0,0 +1,190 @@
+use chrono::Weekday;
+use dayjs::{DayjsTimeZone, Unit};
+
+#[test]
+fn test_getters() {
+    let date = dayjs::from_timestamp(1704497415250);
+    assert_eq!((date.year(), date.month(), date.date()), (2024, 1, 5));
+    assert_eq!(date.day(), Weekday::Fri);
+    assert_eq!(
+        (
+            date.hour(),
+            date.minute(),
+            date.second(),
+            date.millisecond()
+        ),
+        (23, 30, 15, 250)
+    );
+    let west = date.tz(DayjsTimeZone::Offset(-10 * 3600));
+    assert_eq!((west.date(), west.hour()), (5, 13));
+    let east = date.tz(DayjsTimeZone::Offset(14 * 3600));
+    assert_eq!((east.month(), east.date(), east.hour()), (1, 6, 13));
+    assert_eq!(east.day(), Weekday::Sat);
+}
+
+#[test]
+fn test_twelve_hour_clock() {
+    let midnight = dayjs::from_timestamp(1704412800);
+    assert_eq!((midnight.hour12(), midnight.meridiem()), (12, "AM"));
+    let noon = midnight.add(12, Unit::Hour);
+    assert_eq!((noon.hour12(), noon.meridiem()), (12, "PM"));
+    for date in [midnight, noon, noon.add(150, Unit::Minute)] {
+        let text = date.format("YYYY-MM-DD h:mm A");
+        assert_eq!(
+            dayjs::from_format_dayjs(&text, "YYYY-MM-DD h:mm A"),
+            Ok(date)
+        );
+        let text = date.format("YYYY-MM-DD hh:mm a");
+        assert_eq!(
+            dayjs::from_format_dayjs(&text, "YYYY-MM-DD hh:mm a"),
+            Ok(date)
+        );
+    }
+}
+
+#[test]
+fn test_quarter() {
+    // 2023-11-30
+    let date = dayjs::from_timestamp(1701302400);
+    assert_eq!(date.quarter(), 4);
+    assert_eq!(date.format("[Q]Q YYYY"), "Q4 2023");
+    let first = date.set_quarter(1).unwrap();
+    assert_eq!(first.format("YYYY-MM-DD"), "2023-02-28");
+    assert_eq!(first.start_of("quarter").format("YYYY-MM-DD"), "2023-01-01");
+    assert_eq!(
+        first.end_of("quarter").to_string(),
+        "2023-03-31T23:59:59.999+00:00"
+    );
+    assert_eq!(date.set_quarter(4), Ok(date));
+    assert!(date.set_quarter(0).is_err());
+    let east = date
+        .tz(DayjsTimeZone::Offset(3600))
+        .add(2, dayjs::Unit::Month);
+    assert_eq!(east.quarter(), 1);
+}
+
+#[test]
+fn test_get_set_unit() {
+    // 2024-12-30T23:30:15.250 at -01:00, ISO week 1 of 2025
+    let date = dayjs::from_timestamp(1735605015250).tz(DayjsTimeZone::Offset(-3600));
+    assert_eq!(date.get(Unit::Isoweek), 1);
+    assert_eq!(date.get(Unit::Day), 30);
+    assert_eq!(date.get(Unit::Millisecond), 250);
+    let set = |unit, value| {
+        date.set(unit, value)
+            .unwrap()
+            .format("YYYY-MM-DD HH:mm:ss.SSS")
+    };
+    assert_eq!(set(Unit::Day, 1), "2024-12-01 23:30:15.250");
+    assert_eq!(set(Unit::Month, 2), "2024-02-29 23:30:15.250");
+    assert_eq!(set(Unit::Year, 2023), "2023-12-30 23:30:15.250");
+    assert_eq!(set(Unit::Week, 52), "2025-12-22 23:30:15.250");
+    assert_eq!(set(Unit::Second, 0), "2024-12-30 23:30:00.250");
+    assert_eq!(date.set(Unit::Hour, 23), Ok(date));
+    let err = date.set(Unit::Day, 32).unwrap_err();
+    assert_eq!(err.to_string(), "day 32 is not between 1 and 31");
+    assert!(date.set(Unit::Isoweek, 53).is_err());
+    assert!(date.set(Unit::Millisecond, -1).is_err());
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_set_hour_dst() {
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    // 2024-03-10T01:00 in New York, an hour before clocks spring forward
+    let date = dayjs::from_timestamp(1710050400).tz(new_york);
+    let set = |hour| date.set(Unit::Hour, hour).unwrap().format("HH:mm Z");
+    assert_eq!(set(4), "04:00 -04:00");
+    assert_eq!(set(2), "03:00 -04:00");
+}
+
+#[test]
+fn test_iso_weekday() {
+    // sunday 2024-01-07, the last day of ISO week 1
+    let sunday = dayjs::from_timestamp(1704616200);
+    assert_eq!(sunday.iso_weekday(), 7);
+    let monday = sunday.set_iso_weekday(1);
+    assert_eq!(monday.format("YYYY-MM-DD HH:mm"), "2024-01-01 08:30");
+    assert_eq!(monday.get(Unit::Isoweek), sunday.get(Unit::Isoweek));
+    let late = sunday.tz(DayjsTimeZone::Offset(-10 * 3600));
+    assert_eq!(late.iso_weekday(), 6);
+}
+
+#[test]
+fn test_day_of_year() {
+    let date = dayjs::from_timestamp(1709800215);
+    assert_eq!(date.day_of_year(), 67);
+    let west = date.tz(DayjsTimeZone::Offset(-10 * 3600));
+    assert_eq!(west.day_of_year(), 66);
+    let moved = west.set_day_of_year(60).unwrap();
+    assert_eq!(moved.format("YYYY-MM-DD HH:mm"), "2024-02-29 22:30");
+    let next_year = date.add(1, Unit::Year);
+    let err = next_year.set_day_of_year(366).unwrap_err().to_string();
+    assert_eq!(err, "day of year 366 is not between 1 and 365");
+}
+
+#[test]
+fn test_days_in() {
+    let counts = |year: i64| {
+        let start = dayjs::from_timestamp(1704067200)
+            .set(Unit::Year, year)
+            .unwrap();
+        let months: Vec<u32> = (0..12)
+            .map(|m| start.add(m, Unit::Month).days_in_month())
+            .collect();
+        (months.iter().sum::<u32>(), months[1], start.days_in_year())
+    };
+    assert_eq!(counts(2024), (366, 29, 366));
+    assert_eq!(counts(2023), (365, 28, 365));
+    assert_eq!(counts(1900), (365, 28, 365));
+    assert_eq!(counts(2000), (366, 29, 366));
+    assert_eq!(counts(-4), (366, 29, 366));
+}
+
+#[test]
+fn test_set_week() {
+    // sunday 2021-01-03 is in week 53 of 2020
+    let sunday = dayjs::from_timestamp(1609673400);
+    assert_eq!(sunday.week_of_year(), 53);
+    let first = sunday.set_week(1).unwrap();
+    assert_eq!(first.format("YYYY-MM-DD dddd"), "2020-01-05 Sunday");
+    assert_eq!(first.week_of_year(), 1);
+    assert!(sunday.set_week(0).is_err());
+}
+
+#[test]
+fn test_iso_weeks_in_year() {
+    let weeks = [2004, 2009, 2015, 2020, 2021, 2024, 2026, 2032, 1900, 2000].map(|year| {
+        dayjs::from_timestamp(0)
+            .set(Unit::Year, year)
+            .unwrap()
+            .iso_weeks_in_year()
+    });
+    assert_eq!(weeks, [53, 53, 53, 53, 52, 52, 53, 53, 52, 52]);
+    // the calendar year, not the ISO week year of the first days of january
+    let sunday = dayjs::from_timestamp(1609673400);
+    assert_eq!(
+        (sunday.week_of_year(), sunday.iso_weeks_in_year()),
+        (53, 52)
+    );
+}
+
+#[test]
+fn test_week_year() {
+    // 2024-12-30T23:30:15.250 at -01:00, ISO week 1 of 2025
+    let date = dayjs::from_timestamp(1735605015250).tz(DayjsTimeZone::Offset(-3600));
+    assert_eq!(
+        (date.year(), date.iso_week_year(), date.week_year()),
+        (2024, 2025, 2025)
+    );
+    let utc = date.utc();
+    assert_eq!((utc.year(), utc.iso_week_year()), (2024, 2025));
+    // saturday 2022-01-01, the last day of an english week and of ISO week 52 of 2021
+    let saturday = dayjs::from_timestamp(1641025800);
+    assert_eq!(
+        (saturday.week_year(), saturday.iso_week_year()),
+        (2022, 2021)
+    );
+    let july = saturday.add(6, Unit::Month);
+    assert_eq!((july.week_year(), july.iso_week_year()), (2022, 2022));
+}
@@ -0,0 +1,48 @@
+use dayjs::git::{from_git_commit_time, from_git_date, GitDateFormat};
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_to_git_date() {
+    let date = from_git_commit_time(1704443400, -(3 * 60 + 30)).unwrap();
+    assert_eq!(date.to_git_date(GitDateFormat::Raw), "1704443400 -0330");
+    assert_eq!(
+        date.to_git_date(GitDateFormat::Iso),
+        "2024-01-05 05:00:00 -0330"
+    );
+    assert_eq!(
+        date.to_git_date(GitDateFormat::IsoStrict),
+        "2024-01-05T05:00:00-03:30"
+    );
+    assert_eq!(
+        date.to_git_date(GitDateFormat::Rfc2822),
+        "Fri, 5 Jan 2024 05:00:00 -0330"
+    );
+}
+
+#[test]
+fn test_from_git_date() {
+    let date = from_git_commit_time(1704443400, -(3 * 60 + 30)).unwrap();
+    for format in [
+        GitDateFormat::Raw,
+        GitDateFormat::Iso,
+        GitDateFormat::IsoStrict,
+        GitDateFormat::Rfc2822,
+    ] {
+        let parsed = from_git_date(&date.to_git_date(format)).unwrap();
+        assert_eq!(parsed, date, "{:?}", format);
+    }
+    let date = from_git_date("@1704443400 +0000").unwrap();
+    assert_eq!(date.tz, DayjsTimeZone::Offset(0));
+    assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+    assert!(from_git_date("1704443400").is_err());
+    assert!(from_git_date("2024-01-05 16:30:00").is_err());
+}
+
+#[test]
+fn test_from_git_commit_time_out_of_range() {
+    assert!(from_git_commit_time(1704443400, i32::MIN).is_err());
+    assert!(from_git_commit_time(1704443400, 24 * 60).is_err());
+    assert!(from_git_commit_time(i64::MAX, 0).is_err());
+    let date = from_git_commit_time(1704443400, 23 * 60 + 59).unwrap();
+    assert_eq!(date.to_git_date(GitDateFormat::Raw), "1704443400 +2359");
+}
@@ -0,0 +1,61 @@
+use dayjs::html::{from_html_datetime, HtmlDatetime};
+
+#[test]
+fn test_to_html_datetime() {
+    let mut date = dayjs::from_timestamp(1704443415250);
+    assert_eq!(date.to_html_datetime(), "2024-01-05T08:30:15.250Z");
+    date.tz = dayjs::DayjsTimeZone::Offset(-(5 * 3600 + 1800));
+    assert_eq!(date.to_html_datetime(), "2024-01-05T03:00:15.250-05:30");
+}
+
+#[test]
+fn test_from_html_datetime_forms() {
+    for s in [
+        "2024",
+        "2024-01",
+        "2024-01-05",
+        "01-05",
+        "08:30",
+        "08:30:15.250",
+        "2024-01-05T08:30",
+        "Z",
+        "+08:00",
+        "2024-01-05T08:30Z",
+        "2020-W53",
+        "P1DT4H18M3.5S",
+        "PT0S",
+        "P2D",
+    ] {
+        assert_eq!(from_html_datetime(s).unwrap().to_string(), s);
+    }
+    assert_eq!(
+        from_html_datetime("--02-29").unwrap(),
+        HtmlDatetime::YearlessDate { month: 2, day: 29 }
+    );
+    assert_eq!(
+        from_html_datetime("2024-01-05 08:30:00-0130")
+            .unwrap()
+            .to_string(),
+        "2024-01-05T08:30-01:30"
+    );
+    assert_eq!(
+        from_html_datetime("1w 2D 3h 4m 5.5s").unwrap().to_string(),
+        "P9DT3H4M5.5S"
+    );
+}
+
+#[test]
+fn test_from_html_datetime_errors() {
+    assert!(from_html_datetime("2024-W54").is_err());
+    assert!(from_html_datetime("02-30").is_err());
+    assert!(from_html_datetime("2024-01-05T08:30:60").is_err());
+    assert!(from_html_datetime("4h 4h").is_err());
+    let err = from_html_datetime("2024-01-05T8:30")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("expected 2 digit hour at position 11"),
+        "{}",
+        err
+    );
+}
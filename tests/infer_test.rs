@@ -0,0 +1,50 @@
+use dayjs::{infer_format, DateOrder};
+
+#[test]
+fn test_infer_format() {
+    let guesses = infer_format(&["01/02/2024", "03/04/2024", "12/11/2023"]);
+    let top: Vec<_> = guesses
+        .iter()
+        .take(2)
+        .map(|g| (g.template.as_str(), g.order, g.ambiguous))
+        .collect();
+    assert_eq!(
+        top,
+        [
+            ("DD/MM/YYYY", DateOrder::DayFirst, true),
+            ("MM/DD/YYYY", DateOrder::MonthFirst, true)
+        ]
+    );
+    // one day past 12 settles it
+    let guesses = infer_format(&["01/02/2024", "03/04/2024", "12/31/2023"]);
+    assert_eq!(guesses[0].template, "MM/DD/YYYY");
+    assert!(!guesses[0].ambiguous);
+    assert_eq!(guesses[1].template, "DD/MM/YYYY");
+    assert!((guesses[1].confidence - 2.0 / 3.0).abs() < 1e-12);
+
+    let guesses = infer_format(&["2024-01-05T08:30:00Z", "2024-01-05T08:30:00.250+02:00"]);
+    assert_eq!(guesses[0].template, "YYYY-MM-DDTHH:mm:ss.SSSZ");
+    assert_eq!(guesses[0].matched, 1);
+    assert_eq!(
+        infer_format(&["Jan 5, 2024 8:30 PM"])[0].template,
+        "MMM D, YYYY h:mm A"
+    );
+    assert!(infer_format(&["not a date"]).is_empty());
+}
+
+#[test]
+fn test_parser_profile() {
+    let mut profile = dayjs::ParserProfile::new();
+    assert_eq!(profile.last_template(), None);
+    let first = profile.parse("2024-01-05 08:30:15").unwrap();
+    assert_eq!(profile.last_template(), Some("YYYY-MM-DD HH:mm:ss"));
+    assert_eq!(profile.parse("2024-01-05 08:30:15"), Ok(first));
+    let other = profile.parse(" 31/12/2023 ").unwrap();
+    assert_eq!(other.to_string(), "2023-12-31T00:00:00+00:00");
+    assert_eq!(profile.last_template(), Some("DD/MM/YYYY"));
+    assert!(profile.parse("2024-13-05").is_err());
+    assert_eq!(profile.last_template(), Some("DD/MM/YYYY"));
+    let mut pinned = dayjs::ParserProfile::pinned(["MM/DD/YYYY"]);
+    assert_eq!(pinned.parse("01/05/2024").unwrap().date(), 5);
+    assert!(pinned.parse("31/12/2023").is_err());
+}
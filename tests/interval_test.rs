@@ -0,0 +1,99 @@
+use chrono::Duration;
+use dayjs::{DayjsTimeZone, Interval, Unit};
+
+#[test]
+fn test_windows() {
+    let now = dayjs::from_timestamp(1704443415);
+    let leading = now.leading_window(Duration::hours(36));
+    assert_eq!(leading.end.to_string(), "2024-01-06T20:30:15+00:00");
+    assert_eq!(leading.duration(), Duration::hours(36));
+    assert!(now
+        .trailing_window(Duration::hours(1))
+        .contains(&now.subtract(1, Unit::Hour)));
+    assert!(!now.trailing_window(Duration::hours(1)).contains(&now));
+    let utc = DayjsTimeZone::DEFAULT;
+    let months = now.trailing_units(3, Unit::Month, &utc);
+    assert_eq!(
+        months,
+        Interval::new(
+            dayjs::from_str("2023-10-01T00:00:00Z"),
+            dayjs::from_str("2024-01-01T00:00:00Z")
+        )
+    );
+    let weeks = now.leading_units(2, Unit::Isoweek, &utc);
+    assert_eq!(weeks.start.format("YYYY-MM-DD dddd"), "2024-01-08 Monday");
+    assert_eq!(weeks.end.format("YYYY-MM-DD"), "2024-01-22");
+    assert!(now.trailing_units(0, Unit::Day, &utc).is_empty());
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_trailing_units_dst() {
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    // 2024-03-12T12:00 in New York
+    let now = dayjs::from_timestamp(1710259200);
+    let days = now.trailing_units(7, Unit::Day, &new_york);
+    assert_eq!(
+        days.start.format("YYYY-MM-DD HH:mm Z"),
+        "2024-03-05 00:00 -05:00"
+    );
+    assert_eq!(
+        days.end.format("YYYY-MM-DD HH:mm Z"),
+        "2024-03-12 00:00 -04:00"
+    );
+    assert_eq!(days.duration(), Duration::hours(7 * 24 - 1));
+}
+
+#[test]
+fn test_period_presets() {
+    use dayjs::Period;
+    // 2024-03-01T01:00 at +02:00, a friday
+    let now = dayjs::from_timestamp(1709247600);
+    let tz = DayjsTimeZone::Offset(2 * 3600);
+    let day = |period: Period| {
+        let interval = period.at(&now, &tz);
+        (
+            interval.start.format("YYYY-MM-DD HH:mm"),
+            interval.end.format("YYYY-MM-DD HH:mm"),
+        )
+    };
+    let pair = |a: &str, b: &str| (a.to_string(), b.to_string());
+    assert_eq!(
+        day(Period::Today),
+        pair("2024-03-01 00:00", "2024-03-02 00:00")
+    );
+    assert_eq!(
+        day(Period::Yesterday),
+        pair("2024-02-29 00:00", "2024-03-01 00:00")
+    );
+    assert_eq!(
+        day(Period::ThisWeek),
+        pair("2024-02-25 00:00", "2024-03-03 00:00")
+    );
+    assert_eq!(
+        day(Period::MonthToDate),
+        pair("2024-03-01 00:00", "2024-03-01 01:00")
+    );
+    assert_eq!(
+        day(Period::Last30Days),
+        pair("2024-01-31 00:00", "2024-03-01 00:00")
+    );
+    assert_eq!(Period::Today.at(&now, &tz).start.tz, tz);
+    let today = Period::today(DayjsTimeZone::DEFAULT);
+    assert_eq!(today.duration(), Duration::days(1));
+}
+
+#[test]
+fn test_comparable_periods() {
+    // 2024-02-29T00:00 to 2024-03-01T12:00
+    let start = dayjs::from_str("2024-02-29T00:00:00Z");
+    let interval = Interval::new(start, start.add(36, Unit::Hour));
+    let previous = interval.previous_comparable();
+    assert_eq!(previous.start.to_string(), "2024-02-27T12:00:00+00:00");
+    assert_eq!(previous.duration(), interval.duration());
+    let last_year = interval.same_period_last_year();
+    assert_eq!(last_year.start.to_string(), "2023-02-28T00:00:00+00:00");
+    assert_eq!(last_year.end.to_string(), "2023-03-01T12:00:00+00:00");
+    let empty = Interval::new(start, start);
+    assert_eq!(empty.previous_comparable(), empty);
+}
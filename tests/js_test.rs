@@ -0,0 +1,19 @@
+use dayjs::js::DayjsJs;
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_js_names() {
+    let date = dayjs::from_str("2024-02-10T23:30:00Z");
+    let tokyo = date.tz(DayjsTimeZone::Offset(9 * 3600));
+    assert_eq!(date.unix(), 1707607800);
+    assert_eq!(date.daysInMonth(), 29);
+    assert_eq!(tokyo.utcOffset(), 540);
+    assert!(date.isSame(&tokyo, None));
+    // the same instant is the next day in Tokyo
+    assert!(!tokyo.isSame(&date.startOf("day"), Some("day")));
+    assert!(tokyo.isAfter(&date.startOf("day"), Some("day")));
+    assert!(date.isSameOrBefore(&date.endOf("month"), Some("month")));
+    assert!(date.isSameOrAfter(&date, Some("bogus")));
+    assert_eq!(tokyo.toISOString(), "2024-02-10T23:30:00.000Z");
+    assert_eq!(date.fromNow(), date.from_now());
+}
@@ -0,0 +1,36 @@
+use dayjs::ldap::{from_filetime, from_generalized_time};
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_filetime() {
+    let date = from_filetime(133_502_274_001_234_567);
+    assert_eq!(date.to_string(), "2024-01-20T12:30:00.123456700+00:00");
+    assert_eq!(date.to_filetime(), 133_502_274_001_234_567);
+    assert_eq!(from_filetime(0).to_string(), "1601-01-01T00:00:00+00:00");
+    let epoch = from_filetime(116_444_736_000_000_000);
+    assert_eq!(epoch.to_string(), "1970-01-01T00:00:00+00:00");
+}
+
+#[test]
+fn test_generalized_time() {
+    let date = from_generalized_time("20250325172147.0Z").unwrap();
+    assert_eq!(date.to_generalized_time(), "20250325172147.0Z");
+    let date = from_generalized_time("2025032517,25Z").unwrap();
+    assert_eq!(date.to_string(), "2025-03-25T17:15:00+00:00");
+    let date = from_generalized_time("20250325122147-0500").unwrap();
+    assert_eq!(date.tz, DayjsTimeZone::Offset(-5 * 3600));
+    assert_eq!(date.to_generalized_time(), "20250325172147.0Z");
+    assert_eq!(
+        dayjs::from_timestamp(1742923307250).to_generalized_time(),
+        "20250325172147.25Z"
+    );
+    let err = from_generalized_time("20250325172147")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("expected '+' or '-' at position 14"),
+        "{}",
+        err
+    );
+    assert!(from_generalized_time("20251325172147Z").is_err());
+}
@@ -3,3 +3,46 @@ fn test_dayjs() {
     let now = dayjs::dayjs();
     println!("{}", &now);
 }
+
+#[test]
+fn test_ord_hash() {
+    use dayjs::DayjsTimeZone;
+    use std::collections::HashMap;
+    let a = dayjs::from_timestamp(1704443400);
+    let b = dayjs::from_timestamp(1704443401);
+    let shifted = a.tz(DayjsTimeZone::Offset(3600));
+    let mut dates = vec![b, shifted, a];
+    dates.sort();
+    assert_eq!(dates, [a, shifted, b]);
+    assert_ne!(a, shifted);
+    assert!(a < shifted && shifted < b);
+    assert_eq!(dates.iter().max(), Some(&b));
+    let mut counts = HashMap::new();
+    for date in [a, b, a, shifted] {
+        *counts.entry(date).or_insert(0) += 1;
+    }
+    assert_eq!((counts[&a], counts[&b], counts[&shifted]), (2, 1, 1));
+}
+
+#[test]
+fn test_min_max() {
+    use dayjs::DayjsTimeZone;
+    let a = dayjs::from_timestamp(1704443400);
+    let shifted = a.tz(DayjsTimeZone::Offset(3600));
+    let earlier = dayjs::from_timestamp(1704443399);
+    assert_eq!(dayjs::min([shifted, a, earlier]), Some(earlier));
+    assert_eq!(dayjs::max(vec![a, shifted, earlier]), Some(shifted));
+}
+
+#[test]
+fn test_capabilities() {
+    let capabilities = dayjs::capabilities();
+    assert_eq!(capabilities.locales.len(), 6);
+    #[cfg(feature = "tz")]
+    {
+        assert_eq!(capabilities.tz_version.map(str::len), Some(5));
+        assert_eq!(capabilities.missing(), ["lunar", "astro"]);
+    }
+    #[cfg(not(feature = "tz"))]
+    assert_eq!(capabilities.missing(), ["tz", "lunar", "astro"]);
+}
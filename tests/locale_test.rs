@@ -0,0 +1,135 @@
+use dayjs::locale;
+
+#[test]
+fn test_months_format_next_to_day() {
+    let date = dayjs::from_timestamp(1704412800);
+    assert_eq!(
+        date.format_locale("D MMMM YYYY", &locale::RU),
+        "5 января 2024"
+    );
+    assert_eq!(date.format_locale("DD MMMM", &locale::PL), "05 stycznia");
+    assert_eq!(date.format_locale("D MMMM", &locale::UK), "5 січня");
+    assert_eq!(date.format_locale("D.MMMM", &locale::PL), "5.stycznia");
+    // like the dayjs lt locale, a month before the day is declined too
+    assert_eq!(
+        date.format_locale("YYYY MMMM D", &locale::LT),
+        "2024 sausio 5"
+    );
+}
+
+#[test]
+fn test_months_standalone() {
+    let date = dayjs::from_timestamp(1704412800);
+    assert_eq!(date.format_locale("MMMM YYYY", &locale::RU), "январь 2024");
+    assert_eq!(date.format_locale("YYYY MMMM", &locale::LT), "2024 sausis");
+    assert_eq!(date.format_locale("MMMM D", &locale::RU), "январь 5");
+    assert_eq!(
+        date.format_locale("dddd, D MMMM", &locale::EN),
+        "Friday, 5 January"
+    );
+}
+
+#[test]
+fn test_negotiate() {
+    assert_eq!(
+        dayjs::Locale::negotiate("ru-RU,ru;q=0.9,en;q=0.8").name,
+        "ru"
+    );
+    assert_eq!(dayjs::Locale::negotiate("en;q=0.5, pl;q=0.9").name, "pl");
+    assert_eq!(dayjs::Locale::negotiate("zh-TW, zh;q=0.9").name, "zh-cn");
+    assert_eq!(dayjs::Locale::negotiate("zh, en;q=0.5").name, "zh-cn");
+    assert_eq!(dayjs::Locale::negotiate("fr-CH, fr").name, "en");
+    assert_eq!(dayjs::Locale::negotiate("uk;q=0, lt_LT").name, "lt");
+    assert_eq!(dayjs::Locale::negotiate("").name, "en");
+    assert_eq!(dayjs::Locale::find("PL").map(|l| l.name), Some("pl"));
+}
+
+#[test]
+fn test_format_style() {
+    use dayjs::{DateStyle, TimeStyle};
+    let date = dayjs::from_timestamp(1707145445);
+    let full = date.format_style(DateStyle::Full, TimeStyle::Short, &locale::EN);
+    assert_eq!(full, "Monday, February 5, 2024 at 3:04 PM");
+    let uk = date.format_style(DateStyle::Medium, TimeStyle::Medium, &locale::UK);
+    assert_eq!(uk, "5 лют 2024 р., 15:04:05");
+    let lt = date.format_style(DateStyle::Long, TimeStyle::None, &locale::LT);
+    assert_eq!(lt, "2024 m. vasario 5 d.");
+    let pl = date.format_style(DateStyle::Short, TimeStyle::Long, &locale::PL);
+    assert_eq!(pl, "5.02.2024, 15:04:05 UTC");
+    let ru = date.format_style(DateStyle::None, TimeStyle::Full, &locale::RU);
+    assert_eq!(ru, "15:04:05 Всемирное координированное время");
+    #[cfg(feature = "tz")]
+    {
+        let new_york = dayjs::DayjsTimeZone::city("America/New_York").unwrap();
+        let date = date.tz(new_york);
+        let full = date.format_style(DateStyle::None, TimeStyle::Full, &locale::EN);
+        assert_eq!(full, "10:04:05 AM Eastern Standard Time");
+        let long = date.format_style(DateStyle::None, TimeStyle::Long, &locale::EN);
+        assert_eq!(long, "10:04:05 AM EST");
+    }
+    assert_eq!(
+        date.format_style(DateStyle::None, TimeStyle::None, &locale::PL),
+        ""
+    );
+}
+
+#[test]
+fn test_format_escaped() {
+    let date = dayjs::from_timestamp(1707145445);
+    assert_eq!(date.format_locale("[at] h:mm a", &locale::EN), "at 3:04 pm");
+}
+
+#[test]
+fn test_ordinal() {
+    let date = dayjs::from_timestamp(1706659200);
+    assert_eq!(date.format_locale("MMMM Do", &locale::EN), "January 31st");
+    assert_eq!(locale::EN.ordinal(112), "112th");
+    assert_eq!(locale::EN.ordinal(23), "23rd");
+    assert_eq!(date.format_locale("Do MMMM", &locale::LT), "31. sausio");
+    assert_eq!(date.format_locale("MMMDo", &locale::ZH_CN), "1月31日");
+}
+
+#[test]
+fn test_active_locale() {
+    let date = dayjs::from_timestamp(1704443400);
+    assert!(dayjs::locale("xx").is_none());
+    assert_eq!(dayjs::Locale::current().name, "en");
+    assert_eq!(dayjs::locale("zh_CN").map(|l| l.name), Some("zh-cn"));
+    assert_eq!(date.format("MMMM D日 A h:mm"), "一月 5日 上午 8:30");
+    assert_eq!(dayjs::ordinal(3), "3日");
+    let later = dayjs::from_timestamp(1704616200);
+    assert_eq!(date.from(&later), "2 天前");
+    assert_eq!(date.to_rss_date(), "Fri, 05 Jan 2024 08:30:00 +0000");
+    let next_week = dayjs::from_timestamp(1704875400);
+    assert_eq!(next_week.calendar(Some(&date)), "下周三08:30");
+    dayjs::locale("ru");
+    assert_eq!(date.weekday(), 4);
+    let monday = date.set_weekday(0);
+    assert_eq!(monday.format("YYYY-MM-DD dddd"), "2024-01-01 понедельник");
+    assert_eq!(date.start_of("week"), monday.start_of("day"));
+    let sunday = dayjs::from_timestamp(1704616200);
+    assert_eq!(
+        sunday.end_of("week").format("YYYY-MM-DD HH:mm"),
+        "2024-01-07 23:59"
+    );
+    assert!(sunday.is_same_unit(&monday, dayjs::Unit::Week));
+    // friday 2021-01-01, the first russian week holds january 4th
+    let new_year = dayjs::from_timestamp(1609502400);
+    assert_eq!(new_year.week_year(), 2020);
+    assert_eq!(date.format("D MMMM, dddd"), "5 января, пятница");
+    let parsed = dayjs::from_format_dayjs("5 января 2024, пятница", "D MMMM YYYY, dddd");
+    assert_eq!(parsed.unwrap().format("YYYY-MM-DD"), "2024-01-05");
+    let thursday = date.subtract(1, dayjs::Unit::Day);
+    assert_eq!(thursday.calendar(Some(&later)), "В прошлый четверг, в 8:30");
+    assert_eq!(date.format("LLL"), "5 января 2024 г., 8:30");
+    let parsed = dayjs::from_format_dayjs("05.01.2024 8:30", "L LT").unwrap();
+    assert_eq!(parsed, date);
+    dayjs::locale("uk");
+    assert_eq!(thursday.calendar(Some(&later)), "Минулого четвер о 08:30");
+    dayjs::locale("pl");
+    assert_eq!(thursday.calendar(Some(&later)), "W zeszły czwartek o 08:30");
+    dayjs::locale("en");
+    assert_eq!(date.format("dddd, MMMM Do"), "Friday, January 5th");
+    assert_eq!(dayjs::ordinal(103), "103rd");
+    assert_eq!(new_year.week_year(), 2021);
+}
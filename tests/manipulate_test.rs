@@ -0,0 +1,321 @@
+use dayjs::{DayjsTimeZone, Unit};
+
+#[test]
+fn test_start_of() {
+    let mut date = dayjs::from_timestamp(1704443415);
+    assert_eq!(
+        date.start_of("year").to_string(),
+        "2024-01-01T00:00:00+00:00"
+    );
+    assert_eq!(date.start_of("D").to_string(), "2024-01-05T00:00:00+00:00");
+    assert_eq!(
+        date.start_of("minutes").to_string(),
+        "2024-01-05T08:30:00+00:00"
+    );
+    assert_eq!(date.start_of("fortnight"), date);
+    // 14:15:15 in Kathmandu
+    date.tz = DayjsTimeZone::Offset(5 * 3600 + 2700);
+    assert_eq!(date.start_of("hour").format("HH:mm Z"), "14:00 +05:45");
+    assert_eq!(
+        date.start_of("day").format("YYYY-MM-DD HH:mm"),
+        "2024-01-05 00:00"
+    );
+}
+
+#[test]
+fn test_end_of() {
+    let mut date = dayjs::from_timestamp(1709214615);
+    assert_eq!(
+        date.end_of("month").to_string(),
+        "2024-02-29T23:59:59.999+00:00"
+    );
+    assert_eq!(
+        date.end_of("week").to_string(),
+        "2024-03-02T23:59:59.999+00:00"
+    );
+    assert_eq!(
+        date.end_of("s").to_string(),
+        "2024-02-29T13:50:15.999+00:00"
+    );
+    date.tz = DayjsTimeZone::Offset(-8 * 3600);
+    assert_eq!(
+        date.end_of("day").to_string(),
+        "2024-03-01T07:59:59.999+00:00"
+    );
+}
+
+#[test]
+fn test_unit_from_str() {
+    assert_eq!("Months".parse::<Unit>(), Ok(Unit::Month));
+    assert_eq!("M".parse::<Unit>(), Ok(Unit::Month));
+    assert_eq!("m".parse::<Unit>(), Ok(Unit::Minute));
+    assert_eq!("ms".parse::<Unit>(), Ok(Unit::Millisecond));
+    assert!("fortnight".parse::<Unit>().is_err());
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_city_start_end_of_dst() {
+    // 2024-03-10T12:00:00 in New York, the day clocks spring forward
+    let mut date = dayjs::from_timestamp(1710086400);
+    date.tz = DayjsTimeZone::city("America/New_York").unwrap();
+    assert_eq!(date.to_local().to_rfc3339(), "2024-03-10T12:00:00-04:00");
+    assert_eq!(
+        date.start_of("day").to_string(),
+        "2024-03-10T05:00:00+00:00"
+    );
+    assert_eq!(
+        date.end_of("day").to_string(),
+        "2024-03-11T03:59:59.999+00:00"
+    );
+    assert_eq!(date.start_of("day").format("HH:mm Z"), "00:00 -05:00");
+    assert_eq!(date.end_of("day").format("HH:mm:ss Z"), "23:59:59 -04:00");
+}
+
+#[test]
+fn test_add_subtract() {
+    let date = dayjs::from_timestamp(1706704215);
+    assert_eq!(
+        date.add(1, Unit::Day).start_of("day").to_string(),
+        "2024-02-01T00:00:00+00:00"
+    );
+    assert_eq!(date.add(1, Unit::Month).format("YYYY-MM-DD"), "2024-02-29");
+    assert_eq!(
+        date.subtract(2, Unit::Month).format("YYYY-MM-DD"),
+        "2023-11-30"
+    );
+    assert_eq!(date.add(2, Unit::Week).format("YYYY-MM-DD"), "2024-02-14");
+    assert_eq!(
+        date.subtract(1500, Unit::Millisecond).format("ss.SSS"),
+        "13.500"
+    );
+    assert_eq!(date.add(1, Unit::Year).add(-1, Unit::Year), date);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_add_across_dst() {
+    // 2024-03-09T12:00:00 in New York, the day before clocks spring forward
+    let mut date = dayjs::from_timestamp(1710003600);
+    date.tz = DayjsTimeZone::city("America/New_York").unwrap();
+    assert_eq!(date.add(1, Unit::Day).format("HH:mm Z"), "12:00 -04:00");
+    assert_eq!(date.add(24, Unit::Hour).format("HH:mm Z"), "13:00 -04:00");
+}
+
+#[test]
+fn test_start_end_of_in() {
+    // 2024-01-05T19:16:55Z, already the 6th in +05:30 and still the 5th in -08:00
+    let date = dayjs::from_timestamp(1704482215);
+    let ist = DayjsTimeZone::Offset(5 * 3600 + 1800);
+    let pst = DayjsTimeZone::Offset(-8 * 3600);
+    assert_eq!(
+        date.start_of_in(Unit::Day, &ist).to_string(),
+        "2024-01-05T18:30:00+00:00"
+    );
+    assert_eq!(
+        date.end_of_in(Unit::Day, &pst).to_string(),
+        "2024-01-06T07:59:59.999+00:00"
+    );
+    assert_eq!(
+        date.start_of_in(Unit::Hour, &ist).format("HH:mm Z"),
+        "00:00 +05:30"
+    );
+    assert_eq!(date.end_of_in(Unit::Month, &ist).format("MM-DD"), "01-31");
+    assert_eq!(date.start_of_in(Unit::Day, &pst).tz, pst);
+    assert_eq!(date.tz, DayjsTimeZone::DEFAULT);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_end_of_in_city() {
+    let date = dayjs::from_timestamp(1710086400);
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    assert_eq!(
+        date.end_of_in(Unit::Day, &new_york).to_string(),
+        "2024-03-11T03:59:59.999+00:00"
+    );
+}
+
+#[test]
+fn test_quarter_isoweek() {
+    // sunday 2024-11-17
+    let date = dayjs::from_timestamp(1731844800);
+    assert_eq!(
+        date.start_of_unit(Unit::Isoweek).format("YYYY-MM-DD"),
+        "2024-11-11"
+    );
+    assert_eq!(
+        date.end_of_unit(Unit::Isoweek).to_string(),
+        "2024-11-17T23:59:59.999+00:00"
+    );
+    assert_eq!(
+        date.start_of_unit(Unit::Week).format("YYYY-MM-DD"),
+        "2024-11-17"
+    );
+    assert_eq!(date.start_of("Q").to_string(), "2024-10-01T00:00:00+00:00");
+    assert_eq!(
+        date.end_of("quarter").to_string(),
+        "2024-12-31T23:59:59.999+00:00"
+    );
+    assert_eq!(
+        date.add(1, Unit::Quarter).format("YYYY-MM-DD"),
+        "2025-02-17"
+    );
+    assert_eq!(
+        date.subtract(2, Unit::Isoweek).format("YYYY-MM-DD"),
+        "2024-11-03"
+    );
+    assert_eq!("isoWeek".parse::<Unit>(), Ok(Unit::Isoweek));
+    assert_eq!("quarters".parse::<Unit>(), Ok(Unit::Quarter));
+}
+
+#[test]
+fn test_add_months_years() {
+    // 2024-01-31T20:00 at +10:00
+    let mut date = dayjs::from_timestamp(1706695200);
+    date.tz = DayjsTimeZone::Offset(10 * 3600);
+    let ends: Vec<String> = (1..=3)
+        .map(|n| date.add_months(n).format("YYYY-MM-DD HH:mm"))
+        .collect();
+    assert_eq!(
+        ends,
+        ["2024-02-29 20:00", "2024-03-31 20:00", "2024-04-30 20:00"]
+    );
+    assert_eq!(date.add_months(-36).format("YYYY-MM-DD"), "2021-01-31");
+    assert_eq!(date.add_months(-11).format("YYYY-MM-DD"), "2023-02-28");
+    assert_eq!(
+        date.add_years(-1).add_months(1).format("YYYY-MM-DD"),
+        "2023-02-28"
+    );
+    assert_eq!(date.add_years(100), date.add_months(1200));
+    assert_eq!(date.add_months(i64::MAX), date);
+}
+
+#[test]
+fn test_operators() {
+    let mut date = dayjs::from_timestamp(1706704215);
+    date.tz = DayjsTimeZone::Offset(3600);
+    let later = date + chrono::Duration::milliseconds(1500);
+    assert_eq!(later.format("HH:mm:ss.SSS Z"), "13:30:16.500 +01:00");
+    assert_eq!(later - date, chrono::Duration::milliseconds(1500));
+    assert_eq!(date - later, chrono::Duration::milliseconds(-1500));
+    assert_eq!(later - chrono::Duration::milliseconds(1500), date);
+    let mut moved = date;
+    moved += chrono::Duration::days(1);
+    assert_eq!(moved, date.add(1, Unit::Day));
+    moved -= chrono::Duration::weeks(1);
+    assert_eq!(moved.format("YYYY-MM-DD"), "2024-01-25");
+}
+
+#[test]
+fn test_week_start() {
+    use chrono::Weekday;
+    // sunday 2024-11-17T20:00Z, already monday at +05:00
+    let date = dayjs::from_timestamp(1731873600);
+    let east = date.tz(DayjsTimeZone::Offset(5 * 3600));
+    assert_eq!(
+        date.start_of_week(Weekday::Mon).format("YYYY-MM-DD"),
+        "2024-11-11"
+    );
+    assert_eq!(
+        east.start_of_week(Weekday::Mon).to_string(),
+        "2024-11-17T19:00:00+00:00"
+    );
+    assert_eq!(
+        east.end_of_week(Weekday::Sun)
+            .format("YYYY-MM-DD HH:mm:ss.SSS"),
+        "2024-11-23 23:59:59.999"
+    );
+    assert_eq!(
+        date.start_of_week(Weekday::Sun),
+        date.start_of_unit(Unit::Week)
+    );
+    // the other instant is read in the timezone of the receiver
+    let later = date.add(6, Unit::Hour);
+    assert!(!date.is_same_unit(&later, Unit::Isoweek));
+    assert!(east.is_same_unit(&later, Unit::Isoweek));
+    assert!(east.is_same_unit(&later, Unit::Day));
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_month_days_across_dst() {
+    // 2024-03-15T12:00:00 in London, clocks go forward on 2024-03-31 at 01:00
+    let london = DayjsTimeZone::city("Europe/London").unwrap();
+    let date = dayjs::from_timestamp(1710504000).tz(london);
+    let format = |d: dayjs::Dayjs| d.format("YYYY-MM-DD HH:mm:ss.SSS Z");
+    assert_eq!(
+        format(date.first_day_of_month()),
+        "2024-03-01 00:00:00.000 +00:00"
+    );
+    assert_eq!(
+        format(date.last_day_of_month()),
+        "2024-03-31 00:00:00.000 +00:00"
+    );
+    assert_eq!(
+        format(date.end_of("month")),
+        "2024-03-31 23:59:59.999 +01:00"
+    );
+    assert_eq!(
+        date.end_of("month").start_of("day"),
+        date.last_day_of_month()
+    );
+    // 2024-11-20 in New York, after clocks went back on 2024-11-03
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    let date = dayjs::from_timestamp(1732122000).tz(new_york);
+    assert_eq!(
+        format(date.first_day_of_month()),
+        "2024-11-01 00:00:00.000 -04:00"
+    );
+    assert_eq!(
+        format(date.last_day_of_month()),
+        "2024-11-30 00:00:00.000 -05:00"
+    );
+}
+
+#[test]
+fn test_bounds_of() {
+    use dayjs::{Epoch, Precision};
+    // 2024-05-31T23:59:59.9995 at +09:00, after end_of("month") but still in may
+    let late = dayjs::from_epoch(
+        1_717_167_599_999_500_000,
+        Epoch::Unix,
+        Precision::Nanoseconds,
+    )
+    .tz(DayjsTimeZone::Offset(9 * 3600));
+    let (start, end) = late.bounds_of(Unit::Month);
+    assert_eq!(
+        start.format("YYYY-MM-DD HH:mm Z"),
+        "2024-05-01 00:00 +09:00"
+    );
+    assert_eq!(end.format("YYYY-MM-DD HH:mm Z"), "2024-06-01 00:00 +09:00");
+    assert!(late > late.end_of_unit(Unit::Month));
+    assert!(dayjs::Interval::new(start, end).contains(&late));
+    assert_eq!(late.bounds_of(Unit::Hour).1, end);
+    assert_eq!(end.bounds_of(Unit::Day).0, end);
+}
+
+#[test]
+fn test_add_out_of_range() {
+    let date = dayjs::from_timestamp(1704443415);
+    assert_eq!(date.add(1_000_000_000, Unit::Day), date);
+    assert_eq!(date.add(i64::MAX, Unit::Week), date);
+    assert_eq!(date.add(i64::MAX, Unit::Hour), date);
+    assert_eq!(date.add(i64::MIN, Unit::Millisecond), date);
+    assert_eq!(date.add_days(i64::MIN), date);
+    assert_eq!(date.set_weekday(i64::MAX), date);
+    assert_eq!(date.set_iso_weekday(i64::MIN), date);
+    let (max, min) = (
+        chrono::DateTime::<chrono::Utc>::MAX_UTC,
+        chrono::DateTime::<chrono::Utc>::MIN_UTC,
+    );
+    let far = date + chrono::Duration::MAX;
+    assert_eq!(far.to_local().naive_local(), max.naive_utc());
+    let mut early = date - chrono::Duration::MAX;
+    assert_eq!(early.to_local().naive_local(), min.naive_utc());
+    early -= chrono::Duration::days(1);
+    assert_eq!(early.to_local().naive_local(), min.naive_utc());
+    early += chrono::Duration::MAX;
+    assert_eq!(early.to_local().naive_local(), max.naive_utc());
+    assert_eq!(date.add(2, Unit::Day).format("YYYY-MM-DD"), "2024-01-07");
+}
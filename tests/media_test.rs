@@ -0,0 +1,37 @@
+use dayjs::media::{from_dos, from_exif, from_quicktime};
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_from_exif() {
+    let date = from_exif("2024:01:05 08:30:00", None).unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+    let date = from_exif("2024:01:05 03:00:00\0", Some("-05:30\0")).unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+    assert_eq!(date.tz, DayjsTimeZone::Offset(-(5 * 3600 + 1800)));
+    assert!(from_exif("    :  :     :  :  ", None).is_err());
+    let err = from_exif("2024-01-05 08:30:00", None)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected ':' at position 4"), "{}", err);
+    assert!(from_exif("2024:01:05 08:30:00", Some("+0900")).is_err());
+}
+
+#[test]
+fn test_quicktime() {
+    let date = from_quicktime(3_787_288_200);
+    assert_eq!(date.to_string(), "2024-01-05T08:30:00+00:00");
+    assert_eq!(date.to_quicktime(), 3_787_288_200);
+    assert_eq!(from_quicktime(0).to_string(), "1904-01-01T00:00:00+00:00");
+}
+
+#[test]
+fn test_from_dos() {
+    let date = from_dos(0x5825, 0x43c1).unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T08:30:02+00:00");
+    assert_eq!(
+        from_dos(0x0021, 0).unwrap().to_string(),
+        "1980-01-01T00:00:00+00:00"
+    );
+    assert!(from_dos(0x5820, 0).is_err());
+    assert!(from_dos(0x5825, 0xc000).is_err());
+}
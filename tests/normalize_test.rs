@@ -0,0 +1,45 @@
+use dayjs::normalize::{Normalizer, Raw};
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_normalizer() {
+    let mut normalizer = Normalizer::new("YYYY-MM-DDTHH:mm:ssZ", DayjsTimeZone::DEFAULT)
+        .hint("when", "MM/DD/YYYY")
+        .hint("when", "MMM D YYYY");
+    let inputs: [Raw; 8] = [
+        "2024-01-05T08:30:00+02:00".into(),
+        "20240105T083000Z".into(),
+        "Fri, 05 Jan 2024 08:30:00 GMT".into(),
+        " 1704443400 ".into(),
+        1704443400123456.into(),
+        "01/05/2024".into(),
+        "Jan 5 2024".into(),
+        "5 janvier 2024".into(),
+    ];
+    let out: Vec<_> = inputs
+        .into_iter()
+        .map(|raw| normalizer.normalize("when", raw).map_err(|e| e.to_string()))
+        .collect();
+    assert_eq!(
+        out,
+        [
+            Ok("2024-01-05T06:30:00+00:00".to_string()),
+            Ok("2024-01-05T08:30:00+00:00".to_string()),
+            Ok("2024-01-05T08:30:00+00:00".to_string()),
+            Ok("2024-01-05T08:30:00+00:00".to_string()),
+            Ok("2024-01-05T08:30:00+00:00".to_string()),
+            Ok("2024-01-05T00:00:00+00:00".to_string()),
+            Ok("2024-01-05T00:00:00+00:00".to_string()),
+            Err("invalid timestamp \"5 janvier 2024\": no known format matches".to_string()),
+        ]
+    );
+    // hints belong to their field
+    assert!(normalizer.normalize("other", "01/05/2024").is_err());
+    let when = &normalizer.stats()["when"];
+    assert_eq!((when.normalized, when.failed), (7, 1));
+    assert!(when.last_error.is_some());
+    let total = normalizer.total();
+    assert_eq!((total.normalized, total.failed), (7, 2));
+    let date = normalizer.parse("when", -86400).unwrap();
+    assert_eq!(date.to_string(), "1969-12-31T00:00:00+00:00");
+}
@@ -0,0 +1,68 @@
+use dayjs::{from_object, DayjsObject, DayjsTimeZone, ZoneKind};
+
+#[test]
+fn test_object_versions() {
+    let mut date = dayjs::from_timestamp(1704443400);
+    date.tz = DayjsTimeZone::Local;
+    let v2 = date.to_object_v2();
+    assert_eq!(v2.version(), 2);
+    let read: DayjsObject = v2.to_string().parse().unwrap();
+    assert_eq!(from_object(&read).unwrap(), date);
+
+    let v1: DayjsObject = r#" { "milliseconds": 5, "seconds": 0, "minutes": 30, "hours": 8,
+        "date": 29, "months": 1, "years": 2024 } "#
+        .parse()
+        .unwrap();
+    assert_eq!(v1.version(), 1);
+    let date = from_object(&v1).unwrap();
+    assert_eq!(date.to_string(), "2024-02-29T08:30:00.005+00:00");
+    assert_eq!(date.to_object(), v1);
+
+    let newer = r#"{"version":2,"unix":-1,"nanos":999999999,"zone":"utc","source":"queue"}"#;
+    let date = from_object(&newer.parse().unwrap()).unwrap();
+    assert_eq!(date.to_string(), "1969-12-31T23:59:59.999999999+00:00");
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_object_city() {
+    let mut date = dayjs::from_timestamp(1720168200);
+    date.tz = DayjsTimeZone::city("America/New_York").unwrap();
+    let object = date.to_object_v2();
+    assert_eq!(
+        object,
+        DayjsObject::V2 {
+            unix: 1720168200,
+            nanos: 0,
+            zone: ZoneKind::City("America/New_York".into()),
+        }
+    );
+    assert_eq!(from_object(&object).unwrap().tz, date.tz);
+}
+
+#[test]
+fn test_object_errors() {
+    let parse = |s: &str| s.parse::<DayjsObject>().unwrap_err().to_string();
+    assert!(parse(r#"{"version":3}"#).contains("unsupported version 3"));
+    assert!(parse(r#"{"version":2,"unix":0,"nanos":0}"#).contains(r#"missing "zone""#));
+    assert!(parse(r#"{"years":2024,"years":2025}"#).contains("duplicate"));
+    assert!(parse(r#"{"version":2} x"#).contains("after the value"));
+    let v1 = DayjsObject::V1 {
+        years: 2023,
+        months: 1,
+        date: 29,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        milliseconds: 0,
+    };
+    assert!(from_object(&v1).is_err());
+    let far = DayjsObject::V2 {
+        unix: 0,
+        nanos: 0,
+        zone: ZoneKind::Offset(86_400),
+    };
+    assert!(from_object(&far).is_err());
+    let min = r#"{"version":2,"unix":0,"nanos":0,"zone":"offset","offset":-2147483648}"#;
+    assert!(from_object(&min.parse().unwrap()).is_err());
+}
@@ -0,0 +1,63 @@
+use chrono::Weekday;
+use dayjs::oncall::Rotation;
+use dayjs::{DayjsTime, DayjsTimeZone};
+
+#[test]
+fn test_rotation() {
+    let handoff = DayjsTime::new(10, 0, 0).unwrap();
+    // wednesday 2024-01-03T10:00:00Z
+    let start = dayjs::from_timestamp(1704276000);
+    let rotation = Rotation::weekly(vec!["ana", "bo"], handoff, DayjsTimeZone::DEFAULT)
+        .on(Weekday::Wed)
+        .starting(&start);
+    let before = dayjs::from_timestamp(1704275999);
+    assert_eq!(rotation.who_is_on_call(&before), Some("bo"));
+    assert_eq!(rotation.who_is_on_call(&start), Some("ana"));
+    // tuesday of the next week is still ana's shift
+    let tuesday = dayjs::from_timestamp(1704794400);
+    assert_eq!(rotation.who_is_on_call(&tuesday), Some("ana"));
+    let shift = rotation.shifts(&tuesday).nth(1).unwrap();
+    assert_eq!(shift.participant, "bo");
+    assert_eq!(
+        shift.interval.start.to_string(),
+        "2024-01-10T10:00:00+00:00"
+    );
+    assert_eq!(shift.interval.end.to_string(), "2024-01-17T10:00:00+00:00");
+    let empty = Rotation::weekly(Vec::<String>::new(), handoff, DayjsTimeZone::DEFAULT);
+    assert_eq!(empty.who_is_on_call(&start), None);
+    assert_eq!(empty.shifts(&start).count(), 0);
+    let last = dayjs::from_epoch(i64::MAX, dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    let first = dayjs::from_epoch(i64::MIN, dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    assert_eq!(rotation.who_is_on_call(&last), None);
+    assert_eq!(rotation.who_is_on_call(&first), None);
+    let before_last = last.subtract(30, dayjs::Unit::Day);
+    assert!(rotation.shifts(&before_last).count() <= 5);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_rotation_dst() {
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    let handoff = "09:00".parse().unwrap();
+    // monday 2024-03-04T09:00 in New York
+    let start = dayjs::from_timestamp(1709560800);
+    let rotation = Rotation::weekly(["ana", "bo"], handoff, new_york).starting(&start);
+    let shifts: Vec<_> = rotation.shifts(&start).take(2).collect();
+    assert_eq!(shifts[0].interval.start.format("HH:mm Z"), "09:00 -05:00");
+    // clocks spring forward on 2024-03-10, the shift is an hour shorter
+    assert_eq!(shifts[0].interval.end.format("HH:mm Z"), "09:00 -04:00");
+    assert_eq!(shifts[0].interval.duration(), chrono::Duration::hours(167));
+    assert_eq!(shifts[1].participant, "bo");
+}
+
+#[test]
+fn test_dayjs_time() {
+    let time: DayjsTime = "07:05".parse().unwrap();
+    assert_eq!((time.hour(), time.minute(), time.second()), (7, 5, 0));
+    assert!("7pm".parse::<DayjsTime>().is_err());
+    assert!(DayjsTime::MIDNIGHT < time);
+    let date = dayjs::from_timestamp(1704443415);
+    assert_eq!(date.time_of_day().to_string(), "08:30:15");
+    let later = date.tz(DayjsTimeZone::Offset(-10 * 3600)).with_time(time);
+    assert_eq!(later.to_string(), "2024-01-04T17:05:00+00:00");
+}
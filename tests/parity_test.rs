@@ -0,0 +1,53 @@
+use dayjs::parity::{compare, Mismatch, MismatchKind};
+
+#[test]
+fn test_compare() {
+    let rust = r#"{"cases": [{"name": "DST end", "out": "01:30"}, {"name": "x"}], "a b": null}"#;
+    let js = r#"{
+        "cases": [{"name": "DST end", "out": "02:30"}],
+        "a b": 0,
+        "emoji": "\ud83d\ude00\n"
+    }"#;
+    let report = compare(rust, js).unwrap();
+    assert!(!report.is_match());
+    assert_eq!(report.compared, 2);
+    assert_eq!(
+        report.mismatches,
+        [
+            Mismatch {
+                path: "$.cases[0].out".to_string(),
+                kind: MismatchKind::Value {
+                    rust: r#""01:30""#.to_string(),
+                    js: r#""02:30""#.to_string()
+                },
+            },
+            Mismatch {
+                path: "$.cases[1]".to_string(),
+                kind: MismatchKind::MissingInJs,
+            },
+            Mismatch {
+                path: r#"$["a b"]"#.to_string(),
+                kind: MismatchKind::Type {
+                    rust: "null",
+                    js: "number"
+                },
+            },
+            Mismatch {
+                path: "$.emoji".to_string(),
+                kind: MismatchKind::MissingInRust,
+            },
+        ]
+    );
+    let same = compare(r#"[1e3, true, "\u00e9"]"#, r#"[1000, true, "é"]"#).unwrap();
+    assert!(same.is_match());
+    assert_eq!(same.to_string(), "3 compared, 0 mismatched");
+}
+
+#[test]
+fn test_compare_errors() {
+    let err = compare("{\"a\": 01}", "{}").unwrap_err().to_string();
+    assert!(err.starts_with("invalid Rust JSON"), "{}", err);
+    assert!(compare("{}", "[1,]").is_err());
+    assert!(compare("{}", "\"\\ud800\"").is_err());
+    assert!(compare("{}", &"[".repeat(200)).is_err());
+}
@@ -0,0 +1,242 @@
+use dayjs::{
+    from_format_dayjs, from_format_dayjs_strict, parse_iso8601, parse_rfc3339_strict, DayjsTimeZone,
+};
+
+#[test]
+fn test_rfc3339_strict() {
+    let date = parse_rfc3339_strict("2024-01-05T08:30:00+08:00").unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T00:30:00+00:00");
+    assert_eq!(date.tz, DayjsTimeZone::Offset(8 * 3600));
+    let lower = parse_rfc3339_strict("2024-01-05t00:30:00z").unwrap();
+    assert_eq!(lower.to_string(), date.to_string());
+    assert_eq!(lower.tz, DayjsTimeZone::DEFAULT);
+    let unknown = parse_rfc3339_strict("2024-01-05T00:30:00-00:00").unwrap();
+    assert_eq!(unknown.tz, DayjsTimeZone::DEFAULT);
+}
+
+#[test]
+fn test_rfc3339_strict_errors() {
+    let err = parse_rfc3339_strict("2024-01-05 08:30:00Z")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("space separator") && err.contains("position 10"),
+        "{}",
+        err
+    );
+    let err = parse_rfc3339_strict("2024-01-05T08:30:00")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("requires one") && err.contains("position 19"),
+        "{}",
+        err
+    );
+    let err = parse_rfc3339_strict("2024-01-05T08:30Z")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected ':'"), "{}", err);
+    let err = parse_rfc3339_strict("2024-02-30T08:30:00Z")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("date out of range"), "{}", err);
+    assert!(parse_rfc3339_strict("2024-01-05T08:30:00+0800").is_err());
+}
+
+#[test]
+fn test_iso8601() {
+    let expected = "2024-01-05T08:30:00+00:00";
+    for s in [
+        "2024-01-05T08:30:00Z",
+        "20240105T083000Z",
+        "2024-005T08:30",
+        "2024-W01-5T08:30Z",
+        "2024W015T0830",
+        "2024-01-05T08.5",
+        "2024-01-05T10:30+02",
+        "20240105T1030+0200",
+    ] {
+        assert_eq!(parse_iso8601(s).unwrap().to_string(), expected, "{}", s);
+    }
+    assert_eq!(
+        parse_iso8601("2024-01-05").unwrap().to_string(),
+        "2024-01-05T00:00:00+00:00"
+    );
+    assert_eq!(
+        parse_iso8601("2024-01-05T24:00").unwrap().to_string(),
+        "2024-01-06T00:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_iso8601_errors() {
+    let err = parse_iso8601("2024-01-05t08:30Z").unwrap_err().to_string();
+    assert!(err.contains("lowercase 't'"), "{}", err);
+    let err = parse_iso8601("2024-01-05T08:30z").unwrap_err().to_string();
+    assert!(
+        err.contains("lowercase 'z'") && err.contains("position 16"),
+        "{}",
+        err
+    );
+    let err = parse_iso8601("2024-01-05 08:30").unwrap_err().to_string();
+    assert!(err.contains("space separator"), "{}", err);
+    let err = parse_iso8601("2024-W54-1").unwrap_err().to_string();
+    assert!(err.contains("week date out of range"), "{}", err);
+}
+
+#[test]
+fn test_from_format_dayjs() {
+    let date = from_format_dayjs("2024/01/05 08:30:15.250", "YYYY/MM/DD HH:mm:ss.SSS").unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T08:30:15.250+00:00");
+    let date = from_format_dayjs(
+        "Friday, January 5 2024 12:05 AM -0500",
+        "dddd, MMMM D YYYY hh:mm A ZZ",
+    );
+    let date = date.unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T05:05:00+00:00");
+    assert_eq!(date.tz, DayjsTimeZone::Offset(-5 * 3600));
+    let date = from_format_dayjs("99 7 4 x", "YY M D [x]").unwrap();
+    assert_eq!(date.format("YYYY-MM-DD"), "1999-07-04");
+    let date = from_format_dayjs("2024", "YYYY").unwrap();
+    assert_eq!(date.to_string(), "2024-01-01T00:00:00+00:00");
+    let err = from_format_dayjs("2024-13-01", "YYYY-MM-DD").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid dayjs format \"2024-13-01\": date out of range at position 10"
+    );
+    assert!(from_format_dayjs("13:00 PM", "hh:mm A").is_err());
+    assert!(from_format_dayjs("2024-01-05 extra", "YYYY-MM-DD").is_err());
+    assert!(from_format_dayjs("Janu 5", "MMM D").is_err());
+}
+
+#[test]
+fn test_strict() {
+    let template = "DD.MM.YYYY HH:mm Z";
+    let date = from_format_dayjs_strict("05.01.2024 08:30 +01:00", template).unwrap();
+    assert_eq!(date.to_string(), "2024-01-05T07:30:00+00:00");
+    // accepted leniently, but not formatted back the same way
+    for input in [
+        "5.1.2024 08:30 +01:00",
+        "05-01-2024 08:30 +01:00",
+        "05.01.2024 08:30 +0100",
+    ] {
+        assert!(from_format_dayjs(input, template).is_ok(), "{}", input);
+        let err = from_format_dayjs_strict(input, template).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("does not match \"DD.MM.YYYY HH:mm Z\" exactly"));
+    }
+    assert!(dayjs::from_str_strict("2024-01-05T08:30:00+01:00").is_ok());
+    assert!(dayjs::from_str_strict("2024-01-05T08:30+01:00").is_err());
+}
+
+#[test]
+fn test_validate_iso_vectors() {
+    use dayjs::{validate_iso, IsoKind};
+    let valid = [
+        ("2024", IsoKind::Date),
+        ("2024-01", IsoKind::Date),
+        ("20240105", IsoKind::Date),
+        ("2024-005", IsoKind::Date),
+        ("0830", IsoKind::Date),
+        ("2024W015", IsoKind::Date),
+        ("08:30", IsoKind::Time),
+        ("08:30:00,5+05:30", IsoKind::Time),
+        ("T083000", IsoKind::Time),
+        ("T24:00", IsoKind::Time),
+        ("2024-01-05T08:30:00Z", IsoKind::DateTime),
+        ("20240105T0830-0500", IsoKind::DateTime),
+        ("P1Y2M10DT2H30M", IsoKind::Duration),
+        ("P3W", IsoKind::Duration),
+        ("PT0.5S", IsoKind::Duration),
+        ("P1,5D", IsoKind::Duration),
+        ("2024-01-05/2024-02-05", IsoKind::Interval),
+        ("P1M/2024-02-05T00:00Z", IsoKind::Interval),
+    ];
+    for (s, kind) in valid {
+        assert_eq!(validate_iso(s), Ok(kind), "{}", s);
+    }
+    let invalid = [
+        "",
+        "P",
+        "PT",
+        "P1YT",
+        "P1H",
+        "P1D2Y",
+        "P1.5D2H",
+        "P1W2D",
+        "PT1H1D",
+        "P1DT1.5H30M",
+        "2024-13-01",
+        "08:30pm",
+        "25:00",
+        "2024-01-05 08:30",
+        "P1D/PT1H",
+        "2024/",
+        "a/b/c",
+    ];
+    for s in invalid {
+        assert!(validate_iso(s).is_err(), "{}", s);
+    }
+    let err = validate_iso("P1D2Y").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid ISO 8601 duration \"P1D2Y\": designator out of order at position 4"
+    );
+}
+
+#[test]
+fn test_java_patterns() {
+    let pattern = "uuuu-MM-dd'T'HH:mm:ss.SSSXXX";
+    let date = dayjs::from_format_java("2024-01-05T08:30:15.250Z", pattern).unwrap();
+    assert_eq!(date, dayjs::from_timestamp(1704443415250));
+    assert_eq!(
+        date.format_java(pattern).unwrap(),
+        "2024-01-05T08:30:15.250Z"
+    );
+    let date = dayjs::from_format_java("20240105 083015 -0500", "yyyyMMdd HHmmss Z").unwrap();
+    assert_eq!(date.format_java("DDD xxx").unwrap(), "005 -05:00");
+    // dayjs Z is never Zulu, at UTC either
+    let utc = dayjs::from_timestamp(1704443415);
+    let dayjs_pattern = "YYYY-MM-DD HH:mm:ssZ";
+    let java = dayjs::convert_format(
+        dayjs_pattern,
+        dayjs::FormatSyntax::Dayjs,
+        dayjs::FormatSyntax::Java,
+    )
+    .unwrap();
+    assert_eq!(java, "yyyy-MM-dd HH:mm:ssxxx");
+    assert_eq!(utc.format_java(&java).unwrap(), utc.format(dayjs_pattern));
+    let err = dayjs::from_format_java("2024", "yyyy G").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid SimpleDateFormat pattern \"yyyy G\": unsupported pattern letters G"
+    );
+}
+
+#[test]
+fn test_go_layouts() {
+    let layout = "2006-01-02T15:04:05.000Z07:00";
+    let date = dayjs::from_format_go("2024-01-05T08:30:15.250Z", layout).unwrap();
+    assert_eq!(date, dayjs::from_timestamp(1704443415250));
+    assert_eq!(date.format_go(layout).unwrap(), "2024-01-05T08:30:15.250Z");
+    let date = dayjs::from_format_go(
+        "Fri, 05 Jan 24 3:04PM -05:00",
+        "Mon, 02 Jan 06 3:04PM -07:00",
+    );
+    assert_eq!(date.unwrap().to_string(), "2024-01-05T20:04:00+00:00");
+    let go = dayjs::convert_format(
+        "DD/MM/YYYY HH:mm",
+        dayjs::FormatSyntax::Dayjs,
+        dayjs::FormatSyntax::Go,
+    );
+    assert_eq!(go.unwrap(), "02/01/2006 15:04");
+    // "day 1" would read as a month
+    let go = dayjs::convert_format(
+        "[day 1] D",
+        dayjs::FormatSyntax::Dayjs,
+        dayjs::FormatSyntax::Go,
+    );
+    assert!(go.is_err());
+    assert!(dayjs::from_format_go("Jan  5", "Jan _2").is_err());
+}
@@ -0,0 +1,40 @@
+use chrono::Weekday;
+use dayjs::quiet::QuietHours;
+use dayjs::{DayjsTime, DayjsTimeZone};
+
+fn time(s: &str) -> DayjsTime {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_quiet_hours() {
+    let quiet = QuietHours::new(DayjsTimeZone::Offset(-5 * 3600))
+        .window(Weekday::Fri, time("22:00"), time("08:00"))
+        .window(Weekday::Sat, time("00:00"), time("12:00"))
+        .window(Weekday::Sun, time("09:00"), time("09:00"));
+    // friday 2024-01-05T23:30 local
+    let late = dayjs::from_timestamp(1704515400);
+    assert!(quiet.is_quiet(&late));
+    let next = quiet.next_allowed_send(&late).unwrap();
+    assert_eq!(next.to_string(), "2024-01-06T17:00:00+00:00");
+    assert_eq!(next.tz, DayjsTimeZone::DEFAULT);
+    // sunday 09:30 local, the empty window is ignored
+    let sunday = dayjs::from_timestamp(1704637800);
+    assert!(!quiet.is_quiet(&sunday));
+    assert_eq!(quiet.next_allowed_send(&sunday), Some(sunday));
+    let always = QuietHours::new(DayjsTimeZone::DEFAULT)
+        .every_day(time("00:00"), time("12:00"))
+        .every_day(time("12:00"), time("00:00"));
+    assert_eq!(always.next_allowed_send(&sunday), None);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_quiet_hours_dst() {
+    let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+    let quiet = QuietHours::new(new_york).window(Weekday::Sat, time("22:00"), time("02:30"));
+    // 2024-03-10T01:00 local, 02:30 is skipped by the change to daylight saving time
+    let date = dayjs::from_timestamp(1710050400);
+    let next = quiet.next_allowed_send(&date).unwrap();
+    assert_eq!(next.tz(new_york).format("HH:mm Z"), "03:30 -04:00");
+}
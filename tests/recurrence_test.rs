@@ -0,0 +1,85 @@
+use chrono::Weekday;
+use dayjs::recurrence::{Frequency, Recurrence};
+
+fn dates(rule: &Recurrence) -> Vec<String> {
+    rule.iter().map(|d| d.format("YYYY-MM-DD HH:mm")).collect()
+}
+
+#[test]
+fn test_recurrence() {
+    let start = dayjs::from_str("2024-01-31T10:00:00Z");
+    let last_friday = Recurrence::new(Frequency::Monthly, &start)
+        .on_nth(-1, Weekday::Fri)
+        .count(2);
+    assert_eq!(
+        dates(&last_friday),
+        ["2024-02-23 10:00", "2024-03-29 10:00"]
+    );
+    // months without the 31st are skipped
+    let monthly = Recurrence::new(Frequency::Monthly, &start).count(3);
+    assert_eq!(
+        dates(&monthly),
+        ["2024-01-31 10:00", "2024-03-31 10:00", "2024-05-31 10:00"]
+    );
+    let weekly = Recurrence::new(Frequency::Weekly, &start)
+        .interval(2)
+        .on(Weekday::Mon)
+        .on(Weekday::Wed)
+        .until(&dayjs::from_str("2024-02-12T10:00:00Z"));
+    assert_eq!(dates(&weekly), ["2024-01-31 10:00", "2024-02-12 10:00"]);
+    let leap = Recurrence::new(Frequency::Yearly, &start)
+        .in_month(2)
+        .on_month_day(29);
+    let next = leap.next_after(&dayjs::from_str("2024-03-01T00:00:00Z"));
+    assert_eq!(next.unwrap().format("YYYY-MM-DD"), "2028-02-29");
+    let never = Recurrence::new(Frequency::Monthly, &start)
+        .in_month(2)
+        .on_month_day(30);
+    assert_eq!(never.iter().next(), None);
+}
+
+#[test]
+fn test_from_rrule() {
+    let start = dayjs::from_str("2024-01-01T09:00:00Z");
+    let rule = Recurrence::from_rrule("RRULE:FREQ=DAILY;BYDAY=SA,SU;UNTIL=20240114", &start);
+    assert_eq!(rule.unwrap().iter().count(), 4);
+    let rule = Recurrence::from_rrule("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;COUNT=2", &start);
+    assert_eq!(
+        dates(&rule.unwrap()),
+        ["2024-11-28 09:00", "2025-11-27 09:00"]
+    );
+    let rule = Recurrence::from_rrule("FREQ=MONTHLY;BYMONTHDAY=-1", &start).unwrap();
+    let from = dayjs::from_str("2024-02-01T00:00:00Z");
+    let to = dayjs::from_str("2024-05-01T00:00:00Z");
+    let ends: Vec<_> = rule
+        .between(&from, &to)
+        .map(|d| d.format("MM-DD"))
+        .collect();
+    assert_eq!(ends, ["02-29", "03-31", "04-30"]);
+    let err = Recurrence::from_rrule("FREQ=MONTHLY;BYMONTH=13", &start).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid RRULE \"FREQ=MONTHLY;BYMONTH=13\": 13 is not between 1 and 12"
+    );
+    assert!(Recurrence::from_rrule("BYDAY=MO", &start).is_err());
+    assert!(Recurrence::from_rrule("FREQ=HOURLY", &start).is_err());
+}
+
+#[test]
+fn test_recurrence_out_of_range() {
+    let start = dayjs::from_str("2024-01-01T09:00:00Z");
+    for freq in ["DAILY", "WEEKLY", "MONTHLY", "YEARLY"] {
+        let rule = format!("FREQ={};INTERVAL=4294967295", freq);
+        let rule = Recurrence::from_rrule(&rule, &start).unwrap();
+        let years: Vec<i32> = rule.iter().map(|d| d.year()).collect();
+        assert_eq!(years[0], 2024, "{}", freq);
+        assert!(
+            years.windows(2).all(|w| w[0] < w[1]),
+            "{}: {:?}",
+            freq,
+            years
+        );
+    }
+    let yearly = Recurrence::from_rrule("FREQ=YEARLY;INTERVAL=100000", &start).unwrap();
+    assert_eq!(yearly.iter().last().map(|d| d.year()), Some(202024));
+}
@@ -0,0 +1,67 @@
+use dayjs::{locale, Unit};
+
+#[test]
+fn test_from_now() {
+    let now = dayjs::dayjs();
+    assert_eq!(
+        now.subtract(30, Unit::Second).from_now(),
+        "a few seconds ago"
+    );
+    assert_eq!(
+        now.add(3, Unit::Hour).add(1, Unit::Minute).from_now(),
+        "in 3 hours"
+    );
+    assert_eq!(now.subtract(3, Unit::Hour).to_now(), "in 3 hours");
+}
+
+#[test]
+fn test_from_thresholds() {
+    let base = dayjs::from_timestamp(1704443400);
+    let cases = [
+        (44, Unit::Second, "in a few seconds"),
+        (45, Unit::Second, "in a minute"),
+        (89, Unit::Second, "in a minute"),
+        (90, Unit::Second, "in 2 minutes"),
+        (45, Unit::Minute, "in an hour"),
+        (22, Unit::Hour, "in a day"),
+        (26, Unit::Day, "in a month"),
+        (50, Unit::Day, "in 2 months"),
+        (11, Unit::Month, "in a year"),
+        (18, Unit::Month, "in 2 years"),
+        (-5, Unit::Year, "5 years ago"),
+    ];
+    for (value, unit, expected) in cases {
+        assert_eq!(
+            base.add(value, unit).from(&base),
+            expected,
+            "{} {:?}",
+            value,
+            unit
+        );
+    }
+}
+
+#[test]
+fn test_relative_to_locale() {
+    let a = dayjs::from_timestamp(1704443400);
+    let b = a.add(5, Unit::Hour);
+    assert_eq!(a.relative_to(&b, true, &locale::RU), "5 часов назад");
+    assert_eq!(b.relative_to(&a, true, &locale::PL), "za 5 godzin");
+    assert_eq!(a.relative_to(&b, false, &locale::UK), "5 годин");
+}
+
+#[test]
+fn test_relative_to_declined_units() {
+    let a = dayjs::from_timestamp(1704443400);
+    let minute = a.add(1, Unit::Minute);
+    assert_eq!(minute.relative_to(&a, true, &locale::RU), "через минуту");
+    assert_eq!(minute.relative_to(&a, false, &locale::RU), "минута");
+    assert_eq!(a.relative_to(&minute, true, &locale::PL), "minutę temu");
+    let later = a.add(21, Unit::Minute);
+    assert_eq!(a.relative_to(&later, true, &locale::UK), "21 хвилину тому");
+    assert_eq!(later.relative_to(&a, true, &locale::RU), "через 21 минуту");
+    assert_eq!(a.relative_to(&later, false, &locale::UK), "21 хвилина");
+    let hour = a.add(1, Unit::Hour);
+    assert_eq!(hour.relative_to(&a, true, &locale::UK), "за годину");
+    assert_eq!(hour.relative_to(&a, true, &locale::PL), "za godzinę");
+}
@@ -0,0 +1,48 @@
+use dayjs::rss::from_rss_date;
+use dayjs::DayjsTimeZone;
+
+#[test]
+fn test_to_rss_date() {
+    let mut date = dayjs::from_timestamp(1704443400);
+    date.tz = DayjsTimeZone::Offset(-5 * 3600);
+    assert_eq!(date.to_rss_date(), "Fri, 05 Jan 2024 03:30:00 -0500");
+    assert_eq!(date.to_atom_date(), "2024-01-05T03:30:00-05:00");
+}
+
+#[test]
+fn test_from_rss_date() {
+    let expected = "2024-01-05T08:30:00+00:00";
+    for s in [
+        "Fri, 05 Jan 2024 08:30:00 +0000",
+        "Fri, 5 Jan 2024 08:30 GMT",
+        "5 Jan 24 08:30:00 UT",
+        "Fri,05 Jan 2024 08:30:00.123 Z",
+        "Friday, 05 January 2024 00:30:00 PST",
+        "Fri, 05 Jan 2024 09:30:00 +01:00 (CET)",
+        "Jan 05 2024 08:30:00",
+        "  fri, 05 jan 2024 8:30:00 gmt  ",
+        "2024-01-05T08:30:00Z",
+    ] {
+        assert_eq!(from_rss_date(s).unwrap().to_string(), expected, "{}", s);
+    }
+    let date = from_rss_date("Fri, 05 Jan 2024 03:30:00 EST").unwrap();
+    assert_eq!(date.tz, DayjsTimeZone::Offset(-5 * 3600));
+    let old = from_rss_date("Tue, 05 Jan 99 08:30:00 -0000").unwrap();
+    assert_eq!(old.to_string(), "1999-01-05T08:30:00+00:00");
+    assert_eq!(old.tz, DayjsTimeZone::DEFAULT);
+}
+
+#[test]
+fn test_from_rss_date_errors() {
+    let err = from_rss_date("Fri, 05 Foo 2024 08:30:00 GMT")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("expected a month name, found \"Foo\""),
+        "{}",
+        err
+    );
+    assert!(from_rss_date("Fri, 31 Feb 2024 08:30:00 GMT").is_err());
+    assert!(from_rss_date("Fri, 05 Jan 2024 08:30:00 XYZ").is_err());
+    assert!(from_rss_date("").is_err());
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "tz")]
+
+use dayjs::{Dayjs, DayjsTimeZone, Unit};
+
+/// timezone scenario: a wall clock in a zone, then chained expectations on what the API makes
+/// of it, each rendered as `YYYY-MM-DDTHH:mm:ss.SSSZ` in the zone
+struct Scenario {
+    label: String,
+    date: Dayjs,
+}
+
+fn scenario() -> ScenarioStart {
+    ScenarioStart
+}
+
+struct ScenarioStart;
+
+impl ScenarioStart {
+    /// `wall` like `2025-03-09T01:30` read in the IANA zone `zone`
+    #[track_caller]
+    fn at(self, wall: &str, zone: &str) -> Scenario {
+        let tz = DayjsTimeZone::city(zone).unwrap_or_else(|| panic!("unknown zone {}", zone));
+        let naive = dayjs::parse_iso8601(&format!("{}Z", wall))
+            .unwrap_or_else(|e| panic!("bad wall clock {}: {}", wall, e));
+        // the offset of the guess settles within two rounds, away from a transition
+        let mut date = naive.tz(tz);
+        for _ in 0..2 {
+            date = naive
+                .subtract(date.utc_offset().into(), Unit::Minute)
+                .tz(tz);
+        }
+        Scenario {
+            label: format!("{} in {}", wall, zone),
+            date,
+        }
+    }
+}
+
+impl Scenario {
+    #[track_caller]
+    fn check(&self, what: &str, actual: Dayjs, expected: &str) -> &Self {
+        let actual = actual.format("YYYY-MM-DDTHH:mm:ss.SSSZ");
+        assert_eq!(actual, expected, "{} of {}", what, self.label);
+        self
+    }
+
+    #[track_caller]
+    fn expect_local(&self, expected: &str) -> &Self {
+        self.check("wall clock", self.date, expected)
+    }
+
+    #[track_caller]
+    fn expect_start_of(&self, unit: Unit, expected: &str) -> &Self {
+        let what = format!("start of {:?}", unit);
+        self.check(&what, self.date.start_of_unit(unit), expected)
+    }
+
+    #[track_caller]
+    fn expect_end_of(&self, unit: Unit, expected: &str) -> &Self {
+        let what = format!("end of {:?}", unit);
+        self.check(&what, self.date.end_of_unit(unit), expected)
+    }
+
+    #[track_caller]
+    fn expect_start_of_day(&self, expected: &str) -> &Self {
+        self.expect_start_of(Unit::Day, expected)
+    }
+
+    #[track_caller]
+    fn expect_end_of_day(&self, expected: &str) -> &Self {
+        self.expect_end_of(Unit::Day, expected)
+    }
+
+    #[track_caller]
+    fn expect_add(&self, n: i64, unit: Unit, expected: &str) -> &Self {
+        let what = format!("adding {} {:?}", n, unit);
+        self.check(&what, self.date.add(n, unit), expected)
+    }
+
+    #[track_caller]
+    fn expect_add_days(&self, n: i64, expected: &str, hours: i64) -> &Self {
+        let what = format!("adding {} days", n);
+        let moved = self.date.add_days(n);
+        assert_eq!(
+            (moved - self.date).num_hours(),
+            hours,
+            "hours of {} to {}",
+            what,
+            self.label
+        );
+        self.check(&what, moved, expected)
+    }
+
+    #[track_caller]
+    fn expect_set(&self, unit: Unit, value: i64, expected: &str) -> &Self {
+        let what = format!("setting {:?} to {}", unit, value);
+        let set = self
+            .date
+            .set(unit, value)
+            .unwrap_or_else(|e| panic!("{}: {}", what, e));
+        self.check(&what, set, expected)
+    }
+
+    #[track_caller]
+    fn expect_offset(&self, minutes: i32) -> &Self {
+        assert_eq!(self.date.utc_offset(), minutes, "offset of {}", self.label);
+        self
+    }
+}
+
+#[test]
+fn test_new_york_spring_forward() {
+    scenario()
+        .at("2025-03-09T01:30", "America/New_York")
+        .expect_offset(-300)
+        .expect_start_of_day("2025-03-09T00:00:00.000-05:00")
+        .expect_end_of_day("2025-03-09T23:59:59.999-04:00")
+        .expect_add(1, Unit::Hour, "2025-03-09T03:30:00.000-04:00")
+        .expect_add(1, Unit::Day, "2025-03-10T01:30:00.000-04:00")
+        .expect_set(Unit::Hour, 2, "2025-03-09T03:30:00.000-04:00")
+        .expect_end_of(Unit::Month, "2025-03-31T23:59:59.999-04:00");
+}
+
+#[test]
+fn test_new_york_fall_back() {
+    scenario()
+        .at("2025-11-02T12:00", "America/New_York")
+        .expect_local("2025-11-02T12:00:00.000-05:00")
+        .expect_start_of_day("2025-11-02T00:00:00.000-04:00")
+        .expect_end_of_day("2025-11-02T23:59:59.999-05:00")
+        .expect_add(-12, Unit::Hour, "2025-11-02T01:00:00.000-04:00")
+        .expect_add(-1, Unit::Day, "2025-11-01T12:00:00.000-04:00")
+        .expect_start_of(Unit::Isoweek, "2025-10-27T00:00:00.000-04:00");
+}
+
+#[test]
+fn test_days_keep_the_wall_clock() {
+    scenario()
+        .at("2025-03-08T09:15", "America/New_York")
+        .expect_add_days(1, "2025-03-09T09:15:00.000-04:00", 23)
+        .expect_add_days(7, "2025-03-15T09:15:00.000-04:00", 7 * 24 - 1);
+    scenario()
+        .at("2025-10-26T00:30", "Europe/Berlin")
+        .expect_add_days(1, "2025-10-27T00:30:00.000+01:00", 25)
+        .expect_add_days(-1, "2025-10-25T00:30:00.000+02:00", -24)
+        .expect_start_of_day("2025-10-26T00:00:00.000+02:00")
+        .expect_end_of_day("2025-10-26T23:59:59.999+01:00");
+}
+
+#[test]
+fn test_southern_and_offbeat_zones() {
+    // Sydney leaves daylight saving on 2025-04-06 at 03:00
+    scenario()
+        .at("2025-04-06T12:00", "Australia/Sydney")
+        .expect_offset(600)
+        .expect_start_of_day("2025-04-06T00:00:00.000+11:00")
+        .expect_end_of(Unit::Week, "2025-04-12T23:59:59.999+10:00");
+    // no DST, a month end at +05:45
+    scenario()
+        .at("2024-02-29T23:30", "Asia/Kathmandu")
+        .expect_end_of_day("2024-02-29T23:59:59.999+05:45")
+        .expect_add(1, Unit::Year, "2025-02-28T23:30:00.000+05:45")
+        .expect_start_of(Unit::Quarter, "2024-01-01T00:00:00.000+05:45");
+}
@@ -0,0 +1,127 @@
+use chrono::Duration;
+use dayjs::series::{find_duplicates_by_unit, find_gaps, is_sorted_ascending};
+use dayjs::{DayjsTimeZone, Unit};
+
+#[test]
+fn test_is_sorted_ascending() {
+    let start = dayjs::from_timestamp(1704443400);
+    assert!(is_sorted_ascending(&[]));
+    assert!(is_sorted_ascending(&[start]));
+    let points: Vec<_> = (0..5).map(|i| start.add(i, Unit::Second)).collect();
+    assert!(is_sorted_ascending(&points));
+    let mut shuffled = points.clone();
+    shuffled.swap(3, 4);
+    assert!(!is_sorted_ascending(&shuffled));
+}
+
+#[test]
+fn test_find_gaps() {
+    let start = dayjs::from_timestamp(1704443400);
+    let offsets = [0, 10, 20, 50, 60, 61, 100];
+    let points: Vec<_> = offsets
+        .iter()
+        .map(|s| start.add(*s, Unit::Second))
+        .collect();
+    let gaps = find_gaps(&points, Duration::seconds(10));
+    let found: Vec<_> = gaps.iter().map(|g| (g.index, g.duration())).collect();
+    assert_eq!(
+        found,
+        vec![(2, Duration::seconds(30)), (5, Duration::seconds(39))]
+    );
+    assert_eq!(gaps[0].start, points[2]);
+    assert_eq!(gaps[0].end, points[3]);
+    assert!(find_gaps(&points, Duration::minutes(1)).is_empty());
+}
+
+#[test]
+fn test_find_duplicates_by_unit() {
+    // 23:30 and 00:10 UTC, the same day in +05:30
+    let late = dayjs::from_timestamp(1704497400);
+    let early = late.add(40, Unit::Minute);
+    assert!(find_duplicates_by_unit(&[late, early], Unit::Day).is_empty());
+    let ist = DayjsTimeZone::Offset(5 * 3600 + 1800);
+    let (mut a, mut b) = (late, early);
+    a.tz = ist;
+    b.tz = ist;
+    let groups = find_duplicates_by_unit(&[a, b, a], Unit::Day);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(
+        groups[0].0.format("YYYY-MM-DD HH:mm Z"),
+        "2024-01-06 00:00 +05:30"
+    );
+    assert_eq!(groups[0].1, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_resample() {
+    use dayjs::series::{resample, FillPolicy};
+    let start = dayjs::from_timestamp(1704443400);
+    let points = [
+        (start.add(60, Unit::Second), 12.0),
+        (start, 2.0),
+        (start.add(20, Unit::Second), 4.0),
+        (start.add(30, Unit::Second), 5.0),
+        (start.add(30, Unit::Second), 6.0),
+    ];
+    let values = |fill| {
+        resample(&points, Duration::seconds(20), fill)
+            .iter()
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(FillPolicy::ForwardFill), [2.0, 4.0, 6.0, 12.0]);
+    // 08:30:30 falls in the step of 08:30:20
+    assert_eq!(values(FillPolicy::Zero), [2.0, 6.0, 0.0, 12.0]);
+    assert_eq!(values(FillPolicy::Interpolate), [2.0, 4.0, 8.0, 12.0]);
+    let grid = resample(&points, Duration::seconds(20), FillPolicy::Zero);
+    assert_eq!(grid[3].0.format("HH:mm:ss"), "08:31:00");
+    assert!(resample(&[], Duration::seconds(1), FillPolicy::Zero).is_empty());
+    assert!(resample(&points, Duration::zero(), FillPolicy::Zero).is_empty());
+}
+
+#[test]
+fn test_align_to() {
+    let date = dayjs::from_timestamp(1704443415123);
+    let minute = date.align_to(Duration::minutes(1), Duration::zero());
+    assert_eq!(minute.to_string(), "2024-01-05T08:30:00+00:00");
+    assert!(minute.is_aligned(Duration::minutes(1)));
+    assert!(!date.is_aligned(Duration::minutes(1)));
+    assert!(date.is_aligned(Duration::milliseconds(1)));
+    let quarter_hour = date.align_to(Duration::minutes(15), Duration::minutes(5));
+    assert_eq!(quarter_hour.to_string(), "2024-01-05T08:20:00+00:00");
+    assert_eq!(date.align_to(Duration::zero(), Duration::zero()), date);
+    assert!(!date.is_aligned(Duration::zero()));
+    // before the epoch, boundaries continue backwards
+    let old = dayjs::from_epoch(-1, dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    let hour = old.align_to(Duration::hours(1), Duration::zero());
+    assert_eq!(hour.to_string(), "1969-12-31T23:00:00+00:00");
+    // boundaries before the supported range clamp to its first instant
+    let first = dayjs::from_epoch(i64::MIN, dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    let min = chrono::DateTime::<chrono::Utc>::MIN_UTC.timestamp();
+    let epoch = |d: dayjs::Dayjs| d.to_epoch(dayjs::Epoch::Unix, dayjs::Precision::Seconds);
+    assert_eq!(
+        epoch(first.align_to(Duration::days(7), Duration::zero())),
+        min
+    );
+    assert_eq!(epoch(date.align_to(Duration::MAX, Duration::zero())), 0);
+    let offset = Duration::seconds(min.abs() + 86400);
+    assert_eq!(epoch(date.align_to(Duration::MAX, offset)), min);
+}
+
+#[test]
+fn test_rates() {
+    use dayjs::series::{events_per, median_interval};
+    let start = dayjs::from_timestamp(1704443400);
+    let points: Vec<_> = [30, 0, 15, 60, 45]
+        .iter()
+        .map(|m| start.add(*m, Unit::Minute))
+        .collect();
+    assert_eq!(events_per(Unit::Hour, &points), Some(4.0));
+    assert_eq!(events_per(Unit::Day, &points), Some(96.0));
+    assert_eq!(events_per(Unit::Hour, &[start]), None);
+    assert_eq!(events_per(Unit::Hour, &[start, start]), None);
+    assert_eq!(median_interval(&points), Duration::minutes(15));
+    let uneven = [0, 10, 30, 70].map(|s| start.add(s, Unit::Second));
+    assert_eq!(median_interval(&uneven), Duration::seconds(20));
+    assert_eq!(median_interval(&[start]), Duration::zero());
+}
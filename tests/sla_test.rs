@@ -0,0 +1,62 @@
+use chrono::Duration;
+use dayjs::sla::{downtime, error_budget, error_budget_remaining, uptime_ratio};
+use dayjs::{Interval, Unit};
+
+fn hours(start: &dayjs::Dayjs, from: i64, to: i64) -> Interval {
+    Interval::new(start.add(from, Unit::Hour), start.add(to, Unit::Hour))
+}
+
+#[test]
+fn test_uptime_ratio() {
+    let start = dayjs::from_timestamp(1704067200);
+    let window = hours(&start, 0, 100);
+    // overlapping incidents, one partly before the window
+    let incidents = [
+        hours(&start, -2, 1),
+        hours(&start, 10, 12),
+        hours(&start, 11, 13),
+    ];
+    assert_eq!(downtime(&incidents, &window, &[]), Duration::hours(4));
+    assert_eq!(uptime_ratio(&incidents, window, &[]), 0.96);
+    // maintenance covers part of an incident and is left out of the window
+    let maintenance = [hours(&start, 12, 22)];
+    assert_eq!(
+        downtime(&incidents, &window, &maintenance),
+        Duration::hours(3)
+    );
+    let ratio = uptime_ratio(&incidents, window, &maintenance);
+    assert!((ratio - (1.0 - 3.0 / 90.0)).abs() < 1e-12, "{}", ratio);
+    assert_eq!(uptime_ratio(&incidents, window, &[window]), 1.0);
+    assert_eq!(uptime_ratio(&[], window, &[]), 1.0);
+}
+
+#[test]
+fn test_error_budget() {
+    let start = dayjs::from_timestamp(1704067200);
+    let window = hours(&start, 0, 1000);
+    assert_eq!(error_budget(0.99, window, &[]), Duration::hours(10));
+    let incidents = [hours(&start, 5, 9)];
+    assert_eq!(
+        error_budget_remaining(0.99, &incidents, window, &[]),
+        Duration::hours(6)
+    );
+    let incidents = [hours(&start, 5, 20)];
+    assert_eq!(
+        error_budget_remaining(0.99, &incidents, window, &[]),
+        Duration::hours(-5)
+    );
+}
+
+#[test]
+fn test_interval() {
+    let start = dayjs::from_timestamp(1704067200);
+    let a = hours(&start, 0, 2);
+    assert!(a.contains(&start));
+    assert!(!a.contains(&a.end));
+    assert!(hours(&start, 2, 1).is_empty());
+    assert_eq!(hours(&start, 2, 1).duration(), Duration::zero());
+    assert_eq!(
+        a.intersection(&hours(&start, 1, 5)),
+        Some(hours(&start, 1, 2))
+    );
+}
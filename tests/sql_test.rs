@@ -0,0 +1,48 @@
+use dayjs::sql::{between_clause, between_params, SqlDialect};
+use dayjs::{DayjsTimeZone, Interval, Unit};
+
+#[test]
+fn test_between_clause() {
+    // 2024-05-09T08:30:15Z, 2024-05-09 in New York at -04:00
+    let date = dayjs::from_timestamp(1715243415).tz(DayjsTimeZone::Offset(-4 * 3600));
+    let day = date.bounds_of(Unit::Day);
+    assert_eq!(
+        between_clause("created_at", day, SqlDialect::MySql),
+        "`created_at` >= '2024-05-09 04:00:00.000000' AND `created_at` < '2024-05-10 04:00:00.000000'"
+    );
+    assert_eq!(
+        between_clause("created_at", day, SqlDialect::Sqlite),
+        "\"created_at\" >= '2024-05-09T04:00:00.000000Z' AND \"created_at\" < '2024-05-10T04:00:00.000000Z'"
+    );
+    assert_eq!(
+        date.sql_between_clause("t.created_at", Unit::Day, SqlDialect::Postgres),
+        between_clause("t.created_at", Interval::from(day), SqlDialect::Postgres)
+    );
+    let (clause, values) = between_params("at", day, SqlDialect::Postgres);
+    assert_eq!(clause, "\"at\" >= $1 AND \"at\" < $2");
+    assert_eq!(
+        values,
+        [
+            "2024-05-09 04:00:00.000000+00".to_string(),
+            "2024-05-10 04:00:00.000000+00".to_string()
+        ]
+    );
+    assert_eq!(
+        between_params("at", day, SqlDialect::MySql).0,
+        "`at` >= ? AND `at` < ?"
+    );
+}
+
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(
+        SqlDialect::Postgres.quote_identifier("odd\"name"),
+        "\"odd\"\"name\""
+    );
+    assert_eq!(SqlDialect::MySql.quote_identifier("a`b.c"), "`a``b`.`c`");
+    let late = dayjs::from_timestamp(1715243415250);
+    assert_eq!(
+        SqlDialect::Sqlite.literal(&late),
+        "'2024-05-09T08:30:15.250000Z'"
+    );
+}
@@ -0,0 +1,26 @@
+use dayjs::{from_filename_stamp, DayjsTimeZone, StampStyle};
+
+#[test]
+fn test_filename_stamp() {
+    let mut date = dayjs::from_timestamp(1742923307);
+    date.tz = DayjsTimeZone::Offset(8 * 3600);
+    let compact = date.to_filename_stamp(StampStyle::Compact);
+    assert_eq!(compact, "20250325T172147Z");
+    let readable = date.to_filename_stamp(StampStyle::Readable);
+    assert_eq!(readable, "2025-03-25_17-21-47");
+    for stamp in [compact, readable] {
+        assert_eq!(
+            from_filename_stamp(&stamp).unwrap().to_string(),
+            "2025-03-25T17:21:47+00:00"
+        );
+    }
+}
+
+#[test]
+fn test_from_filename_stamp_in_name() {
+    let date = from_filename_stamp("db-v2-20250325T172147Z.sql.gz").unwrap();
+    assert_eq!(date.to_string(), "2025-03-25T17:21:47+00:00");
+    assert!(from_filename_stamp("120250325T172147Z").is_err());
+    assert!(from_filename_stamp("20251325T172147Z").is_err());
+    assert!(from_filename_stamp("2025-03-25 17-21-47").is_err());
+}
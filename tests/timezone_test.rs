@@ -0,0 +1,224 @@
+use dayjs::{locale, DayjsTimeZone, NameStyle};
+
+// 2024-01-15T12:00:00Z
+const JANUARY: i64 = 1705320000;
+
+#[test]
+fn test_display_name_fixed() {
+    let at = dayjs::from_timestamp(JANUARY);
+    let utc = DayjsTimeZone::DEFAULT;
+    assert_eq!(
+        utc.display_name_at(&at, &locale::EN, NameStyle::Long),
+        "Coordinated Universal Time"
+    );
+    assert_eq!(
+        utc.display_name_at(&at, &locale::EN, NameStyle::Offset),
+        "GMT"
+    );
+    let offset = DayjsTimeZone::Offset(-3 * 3600 - 1800);
+    assert_eq!(
+        offset.display_name_at(&at, &locale::RU, NameStyle::Long),
+        "GMT-3:30"
+    );
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_display_name_city() {
+    // 2024-07-15T12:00:00Z
+    let july = dayjs::from_timestamp(1721044800);
+    let january = dayjs::from_timestamp(JANUARY);
+    let shanghai = DayjsTimeZone::city("Asia/Shanghai").unwrap();
+    assert_eq!(
+        shanghai.display_name_at(&january, &locale::RU, NameStyle::Long),
+        "Китай, стандартное время"
+    );
+    assert_eq!(
+        shanghai.display_name_at(&january, &locale::EN, NameStyle::Short),
+        "GMT+8"
+    );
+    let la = DayjsTimeZone::city("America/Los_Angeles").unwrap();
+    assert_eq!(
+        la.display_name_at(&january, &locale::EN, NameStyle::Short),
+        "PST"
+    );
+    assert_eq!(
+        la.display_name_at(&july, &locale::EN, NameStyle::Long),
+        "Pacific Daylight Time"
+    );
+    assert_eq!(
+        la.display_name_at(&july, &locale::PL, NameStyle::Offset),
+        "GMT-7"
+    );
+    let kolkata = DayjsTimeZone::city("Asia/Kolkata").unwrap();
+    assert_eq!(
+        kolkata.display_name_at(&july, &locale::EN, NameStyle::Offset),
+        "GMT+5:30"
+    );
+    // the locale data keys its names on "Asia/Calcutta" and "Europe/Kiev"
+    assert_eq!(
+        kolkata.display_name_at(&july, &locale::EN, NameStyle::Long),
+        "India Standard Time"
+    );
+    let kyiv = DayjsTimeZone::city("Europe/Kyiv").unwrap();
+    assert_eq!(
+        kyiv.display_name_at(&july, &locale::EN, NameStyle::Long),
+        "Eastern European Summer Time"
+    );
+    assert_eq!(DayjsTimeZone::city("Mars/Olympus"), None);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_tz_catalog() {
+    let catalog = dayjs::tz_catalog_at(&dayjs::from_timestamp(JANUARY));
+    let names: Vec<&str> = catalog.iter().map(|r| r.name).collect();
+    assert!(names.contains(&"Europe") && names.contains(&"Pacific"));
+    assert!(names.windows(2).all(|w| w[0] < w[1]));
+    let europe = catalog.iter().find(|r| r.name == "Europe").unwrap();
+    assert!(europe.zones.windows(2).all(|w| w[0].offset <= w[1].offset));
+    let berlin = europe
+        .zones
+        .iter()
+        .find(|z| z.name == "Europe/Berlin")
+        .unwrap();
+    assert_eq!(
+        (berlin.city.as_str(), berlin.offset, berlin.dst),
+        ("Berlin", 3600, false)
+    );
+    let america = catalog.iter().find(|r| r.name == "America").unwrap();
+    let buenos_aires = america
+        .zones
+        .iter()
+        .find(|z| z.name == "America/Argentina/Buenos_Aires")
+        .unwrap();
+    assert_eq!(buenos_aires.city, "Buenos Aires");
+
+    let catalog = dayjs::tz_catalog_at(&dayjs::from_timestamp(1721044800));
+    let europe = catalog.iter().find(|r| r.name == "Europe").unwrap();
+    let berlin = europe
+        .zones
+        .iter()
+        .find(|z| z.name == "Europe/Berlin")
+        .unwrap();
+    assert_eq!((berlin.offset, berlin.dst), (7200, true));
+}
+
+#[test]
+fn test_country_zones() {
+    assert_eq!(
+        dayjs::timezones_for_country("CN"),
+        ["Asia/Shanghai", "Asia/Urumqi"]
+    );
+    assert_eq!(dayjs::timezones_for_country("us")[0], "America/New_York");
+    assert!(dayjs::timezones_for_country("").is_empty());
+    assert_eq!(
+        dayjs::country_for_timezone("America/Los_Angeles"),
+        Some("US")
+    );
+    assert_eq!(dayjs::country_for_timezone("Asia/Calcutta"), Some("IN"));
+    assert_eq!(dayjs::country_for_timezone("Europe/Zaporozhye"), Some("UA"));
+    assert_eq!(dayjs::country_for_timezone("Etc/UTC"), None);
+}
+
+#[test]
+fn test_offset_string() {
+    use dayjs::OffsetStyle;
+    let mut date = dayjs::from_timestamp(JANUARY);
+    assert_eq!(date.offset_string(OffsetStyle::Colon), "+00:00");
+    assert_eq!(date.offset_string(OffsetStyle::Utc), "UTC");
+    date.tz = DayjsTimeZone::Offset(-(9 * 3600 + 30 * 60));
+    assert_eq!(date.offset_string(OffsetStyle::Basic), "-0930");
+    assert_eq!(date.offset_string(OffsetStyle::Utc), "UTC-9:30");
+    assert_eq!(date.offset_string(OffsetStyle::Gmt), "GMT-09:30");
+    assert_eq!(date.format_locale("HH:mm Z", &locale::EN), "02:30 -09:30");
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_tz_conversion() {
+    use chrono::Weekday;
+    // 2024-01-05T23:30:00Z, already saturday in Sydney
+    let date = dayjs::from_timestamp(1704497400);
+    let sydney = date.tz(DayjsTimeZone::city("Australia/Sydney").unwrap());
+    assert_eq!(sydney.to_string(), date.to_string());
+    assert_eq!((sydney.date(), sydney.hour()), (6, 10));
+    assert_eq!(sydney.day(), Weekday::Sat);
+    assert_eq!(sydney.format("DD HH:mm Z"), "06 10:30 +11:00");
+    assert_eq!(
+        sydney.start_of("day").to_string(),
+        "2024-01-05T13:00:00+00:00"
+    );
+    let back = sydney.tz(DayjsTimeZone::DEFAULT);
+    assert_eq!(back, date);
+}
+
+#[test]
+fn test_utc_offset() {
+    let date = dayjs::from_timestamp(JANUARY);
+    assert_eq!(date.utc_offset(), 0);
+    let india = date.set_utc_offset(330, false);
+    assert_eq!(india.utc_offset(), 330);
+    assert_eq!(india.tz, DayjsTimeZone::Offset(19800));
+    assert_eq!(india.format("HH:mm"), "17:30");
+    let kept = india.set_utc_offset(-300, true);
+    assert_eq!(kept.format("HH:mm Z"), "17:30 -05:00");
+    assert_eq!(kept.to_string(), "2024-01-15T22:30:00+00:00");
+    assert_eq!(date.set_utc_offset(5000, false).utc_offset(), 1439);
+    #[cfg(feature = "tz")]
+    {
+        let new_york = date.tz(DayjsTimeZone::city("America/New_York").unwrap());
+        assert_eq!(new_york.utc_offset(), -300);
+    }
+}
+
+#[test]
+fn test_utc_local_mode() {
+    use chrono::{Offset, TimeZone};
+    let date = dayjs::from_timestamp(JANUARY);
+    let local = date.local();
+    assert!(!local.is_utc());
+    assert_eq!(local.to_string(), date.to_string());
+    let expected = chrono::Local.timestamp_opt(JANUARY, 0).unwrap();
+    assert_eq!(
+        local.utc_offset() * 60,
+        expected.offset().fix().local_minus_utc()
+    );
+    assert_eq!(local.hour(), chrono::Timelike::hour(&expected));
+    assert_eq!(local.utc(), date);
+    assert!(dayjs::utc().is_utc());
+}
+
+#[test]
+fn test_is_dst() {
+    // 2024-03-31T00:30:00Z, half an hour before Europe leaves winter time
+    let date = dayjs::from_timestamp(1711845000);
+    assert_eq!(date.is_dst(), None);
+    assert_eq!(date.local().is_dst(), None);
+    assert_eq!(date.tz(DayjsTimeZone::Offset(3600)).is_dst(), None);
+    #[cfg(feature = "tz")]
+    {
+        let berlin = date.tz(DayjsTimeZone::city("Europe/Berlin").unwrap());
+        assert_eq!(berlin.is_dst(), Some(false));
+        assert_eq!(berlin.add(1, dayjs::Unit::Hour).is_dst(), Some(true));
+        let tokyo = date.tz(DayjsTimeZone::city("Asia/Tokyo").unwrap());
+        assert_eq!(tokyo.is_dst(), Some(false));
+        // Sydney is in summer time in march
+        let sydney = date.tz(DayjsTimeZone::city("Australia/Sydney").unwrap());
+        assert_eq!(sydney.is_dst(), Some(true));
+    }
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_available_zones() {
+    let zones: Vec<&str> = DayjsTimeZone::available_zones().collect();
+    assert!(zones.len() > 400);
+    assert!(zones.contains(&"America/New_York") && zones.contains(&"UTC"));
+    assert!(zones.iter().all(|name| DayjsTimeZone::is_valid_city(name)));
+    for zone in dayjs::timezones_for_country("CN") {
+        assert!(DayjsTimeZone::is_valid_city(zone), "{}", zone);
+    }
+    assert!(!DayjsTimeZone::is_valid_city(""));
+    assert!(!DayjsTimeZone::is_valid_city("+08:00"));
+}
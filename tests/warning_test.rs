@@ -0,0 +1,51 @@
+use dayjs::{DayjsTimeZone, Warning};
+use std::sync::Mutex;
+
+static SEEN: Mutex<Vec<Warning>> = Mutex::new(Vec::new());
+
+#[test]
+fn test_warning_handler() {
+    dayjs::set_warning_handler(|w| SEEN.lock().unwrap().push(w.clone()));
+    let date = dayjs::from_timestamp(1704443415);
+    assert_eq!(date.end_of("fortnight"), date);
+    let bad = date.tz(DayjsTimeZone::Offset(86_400));
+    assert_eq!(bad.format("HH:mm Z"), "08:30 +00:00");
+    #[cfg(feature = "tz")]
+    {
+        // 2024-03-10T02:30 does not exist in New York
+        let new_york = DayjsTimeZone::city("America/New_York").unwrap();
+        let before = dayjs::from_timestamp(1710052200).tz(new_york);
+        let moved = before.set(dayjs::Unit::Hour, 2).unwrap();
+        assert_eq!(moved.format("HH:mm Z"), "03:30 -04:00");
+    }
+    dayjs::clear_warning_handler();
+    assert_eq!(date.start_of("fortnight"), date);
+    let seen = std::mem::take(&mut *SEEN.lock().unwrap());
+    assert_eq!(seen[0], Warning::UnknownUnit("fortnight".to_string()));
+    assert_eq!(seen[0].to_string(), "unknown unit \"fortnight\" is ignored");
+    assert!(seen.contains(&Warning::InvalidOffset(86_400)));
+    #[cfg(feature = "tz")]
+    {
+        let skipped = seen.iter().find_map(|w| match w {
+            Warning::SkippedLocalTime(local) => Some(local.to_string()),
+            _ => None,
+        });
+        assert_eq!(skipped.as_deref(), Some("2024-03-10 02:30:00"));
+    }
+    assert_eq!(
+        seen.iter()
+            .filter(|w| matches!(w, Warning::UnknownUnit(_)))
+            .count(),
+        1
+    );
+    // a handler that warns again and then clears itself, once
+    dayjs::set_warning_handler(move |w| {
+        SEEN.lock().unwrap().push(w.clone());
+        dayjs::clear_warning_handler();
+        assert_eq!(date.start_of("lustrum"), date);
+    });
+    assert_eq!(date.start_of("decade"), date);
+    assert_eq!(date.start_of("century"), date);
+    let seen = std::mem::take(&mut *SEEN.lock().unwrap());
+    assert_eq!(seen, [Warning::UnknownUnit("decade".to_string())]);
+}
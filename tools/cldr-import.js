@@ -0,0 +1,362 @@
+#!/usr/bin/env node
+// generate src/locale/generated.rs from locale data in the CLDR JSON layout
+//
+// usage: node tools/cldr-import.js <data-dir> [locale...]
+//
+// <data-dir> is tools/locale-data, a hand maintained subset of the files of
+// https://github.com/unicode-org/cldr-json, only `cldr-core`, `cldr-dates-full`,
+// `cldr-units-full` and `cldr-bcp47` are read. its metazone ids are its own, e.g.
+// `Central_European_Standard` where CLDR has `Europe_Central`, so the tables are not CLDR's. relative time phrases, calendar templates and
+// localized formats use dayjs style templates and, like ordinal suffixes, CLDR
+// does not provide them, they come from
+// tools/locales/<locale>.json, which also lists the locales to generate when none are given.
+// `weekStart` is kept there too, 0 for sunday like dayjs, as it is territory data in CLDR,
+// and so is `yearStart`, the day of january that is always in the first week.
+// `monthsFormatBeforeDay` marks a language that declines a month before a day number too,
+// like the dayjs lt locale.
+'use strict'
+
+const fs = require('fs')
+const path = require('path')
+const { execFileSync } = require('child_process')
+
+const supplementDir = path.join(__dirname, 'locales')
+const output = path.join(__dirname, '..', 'src', 'locale', 'generated.rs')
+
+const CATEGORIES = ['zero', 'one', 'two', 'few', 'many']
+const WEEKDAYS = ['sun', 'mon', 'tue', 'wed', 'thu', 'fri', 'sat']
+const DURATION_UNITS = ['day', 'hour', 'minute', 'second']
+const PLURAL_FIELDS = ['minutes', 'hours', 'days', 'months', 'years']
+const FORMAT_FIELDS = ['LT', 'LTS', 'L', 'LL', 'LLL', 'LLLL']
+const CALENDAR_FIELDS = ['sameDay', 'nextDay', 'nextWeek', 'lastDay', 'lastWeek', 'sameElse']
+const RELATIVE_FIELDS = [
+  'second', 'minute', 'minutes', 'hour', 'hours', 'day', 'days', 'month', 'months', 'year', 'years',
+]
+
+function readJson(file) {
+  return JSON.parse(fs.readFileSync(file, 'utf8'))
+}
+
+function rustStr(s) {
+  return JSON.stringify(s).replace(/\\u([0-9a-fA-F]{4})/g, '\\u{$1}')
+}
+
+function rustArray(values) {
+  return `[${values.map(rustStr).join(', ')}]`
+}
+
+function capitalize(s) {
+  return s[0].toUpperCase() + s.slice(1)
+}
+
+// translate one CLDR relation for integer input, where i = n and v, w, f, t, c, e are 0
+function relation(text) {
+  const m = text.trim().match(/^([nivwftce])\s*(?:%\s*(\d+))?\s*(!?=)\s*([\d.,\s]+)$/)
+  if (!m) throw new Error(`unsupported plural relation: ${text}`)
+  const [, operand, mod, op, list] = m
+  const items = list.split(',').map((item) => item.trim())
+  if (!'ni'.includes(operand)) {
+    const zero = items.some((item) => {
+      const [from, to = from] = item.split('..').map(Number)
+      return from <= 0 && 0 <= to
+    })
+    return (op === '=') === zero
+  }
+  if (mod && items.length === 1 && items[0] === '0') {
+    return `${op === '=' ? '' : '!'}n.is_multiple_of(${mod})`
+  }
+  const expr = mod ? `n % ${mod}` : 'n'
+  if (items.length === 1 && !items[0].includes('..')) {
+    return `${expr} ${op === '=' ? '==' : '!='} ${items[0]}`
+  }
+  const test = `matches!(${expr}, ${items.map((item) => item.replace('..', '..=')).join(' | ')})`
+  return op === '=' ? test : `!${test}`
+}
+
+// translate a CLDR plural rule into a rust condition, null when it never matches integers
+function condition(rule) {
+  const branches = []
+  for (const conjunction of rule.split(/\s+or\s+/)) {
+    const parts = []
+    let possible = true
+    for (const text of conjunction.split(/\s+and\s+/)) {
+      const part = relation(text)
+      if (part === false) possible = false
+      else if (part !== true) parts.push(part)
+    }
+    if (!possible) continue
+    if (!parts.length) return 'true'
+    branches.push(parts.join(' && '))
+  }
+  if (!branches.length) return null
+  if (branches.length === 1) return branches[0]
+  return branches.map((b) => (b.includes('&&') ? `(${b})` : b)).join(' || ')
+}
+
+const LDML_FIELDS = {
+  y: (n) => (n === 2 ? 'YY' : 'YYYY'),
+  M: (n) => ['M', 'MM', 'MMM', 'MMMM'][Math.min(n, 4) - 1],
+  L: (n) => ['M', 'MM', 'MMM', 'MMMM'][Math.min(n, 4) - 1],
+  d: (n) => (n === 1 ? 'D' : 'DD'),
+  E: (n) => (n === 4 ? 'dddd' : 'ddd'),
+  c: (n) => (n === 4 ? 'dddd' : 'ddd'),
+  H: (n) => (n === 1 ? 'H' : 'HH'),
+  h: (n) => (n === 1 ? 'h' : 'hh'),
+  m: (n) => (n === 1 ? 'm' : 'mm'),
+  s: (n) => (n === 1 ? 's' : 'ss'),
+  a: () => 'A',
+  z: (n) => (n === 4 ? 'zzz' : 'z'),
+  v: (n) => (n === 4 ? 'zzz' : 'z'),
+  O: () => 'Z',
+}
+
+// convert a CLDR (LDML) date pattern into a dayjs style template, quoted text becomes [literal]
+function ldmlToDayjs(pattern) {
+  let out = ''
+  let i = 0
+  while (i < pattern.length) {
+    const c = pattern[i]
+    if (c === "'") {
+      const end = pattern.indexOf("'", i + 1)
+      const text = pattern.slice(i + 1, end === -1 ? pattern.length : end)
+      out += text === '' ? "'" : `[${text}]`
+      i = end === -1 ? pattern.length : end + 1
+    } else if (/[A-Za-z]/.test(c)) {
+      let n = 1
+      while (pattern[i + n] === c) n += 1
+      if (!LDML_FIELDS[c]) throw new Error(`unsupported LDML field ${c} in ${pattern}`)
+      out += LDML_FIELDS[c](n)
+      i += n
+    } else {
+      out += c
+      i += 1
+    }
+  }
+  return out
+}
+
+function pluralFn(kind, name, rules, used) {
+  const lines = [`fn ${kind}_${name}(n: u64) -> PluralCategory {`]
+  for (const category of CATEGORIES) {
+    const rule = rules[`pluralRule-count-${category}`]
+    if (!rule) continue
+    const test = condition(rule.split('@')[0].trim())
+    if (test === null) continue
+    used.add(capitalize(category))
+    lines.push(`    if ${test} {`, `        return ${capitalize(category)};`, '    }')
+  }
+  lines.push('    Other', '}')
+  // rules like "other" only never read the number
+  if (lines.length === 3) lines[0] = lines[0].replace('(n:', '(_n:')
+  return lines.join('\n')
+}
+
+function pluralForms(forms, used) {
+  const order = [...CATEGORIES, 'other']
+  const sorted = Object.entries(forms).sort(([a], [b]) => order.indexOf(a) - order.indexOf(b))
+  const entries = sorted.map(([category, text]) => {
+    if (!CATEGORIES.includes(category) && category !== 'other') {
+      throw new Error(`unknown plural category: ${category}`)
+    }
+    used.add(capitalize(category))
+    return `(${capitalize(category)}, ${rustStr(text)})`
+  })
+  return `&[${entries.join(', ')}]`
+}
+
+function localeStatic(name, cldrDir, plurals, ordinals, used) {
+  const supplement = readJson(path.join(supplementDir, `${name}.json`))
+  const id = supplement.cldr || name
+  const gregorian = readJson(
+    path.join(cldrDir, 'cldr-dates-full', 'main', id, 'ca-gregorian.json'),
+  ).main[id].dates.calendars.gregorian
+  const fields = readJson(path.join(cldrDir, 'cldr-dates-full', 'main', id, 'dateFields.json'))
+    .main[id].dates.fields
+  const units = readJson(path.join(cldrDir, 'cldr-units-full', 'main', id, 'units.json')).main[id].units
+  const unitForms = (width) => `[${DURATION_UNITS.map((unit) => {
+    const patterns = Object.entries(units[width][`duration-${unit}`])
+      .filter(([key]) => key.startsWith('unitPattern-count-'))
+      .map(([key, pattern]) => [key.slice('unitPattern-count-'.length), pattern.replace('{0}', '%d')])
+    return pluralForms(Object.fromEntries(patterns), used)
+  }).join(', ')}]`
+  const zoneNames = readJson(path.join(cldrDir, 'cldr-dates-full', 'main', id, 'timeZoneNames.json'))
+    .main[id].dates.timeZoneNames
+  const utc = zoneNames.zone.Etc.UTC
+  const metazones = Object.keys(zoneNames.metazone).sort().map((mz) => {
+    const names = zoneNames.metazone[mz]
+    const get = (width, kind) => (names[width] && names[width][kind]) || ''
+    const values = [get('long', 'standard'), get('long', 'daylight'), get('short', 'standard'), get('short', 'daylight')]
+    return `(${rustStr(mz)}, ${rustArray(values)})`
+  })
+  const months = (ctx, width) => Array.from({ length: 12 }, (_, i) => gregorian.months[ctx][width][i + 1])
+  const days = (ctx, width) => WEEKDAYS.map((day) => gregorian.days[ctx][width][day])
+  const styles = (formats) => ['full', 'long', 'medium', 'short'].map((style) => ldmlToDayjs(formats[style]))
+  const rules = plurals[id] || plurals[id.split('-')[0]]
+  if (!rules) throw new Error(`no plural rules for ${id}`)
+  const ordinalRules = ordinals[id] || ordinals[id.split('-')[0]]
+  if (!ordinalRules) throw new Error(`no ordinal rules for ${id}`)
+  if (!supplement.ordinal) throw new Error(`${name}: missing ordinal`)
+  if (!(supplement.weekStart >= 0 && supplement.weekStart < 7)) {
+    throw new Error(`${name}: missing weekStart`)
+  }
+  if (!(supplement.yearStart >= 1 && supplement.yearStart <= 7)) {
+    throw new Error(`${name}: missing yearStart`)
+  }
+
+  const formats = FORMAT_FIELDS.map((field) => {
+    if (!supplement.formats || supplement.formats[field] === undefined) {
+      throw new Error(`${name}: missing formats.${field}`)
+    }
+    return supplement.formats[field]
+  })
+  const calendar = CALENDAR_FIELDS.map((field) => {
+    if (!supplement.calendar || supplement.calendar[field] === undefined) {
+      throw new Error(`${name}: missing calendar.${field}`)
+    }
+    return supplement.calendar[field]
+  })
+  const rt = supplement.relativeTime
+  for (const field of ['future', 'past', ...RELATIVE_FIELDS]) {
+    if (rt[field] === undefined) throw new Error(`${name}: missing relativeTime.${field}`)
+  }
+  // futureUnits and pastUnits override the units that are declined inside the suffix
+  const relativeUnits = (key, overrides = {}) => [
+    `        ${key}: RelativeUnits {`,
+    ...RELATIVE_FIELDS.map((field) => {
+      const text = overrides[field] === undefined ? rt[field] : overrides[field]
+      const value = PLURAL_FIELDS.includes(field) ? pluralForms(text, used) : rustStr(text)
+      return `            ${field}: ${value},`
+    }),
+    '        },',
+  ]
+  const relative = [
+    `        future: ${rustStr(rt.future)},`,
+    `        past: ${rustStr(rt.past)},`,
+    ...relativeUnits('units'),
+    ...relativeUnits('future_units', rt.futureUnits),
+    ...relativeUnits('past_units', rt.pastUnits),
+  ]
+  const ident = name.replace(/-/g, '_')
+  const lines = [
+    `pub static ${ident.toUpperCase()}: Locale = Locale {`,
+    `    name: ${rustStr(name)},`,
+    `    months_format: ${rustArray(months('format', 'wide'))},`,
+    `    months_standalone: ${rustArray(months('stand-alone', 'wide'))},`,
+    `    months_format_before_day: ${Boolean(supplement.monthsFormatBeforeDay)},`,
+    `    months_short: ${rustArray(months('stand-alone', 'abbreviated'))},`,
+    `    weekdays: ${rustArray(days('format', 'wide'))},`,
+    `    weekdays_short: ${rustArray(days('format', 'abbreviated'))},`,
+    `    week_start: Weekday::${WEEKDAYS[supplement.weekStart].replace(/^./, (c) => c.toUpperCase())},`,
+    `    year_start: ${supplement.yearStart},`,
+    `    weekdays_relative: [${WEEKDAYS.map((day) => rustArray([-1, 0, 1].map((n) => fields[day][`relative-type-${n}`]))).join(', ')}],`,
+    `    meridiem: ${rustArray(['am', 'pm'].map((p) => gregorian.dayPeriods.format.abbreviated[p]))},`,
+    `    date_formats: ${rustArray(styles(gregorian.dateFormats))},`,
+    `    time_formats: ${rustArray(styles(gregorian.timeFormats))},`,
+    `    datetime_formats: ${rustArray(styles(gregorian.dateTimeFormats))},`,
+    `    gmt_format: ${rustStr(zoneNames.gmtFormat)},`,
+    `    gmt_zero_format: ${rustStr(zoneNames.gmtZeroFormat)},`,
+    `    utc_names: ${rustArray([utc.long.standard, utc.short.standard])},`,
+    `    zone_names: &[${metazones.join(', ')}],`,
+    `    units_long: ${unitForms('long')},`,
+    `    units_short: ${unitForms('short')},`,
+    `    units_narrow: ${unitForms('narrow')},`,
+    `    plural: plural_${ident},`,
+    `    ordinal_plural: ordinal_${ident},`,
+    `    ordinals: ${pluralForms(supplement.ordinal, used)},`,
+    `    localized_formats: ${rustArray(formats)},`,
+    `    calendar: ${rustArray(calendar)},`,
+    '    relative_time: RelativeTime {',
+    ...relative,
+    '    },',
+    '};',
+  ]
+  const plural = [pluralFn('plural', ident, rules, used), pluralFn('ordinal', ident, ordinalRules, used)]
+  return { ident, code: lines.join('\n'), plural: plural.join('\n\n') }
+}
+
+// flatten the metazone tree into sorted (zone, metazone) pairs, using the current mapping
+function metazoneMap(cldrDir) {
+  const tree = readJson(path.join(cldrDir, 'cldr-core', 'supplemental', 'metaZones.json'))
+    .supplemental.metaZones.metazoneInfo.timezone
+  const pairs = []
+  const walk = (node, prefix) => {
+    for (const [key, value] of Object.entries(node)) {
+      const zone = prefix ? `${prefix}/${key}` : key
+      if (Array.isArray(value)) {
+        const current = value.find((entry) => !entry.usesMetazone._to)
+        if (current) pairs.push([zone, current.usesMetazone._mzone])
+      } else {
+        walk(value, zone)
+      }
+    }
+  }
+  walk(tree, '')
+  return pairs.sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0))
+}
+
+// (alias, id) pairs sorted by alias, like CLDR the data keeps the old name of a renamed zone
+// as its id
+function zoneAliases(cldrDir) {
+  const keys = readJson(path.join(cldrDir, 'cldr-bcp47', 'bcp47', 'timezone.json')).keyword.u.tz
+  const pairs = []
+  for (const [key, entry] of Object.entries(keys)) {
+    if (key.startsWith('_') || !entry._alias) continue
+    const names = entry._alias.split(' ')
+    const preferred = entry._preferred ? keys[entry._preferred] : null
+    const id = preferred ? preferred._alias.split(' ')[0] : names[0]
+    for (const name of names) {
+      if (name !== id) pairs.push([name, id])
+    }
+  }
+  return pairs.sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0))
+}
+
+function main() {
+  const [cldrDir, ...names] = process.argv.slice(2)
+  if (!cldrDir) {
+    console.error('usage: node tools/cldr-import.js <data-dir> [locale...]')
+    process.exit(1)
+  }
+  const locales = names.length
+    ? names
+    : fs.readdirSync(supplementDir).filter((f) => f.endsWith('.json')).map((f) => path.basename(f, '.json'))
+  locales.sort()
+  const plurals = readJson(path.join(cldrDir, 'cldr-core', 'supplemental', 'plurals.json'))
+    .supplemental['plurals-type-cardinal']
+  const ordinals = readJson(path.join(cldrDir, 'cldr-core', 'supplemental', 'ordinals.json'))
+    .supplemental['plurals-type-ordinal']
+
+  const zones = metazoneMap(cldrDir)
+  const aliases = zoneAliases(cldrDir)
+  const used = new Set(['Other'])
+  const generated = locales.map((name) => localeStatic(name, cldrDir, plurals, ordinals, used))
+  const imports = ['Zero', 'One', 'Two', 'Few', 'Many', 'Other'].filter((c) => used.has(c))
+  const source = [
+    '// generated by tools/cldr-import.js from tools/locale-data, do not edit by hand',
+    'use super::{Locale, PluralCategory, RelativeTime, RelativeUnits};',
+    'use chrono::Weekday;',
+    `use PluralCategory::{${imports.join(', ')}};`,
+    '',
+    `/// all generated locales`,
+    `pub static LOCALES: [&Locale; ${generated.length}] = [${generated.map((g) => `&${g.ident.toUpperCase()}`).join(', ')}];`,
+    '',
+    '/// zone to metazone of the locale data, sorted by zone',
+    '#[cfg(feature = "tz")]',
+    `pub(crate) static METAZONES: [(&str, &str); ${zones.length}] = [${zones.map(([z, mz]) => `(${rustStr(z)}, ${rustStr(mz)})`).join(', ')}];`,
+    '',
+    '/// other names of a zone to its id in the locale data, sorted by name',
+    `pub(crate) static ZONE_ALIASES: [(&str, &str); ${aliases.length}] = [${aliases.map(([a, id]) => `(${rustStr(a)}, ${rustStr(id)})`).join(', ')}];`,
+    '',
+    ...generated.map((g) => g.code + '\n'),
+    ...generated.map((g) => g.plural + '\n'),
+  ].join('\n')
+  fs.writeFileSync(output, source)
+  try {
+    execFileSync('rustfmt', ['--edition', '2021', output])
+  } catch (e) {
+    console.warn('rustfmt failed, run `cargo fmt` before committing')
+  }
+  console.log(`wrote ${generated.length} locales to ${path.relative(process.cwd(), output)}`)
+}
+
+main()
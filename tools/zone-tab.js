@@ -0,0 +1,46 @@
+#!/usr/bin/env node
+// generate src/timezone/zone_tab.rs from tzdata zone.tab
+//
+// usage: node tools/zone-tab.js [path/to/zone.tab]
+'use strict'
+
+const fs = require('fs')
+const path = require('path')
+const { execFileSync } = require('child_process')
+
+const input = process.argv[2] || '/usr/share/zoneinfo/zone.tab'
+const output = path.join(__dirname, '..', 'src', 'timezone', 'zone_tab.rs')
+
+const compare = (a, b) => (a < b ? -1 : a > b ? 1 : 0)
+
+// file order is kept within a country, zone.tab lists the most populous zone first
+const rows = fs
+  .readFileSync(input, 'utf8')
+  .split('\n')
+  .filter((line) => line && !line.startsWith('#'))
+  .map((line) => line.split('\t'))
+  .map(([country, , zone]) => ({ country, zone }))
+
+const byZone = [...rows].sort((a, b) => compare(a.zone, b.zone))
+const countries = [...new Set(rows.map((r) => r.country))].sort(compare).map((country) => {
+  const zones = rows.filter((r) => r.country === country).map((r) => JSON.stringify(r.zone))
+  return `(${JSON.stringify(country)}, &[${zones.join(', ')}])`
+})
+
+const source = [
+  `// generated by tools/zone-tab.js from ${path.basename(input)}, do not edit by hand`,
+  '',
+  '/// zones listed in zone.tab and their ISO 3166 country, sorted by zone',
+  `pub(crate) static ZONES: [(&str, &str); ${byZone.length}] = [${byZone.map((r) => `(${JSON.stringify(r.zone)}, ${JSON.stringify(r.country)})`).join(', ')}];`,
+  '',
+  '/// zones of each country, sorted by country',
+  `pub(crate) static COUNTRIES: [(&str, &[&str]); ${countries.length}] = [${countries.join(', ')}];`,
+  '',
+].join('\n')
+fs.writeFileSync(output, source)
+try {
+  execFileSync('rustfmt', ['--edition', '2021', output])
+} catch (e) {
+  console.warn('rustfmt failed, run `cargo fmt` before committing')
+}
+console.log(`wrote ${rows.length} zones to ${path.relative(process.cwd(), output)}`)